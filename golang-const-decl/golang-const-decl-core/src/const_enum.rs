@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+
+use convert_case::{Case, Casing as _};
+use proc_macro2::{Ident, Punct, Spacing, TokenStream};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
+
+// A Rust enum mirroring the integer or string constants of a Go type, e.g.
+// `type Status int; const ( Active Status = iota; Disabled )`,
+// `type Region string; const ( USEast Region = "us-east-1"; USWest Region = "us-west-1" )`.
+pub struct ConstEnum {
+    // The Go type name.
+    pub name: String,
+    // All of the same kind of value.
+    pub variants: Vec<ConstEnumVariant>,
+    pub opt: ConstEnumOption,
+}
+
+pub struct ConstEnumVariant {
+    // The Go identifier.
+    pub name: String,
+    pub value: ConstEnumValue,
+}
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum ConstEnumValue {
+    Int(i64),
+    String(String),
+}
+
+#[derive(Default, Debug)]
+pub struct ConstEnumOption {
+    pub enable_derive_serde_ser: bool,
+    pub enable_derive_serde_de: bool,
+    pub custom_derive: Vec<String>,
+    //
+    pub alias_name: Option<String>,
+    // Adds `Other(String)` for unknown values, only for string constants.
+    pub enable_other_variant: bool,
+}
+
+struct Variant {
+    name: Ident,
+    value: ConstEnumValue,
+    go_name: String,
+}
+
+impl ToTokens for ConstEnum {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let enum_name_str = self
+            .opt
+            .alias_name
+            .to_owned()
+            .unwrap_or_else(|| self.name.to_case(Case::Pascal));
+        let enum_name = format_ident!("{}", enum_name_str);
+
+        // Go allows constants with the same value, the later ones become associated constants.
+        let mut variant_names: HashMap<&ConstEnumValue, _> = HashMap::new();
+        let mut variants = vec![];
+        let mut alias_consts = vec![];
+        for variant in self.variants.iter().filter(|x| x.name != "_") {
+            let variant_name = format_ident!("{}", variant_name(&variant.name));
+
+            if let Some(first_variant_name) = variant_names.get(&variant.value) {
+                alias_consts.push(quote! {
+                    #[allow(non_upper_case_globals)]
+                    pub const #variant_name: Self = Self::#first_variant_name;
+                });
+            } else {
+                variants.push(Variant {
+                    name: variant_name.to_owned(),
+                    value: variant.value.to_owned(),
+                    go_name: variant.name.to_owned(),
+                });
+                variant_names.insert(&variant.value, variant_name);
+            }
+        }
+
+        let is_string = matches!(
+            variants.first().map(|x| &x.value),
+            Some(ConstEnumValue::String(_))
+        );
+        if is_string {
+            self.string_enum_to_tokens(&enum_name, &enum_name_str, &variants, tokens);
+        } else {
+            self.int_enum_to_tokens(&enum_name, &enum_name_str, &variants, tokens);
+        }
+
+        if !alias_consts.is_empty() {
+            tokens.append_all(quote! {
+                impl #enum_name {
+                    #(#alias_consts)*
+                }
+            });
+        }
+    }
+}
+
+impl ConstEnum {
+    fn int_enum_to_tokens(
+        &self,
+        enum_name: &Ident,
+        enum_name_str: &str,
+        variants: &[Variant],
+        tokens: &mut TokenStream,
+    ) {
+        let variants: Vec<_> = variants
+            .iter()
+            .filter_map(|variant| match variant.value {
+                ConstEnumValue::Int(value) => Some((&variant.name, value, &variant.go_name)),
+                ConstEnumValue::String(_) => None,
+            })
+            .collect();
+
+        let derive_attr = ConstEnumDeriveAttr {
+            enable_copy: true,
+            custom: self.opt.custom_derive.to_owned(),
+        };
+        let enum_variants = variants
+            .iter()
+            .map(|(variant_name, value, _)| quote!(#variant_name = #value,));
+        tokens.append_all(quote! {
+            #[derive(#derive_attr)]
+            #[repr(i64)]
+            pub enum #enum_name {
+                #(#enum_variants)*
+            }
+        });
+
+        let try_from_arms = variants
+            .iter()
+            .map(|(variant_name, value, _)| quote!(#value => Ok(Self::#variant_name),));
+        let display_arms = variants
+            .iter()
+            .map(|(variant_name, _, go_name)| quote!(Self::#variant_name => #go_name,));
+        tokens.append_all(quote! {
+            impl ::core::convert::TryFrom<i64> for #enum_name {
+                type Error = i64;
+
+                fn try_from(value: i64) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #(#try_from_arms)*
+                        _ => Err(value),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#enum_name> for i64 {
+                fn from(value: #enum_name) -> Self {
+                    value as i64
+                }
+            }
+
+            impl ::core::fmt::Display for #enum_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(match self {
+                        #(#display_arms)*
+                    })
+                }
+            }
+        });
+
+        // Like encoding/json, as the integer.
+        if self.opt.enable_derive_serde_ser {
+            tokens.append_all(quote! {
+                impl ::serde::Serialize for #enum_name {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer,
+                    {
+                        serializer.serialize_i64(*self as i64)
+                    }
+                }
+            });
+        }
+        if self.opt.enable_derive_serde_de {
+            let expected = format!("a value of {}", enum_name_str);
+            tokens.append_all(quote! {
+                impl<'de> ::serde::Deserialize<'de> for #enum_name {
+                    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        let value = <i64 as ::serde::Deserialize>::deserialize(deserializer)?;
+                        <Self as ::core::convert::TryFrom<i64>>::try_from(value).map_err(|value| {
+                            <D::Error as ::serde::de::Error>::invalid_value(
+                                ::serde::de::Unexpected::Signed(value),
+                                &#expected,
+                            )
+                        })
+                    }
+                }
+            });
+        }
+    }
+
+    fn string_enum_to_tokens(
+        &self,
+        enum_name: &Ident,
+        enum_name_str: &str,
+        variants: &[Variant],
+        tokens: &mut TokenStream,
+    ) {
+        let variants: Vec<_> = variants
+            .iter()
+            .filter_map(|variant| match &variant.value {
+                ConstEnumValue::String(value) => Some((&variant.name, value)),
+                ConstEnumValue::Int(_) => None,
+            })
+            .collect();
+        let enable_other_variant = self.opt.enable_other_variant;
+
+        let derive_attr = ConstEnumDeriveAttr {
+            enable_copy: !enable_other_variant,
+            custom: self.opt.custom_derive.to_owned(),
+        };
+        let enum_variants = variants
+            .iter()
+            .map(|(variant_name, _)| quote!(#variant_name,));
+        let (other_variant, other_as_str_arm, from_str_error, from_str_other_arm) =
+            if enable_other_variant {
+                (
+                    quote!(Other(::std::string::String),),
+                    quote!(Self::Other(s) => s.as_str(),),
+                    quote!(::core::convert::Infallible),
+                    quote!(_ => Ok(Self::Other(s.to_owned())),),
+                )
+            } else {
+                (
+                    quote!(),
+                    quote!(),
+                    quote!(::std::string::String),
+                    quote!(_ => Err(s.to_owned()),),
+                )
+            };
+        tokens.append_all(quote! {
+            #[derive(#derive_attr)]
+            pub enum #enum_name {
+                #(#enum_variants)*
+                #other_variant
+            }
+        });
+
+        let as_str_arms = variants
+            .iter()
+            .map(|(variant_name, value)| quote!(Self::#variant_name => #value,));
+        let from_str_arms = variants
+            .iter()
+            .map(|(variant_name, value)| quote!(#value => Ok(Self::#variant_name),));
+        tokens.append_all(quote! {
+            impl #enum_name {
+                pub fn as_str(&self) -> &str {
+                    match self {
+                        #(#as_str_arms)*
+                        #other_as_str_arm
+                    }
+                }
+            }
+
+            impl ::core::convert::AsRef<str> for #enum_name {
+                fn as_ref(&self) -> &str {
+                    self.as_str()
+                }
+            }
+
+            impl ::core::str::FromStr for #enum_name {
+                type Err = #from_str_error;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    match s {
+                        #(#from_str_arms)*
+                        #from_str_other_arm
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for #enum_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+        });
+
+        // Like encoding/json, as the exact string.
+        if self.opt.enable_derive_serde_ser {
+            tokens.append_all(quote! {
+                impl ::serde::Serialize for #enum_name {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer,
+                    {
+                        serializer.serialize_str(self.as_str())
+                    }
+                }
+            });
+        }
+        if self.opt.enable_derive_serde_de {
+            let expected = format!("a value of {}", enum_name_str);
+            tokens.append_all(quote! {
+                impl<'de> ::serde::Deserialize<'de> for #enum_name {
+                    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        let s = <::std::string::String as ::serde::Deserialize>::deserialize(
+                            deserializer,
+                        )?;
+                        <Self as ::core::str::FromStr>::from_str(&s).map_err(|_| {
+                            <D::Error as ::serde::de::Error>::invalid_value(
+                                ::serde::de::Unexpected::Str(&s),
+                                &#expected,
+                            )
+                        })
+                    }
+                }
+            });
+        }
+    }
+}
+
+// Exported Go identifiers are kept, e.g. `MB` and `StatusOK`, others are converted, e.g. `KIND_A`.
+fn variant_name(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_uppercase()) && !name.contains('_') {
+        name.to_owned()
+    } else {
+        name.to_case(Case::Pascal)
+    }
+}
+
+struct ConstEnumDeriveAttr {
+    enable_copy: bool,
+    custom: Vec<String>,
+}
+impl ToTokens for ConstEnumDeriveAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(quote!(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        ));
+
+        if self.enable_copy {
+            tokens.append_all(quote!(::core::marker::Copy,));
+        }
+
+        for custom in &self.custom {
+            let custom = format_ident!("{}", custom);
+            tokens.append_all(quote!(#custom));
+            tokens.append(Punct::new(',', Spacing::Alone));
+        }
+    }
+}
@@ -1 +1,236 @@
+pub use golang_type_core;
 
+use std::str::{self, FromStr};
+
+use golang_parser::{tree_sitter::Node, Parser, Span, NODE_KIND_COMMENT};
+use golang_type_core::{
+    ConstEvalError, ConstEvaluator, Constant, Expression, Type, TypeParseError,
+};
+
+#[cfg(feature = "enable-quote-to_tokens")]
+pub mod const_enum;
+
+// https://golang.org/ref/spec#Constant_declarations
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ConstDecl {
+    pub const_specs: Vec<ConstSpec>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ConstSpec {
+    pub names: Vec<String>,
+    pub r#type: Option<Type>,
+    // Empty if the previous ExpressionList and type are repeated.
+    pub values: Vec<Expression>,
+    pub span: Span,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConstDeclParseError {
+    #[error("GolangParserError {0:?}")]
+    GolangParserError(#[from] golang_parser::Error),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(#[from] str::Utf8Error),
+    //
+    #[error("TypeParseError {0:?}")]
+    TypeParseError(#[from] TypeParseError),
+}
+
+// An evaluated constant of a ConstSpec.
+#[derive(PartialEq, Debug, Clone)]
+pub struct NamedConst {
+    pub name: String,
+    pub constant: Constant,
+    pub span: Span,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConstDeclEvalError {
+    #[error("ExpressionMissing {0} at {1}")]
+    ExpressionMissing(String, Span),
+    #[error("ExpressionCountMismatch {0} at {1}")]
+    ExpressionCountMismatch(String, Span),
+    #[error("ConstEvalError {1} of {0} at {2}")]
+    ConstEvalError(String, Box<ConstEvalError>, Span),
+}
+
+impl FromStr for ConstDecl {
+    type Err = ConstDeclParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new_strict(s)?;
+        let source = parser.get_source();
+        let root_node = parser.get_root_node();
+
+        let mut cursor = root_node.walk();
+        let mut node_source_file_named_children_iter = root_node
+            .named_children(&mut cursor)
+            .filter(|x| x.kind() != NODE_KIND_COMMENT);
+
+        let node_const_declaration =
+            node_source_file_named_children_iter.next().ok_or_else(|| {
+                ConstDeclParseError::NodeMissing(
+                    "const_declaration".to_string(),
+                    Span::from(root_node),
+                )
+            })?;
+        let const_decl = Self::from_const_declaration_node(node_const_declaration, source)?;
+
+        if let Some(node) = node_source_file_named_children_iter.next() {
+            return Err(ConstDeclParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        Ok(const_decl)
+    }
+}
+
+impl ConstDecl {
+    pub fn from_const_declaration_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Self, ConstDeclParseError> {
+        if node.kind() != "const_declaration" {
+            return Err(ConstDeclParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        let mut const_specs = vec![];
+        for node_const_spec in node
+            .named_children(&mut node.walk())
+            .filter(|x| x.kind() != NODE_KIND_COMMENT)
+        {
+            match node_const_spec.kind() {
+                "const_spec" => {
+                    const_specs.push(ConstSpec::from_const_spec_node(node_const_spec, source)?)
+                }
+                _ => {
+                    return Err(ConstDeclParseError::NodeKindUnknown(
+                        node_const_spec.kind().to_owned(),
+                        Span::from(node_const_spec),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { const_specs })
+    }
+}
+
+impl ConstDecl {
+    pub fn eval(&self) -> Result<Vec<NamedConst>, ConstDeclEvalError> {
+        self.eval_with(&mut ConstEvaluator::default())
+    }
+
+    // https://golang.org/ref/spec#Iota
+    // The constants are added to the evaluator, so that later declarations can refer to them.
+    pub fn eval_with(
+        &self,
+        evaluator: &mut ConstEvaluator,
+    ) -> Result<Vec<NamedConst>, ConstDeclEvalError> {
+        let mut named_consts = vec![];
+
+        let mut previous = None;
+        for (iota, const_spec) in self.const_specs.iter().enumerate() {
+            let names = || const_spec.names.join(", ");
+
+            // Implicit repetition of the previous ExpressionList and its type.
+            let (r#type, values) = if const_spec.values.is_empty() {
+                match (&const_spec.r#type, previous) {
+                    (None, Some(previous)) => previous,
+                    _ => {
+                        return Err(ConstDeclEvalError::ExpressionMissing(
+                            names(),
+                            const_spec.span,
+                        ))
+                    }
+                }
+            } else {
+                (&const_spec.r#type, &const_spec.values)
+            };
+            previous = Some((r#type, values));
+
+            if values.len() != const_spec.names.len() {
+                return Err(ConstDeclEvalError::ExpressionCountMismatch(
+                    names(),
+                    const_spec.span,
+                ));
+            }
+
+            for (name, value) in const_spec.names.iter().zip(values) {
+                evaluator.iota = Some(iota as i128);
+                let constant = evaluator.eval(value).and_then(|constant| match r#type {
+                    Some(r#type) => constant.convert(r#type),
+                    None => Ok(constant),
+                });
+                evaluator.iota = None;
+                let constant = constant.map_err(|err| {
+                    ConstDeclEvalError::ConstEvalError(name.to_owned(), err.into(), const_spec.span)
+                })?;
+
+                if name != "_" {
+                    evaluator
+                        .constants
+                        .insert(name.to_owned(), constant.to_owned());
+                }
+                named_consts.push(NamedConst {
+                    name: name.to_owned(),
+                    constant,
+                    span: const_spec.span,
+                });
+            }
+        }
+
+        Ok(named_consts)
+    }
+}
+
+impl ConstSpec {
+    fn from_const_spec_node(node: Node, source: &[u8]) -> Result<Self, ConstDeclParseError> {
+        let mut names = vec![];
+        // tree-sitter 0.20 also yields the `,` between the names.
+        for node_name in node
+            .children_by_field_name("name", &mut node.walk())
+            .filter(|x| x.is_named())
+        {
+            names.push(node_name.utf8_text(source)?.to_owned());
+        }
+        if names.is_empty() {
+            return Err(ConstDeclParseError::NodeMissing(
+                "const_spec name".to_string(),
+                Span::from(node),
+            ));
+        }
+
+        let r#type = if let Some(node_type) = node.child_by_field_name("type") {
+            Some(Type::from_node(node_type, source)?)
+        } else {
+            None
+        };
+
+        let mut values = vec![];
+        if let Some(node_value) = node.child_by_field_name("value") {
+            for node_expression in node_value
+                .named_children(&mut node_value.walk())
+                .filter(|x| x.kind() != NODE_KIND_COMMENT)
+            {
+                values.push(Expression::from_node(node_expression, source)?);
+            }
+        }
+
+        Ok(Self {
+            names,
+            r#type,
+            values,
+            span: Span::from(node),
+        })
+    }
+}
@@ -0,0 +1,243 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_const_decl_core::{
+    golang_type_core::{
+        num_bigint::BigInt, num_rational::BigRational, BasicLit, BinaryExpression, BinaryOperator,
+        ConstEvaluator, ConstValue, Constant, Expression, Type, TypeName,
+    },
+    ConstDecl, ConstDeclEvalError, ConstSpec,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let const_decl: ConstDecl = r#"
+const (
+	A Status = iota + 1 // comment
+	B
+	c, d = "c", 'd'
+)
+"#
+    .parse()?;
+
+    assert_eq!(
+        const_decl,
+        ConstDecl {
+            const_specs: vec![
+                ConstSpec {
+                    names: vec!["A".to_owned()],
+                    r#type: Some(Type::TypeName(TypeName::Identifier("Status".to_owned()))),
+                    values: vec![Expression::Binary(BinaryExpression {
+                        left: Expression::Identifier("iota".to_owned()).into(),
+                        operator: BinaryOperator::Add,
+                        right: Expression::BasicLit(BasicLit::Int("1".to_owned())).into(),
+                    })],
+                    span: Default::default(),
+                },
+                ConstSpec {
+                    names: vec!["B".to_owned()],
+                    r#type: None,
+                    values: vec![],
+                    span: Default::default(),
+                },
+                ConstSpec {
+                    names: vec!["c".to_owned(), "d".to_owned()],
+                    r#type: None,
+                    values: vec![
+                        Expression::BasicLit(BasicLit::String(r#""c""#.to_owned())),
+                        Expression::BasicLit(BasicLit::Rune("'d'".to_owned())),
+                    ],
+                    span: Default::default(),
+                },
+            ]
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_single() -> Result<(), Box<dyn error::Error>> {
+    let const_decl: ConstDecl = "const Pi float64 = 3.14".parse()?;
+    assert_eq!(const_decl.const_specs.len(), 1);
+    assert_eq!(const_decl.const_specs[0].names, vec!["Pi"]);
+
+    assert!("type A int".parse::<ConstDecl>().is_err());
+
+    Ok(())
+}
+
+fn const_decls() -> Result<Vec<ConstDecl>, Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/iota.go"))?;
+    let mut const_decls = vec![];
+    for (skip, take) in &[(4, 7), (14, 6), (21, 6), (28, 6), (35, 5), (41, 7)] {
+        let str = content
+            .lines()
+            .skip(*skip)
+            .take(*take)
+            .collect::<Vec<_>>()
+            .join("\n");
+        const_decls.push(str.parse()?);
+    }
+    Ok(const_decls)
+}
+
+fn values(const_decl: &ConstDecl) -> Result<Vec<(String, ConstValue)>, ConstDeclEvalError> {
+    Ok(const_decl
+        .eval()?
+        .into_iter()
+        .map(|x| (x.name, x.constant.value))
+        .collect())
+}
+
+#[test]
+fn test_eval_iota() -> Result<(), Box<dyn error::Error>> {
+    let const_decls = const_decls()?;
+
+    let weekday = Some(Type::TypeName(TypeName::Identifier("Weekday".to_owned())));
+    let named_consts = const_decls[0].eval()?;
+    assert_eq!(
+        named_consts
+            .iter()
+            .map(|x| (x.name.as_str(), &x.constant))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "Sunday",
+                &Constant {
+                    value: ConstValue::Int(0.into()),
+                    r#type: weekday.clone()
+                }
+            ),
+            (
+                "Monday",
+                &Constant {
+                    value: ConstValue::Int(1.into()),
+                    r#type: weekday.clone()
+                }
+            ),
+            (
+                "Tuesday",
+                &Constant {
+                    value: ConstValue::Int(2.into()),
+                    r#type: weekday.clone()
+                }
+            ),
+            (
+                "_",
+                &Constant {
+                    value: ConstValue::Int(3.into()),
+                    r#type: weekday.clone()
+                }
+            ),
+            (
+                "Thursday",
+                &Constant {
+                    value: ConstValue::Int(4.into()),
+                    r#type: weekday
+                }
+            ),
+        ]
+    );
+
+    let named_consts = const_decls[1].eval()?;
+    assert_eq!(named_consts[0].constant.r#type, None);
+    assert_eq!(
+        named_consts[3].constant,
+        Constant {
+            value: ConstValue::Int((1 << 30).into()),
+            r#type: Some(Type::TypeName(TypeName::Identifier("ByteSize".to_owned()))),
+        }
+    );
+
+    assert_eq!(
+        values(&const_decls[2])?,
+        vec![
+            ("bit0".to_owned(), ConstValue::Int(1.into())),
+            ("mask0".to_owned(), ConstValue::Int(0.into())),
+            ("bit1".to_owned(), ConstValue::Int(2.into())),
+            ("mask1".to_owned(), ConstValue::Int(1.into())),
+            ("_".to_owned(), ConstValue::Int(4.into())),
+            ("_".to_owned(), ConstValue::Int(3.into())),
+            ("bit3".to_owned(), ConstValue::Int(8.into())),
+            ("mask3".to_owned(), ConstValue::Int(7.into())),
+        ]
+    );
+
+    assert_eq!(
+        values(&const_decls[3])?,
+        vec![
+            ("a".to_owned(), ConstValue::Int(1.into())),
+            ("b".to_owned(), ConstValue::Int(2.into())),
+            ("c".to_owned(), ConstValue::Int(3.into())),
+            ("d".to_owned(), ConstValue::Int(8.into())),
+        ]
+    );
+
+    let named_consts = const_decls[4].eval()?;
+    assert_eq!(
+        named_consts[0].constant,
+        Constant::untyped(ConstValue::Int(0.into()))
+    );
+    assert_eq!(
+        named_consts[1].constant,
+        Constant {
+            value: ConstValue::Float(BigRational::from_integer(42.into())),
+            r#type: Some(Type::TypeName(TypeName::Float64)),
+        }
+    );
+    assert_eq!(
+        named_consts[2].constant,
+        Constant::untyped(ConstValue::Int(84.into()))
+    );
+
+    let named_consts = const_decls[5].eval()?;
+    assert_eq!(
+        named_consts[0].constant.value.to_f64(),
+        Some(std::f64::consts::PI)
+    );
+    assert_eq!(
+        named_consts[1].constant.value,
+        ConstValue::Int(BigInt::from(1) << 100)
+    );
+    assert_eq!(named_consts[2].constant.value, ConstValue::Int(2.into()));
+    assert_eq!(named_consts[3].constant.value.as_str(), Some("huge"));
+    assert_eq!(named_consts[4].constant.value.to_u64(), Some(255));
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_with() -> Result<(), Box<dyn error::Error>> {
+    let mut evaluator = ConstEvaluator::default();
+    "const KB = 1 << 10"
+        .parse::<ConstDecl>()?
+        .eval_with(&mut evaluator)?;
+    let named_consts = "const (\n\tMB = KB << 10\n\tName = \"x\" + \"y\"\n)"
+        .parse::<ConstDecl>()?
+        .eval_with(&mut evaluator)?;
+    assert_eq!(
+        named_consts[0].constant.value,
+        ConstValue::Int((1 << 20).into())
+    );
+    assert_eq!(evaluator.constants.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_error() -> Result<(), Box<dyn error::Error>> {
+    assert!(matches!(
+        "const (\n\tA int8 = 127 + iota\n\tB\n)".parse::<ConstDecl>()?.eval(),
+        Err(ConstDeclEvalError::ConstEvalError(name, _, span)) if name == "B" && span.start.line == 3
+    ));
+    assert!(matches!(
+        "const A, B = 1".parse::<ConstDecl>()?.eval(),
+        Err(ConstDeclEvalError::ExpressionCountMismatch(_, _))
+    ));
+    assert!(matches!(
+        "const A = B".parse::<ConstDecl>()?.eval(),
+        Err(ConstDeclEvalError::ConstEvalError(_, _, _))
+    ));
+
+    Ok(())
+}
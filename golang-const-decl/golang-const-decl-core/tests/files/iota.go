@@ -0,0 +1,48 @@
+package main
+
+type Weekday int
+
+const (
+	Sunday Weekday = iota
+	Monday
+	Tuesday
+	_
+	Thursday
+)
+
+type ByteSize float64
+
+const (
+	_           = iota // ignore first value by assigning to blank identifier
+	KB ByteSize = 1 << (10 * iota)
+	MB
+	GB
+)
+
+const (
+	bit0, mask0 = 1 << iota, 1<<iota - 1 // bit0 == 1, mask0 == 0  (iota == 0)
+	bit1, mask1                          // bit1 == 2, mask1 == 1  (iota == 1)
+	_, _                                 //                        (iota == 2, unused)
+	bit3, mask3                          // bit3 == 8, mask3 == 7  (iota == 3)
+)
+
+const (
+	a = 1 << iota // a == 1  (iota == 0)
+	b = 1 << iota // b == 2  (iota == 1)
+	c = 3         // c == 3  (iota == 2, unused)
+	d = 1 << iota // d == 8  (iota == 3)
+)
+
+const (
+	u         = iota * 42 // u == 0     (untyped integer constant)
+	v float64 = iota * 42 // v == 42.0  (float64 constant)
+	w         = iota * 42 // w == 84    (untyped integer constant)
+)
+
+const (
+	Pi       = 3.14159265358979323846264338327950288419716939937510582097494459
+	Big      = 1 << 100
+	Small    = Big >> 99
+	Huge     = "huge"
+	MaxUint8 = ^uint8(0)
+)
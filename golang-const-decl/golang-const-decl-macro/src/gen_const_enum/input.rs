@@ -0,0 +1,94 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Error as SynError, Ident, LitBool, LitInt, LitStr, Token,
+};
+
+use golang_macro_utils::path_to_code;
+
+pub struct Input {
+    pub code: String,
+    pub nth: usize,
+    pub name: Option<String>,
+    //
+    pub disable_derive_serde_ser: bool,
+    pub disable_derive_serde_de: bool,
+    pub custom_derive: Vec<String>,
+
+    pub alias_name: Option<String>,
+    pub enable_other_variant: bool,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self, SynError> {
+        let mut code = String::new();
+        let mut nth = 0;
+        let mut name = None;
+
+        let mut disable_derive_serde_ser = false;
+        let mut disable_derive_serde_de = false;
+        let mut custom_derive = vec![];
+
+        let mut alias_name = None;
+        let mut enable_other_variant = false;
+
+        while !input.is_empty() {
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            if key == "code" {
+                let s = input.parse::<LitStr>()?.value();
+                input.parse::<Token![,]>()?;
+
+                code = s.trim_start().trim_end().to_owned();
+            } else if key == "path" {
+                let s = input.parse::<LitStr>()?.value();
+                input.parse::<Token![,]>()?;
+
+                match path_to_code(&s) {
+                    Ok(s) => code = s,
+                    Err(err) => {
+                        return Err(SynError::new_spanned(key, err));
+                    }
+                }
+            } else if key == "nth" {
+                nth = input.parse::<LitInt>()?.base10_parse::<usize>()?;
+                input.parse::<Token![,]>()?;
+            } else if key == "name" {
+                name = Some(input.parse::<LitStr>()?.value());
+                input.parse::<Token![,]>()?;
+            } else if key == "disable_derive_serde_ser" {
+                disable_derive_serde_ser = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else if key == "disable_derive_serde_de" {
+                disable_derive_serde_de = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else if key == "custom_derive" {
+                let s = input.parse::<LitStr>()?.value();
+                if !s.is_empty() {
+                    custom_derive = s.split(',').map(|x| x.trim().to_owned()).collect()
+                };
+                input.parse::<Token![,]>()?;
+            } else if key == "alias_name" {
+                alias_name = Some(input.parse::<LitStr>()?.value());
+                input.parse::<Token![,]>()?;
+            } else if key == "enable_other_variant" {
+                enable_other_variant = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else {
+                let err = format!("unexpected input key: {}", key);
+                return Err(SynError::new_spanned(key, err));
+            }
+        }
+
+        Ok(Self {
+            code,
+            nth,
+            name,
+            disable_derive_serde_ser,
+            disable_derive_serde_de,
+            custom_derive,
+            alias_name,
+            enable_other_variant,
+        })
+    }
+}
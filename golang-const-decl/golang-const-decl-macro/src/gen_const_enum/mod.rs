@@ -0,0 +1,162 @@
+use golang_const_decl_core::{
+    const_enum::{ConstEnum, ConstEnumOption, ConstEnumValue, ConstEnumVariant},
+    golang_type_core::{ConstEvaluator, ConstValue, Type, TypeName},
+    NamedConst,
+};
+use golang_source_file::SourceFile;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+mod input;
+
+pub use self::input::Input;
+
+pub fn get_output(input: Input) -> TokenStream {
+    let source_file = match input.code.parse::<SourceFile>() {
+        Ok(source_file) => source_file,
+        Err(err) => {
+            let err = err.to_string();
+            return quote!(compile_error!(#err));
+        }
+    };
+
+    // Evaluated in order, so that constants may refer to the ones of previous declarations.
+    let mut evaluator = ConstEvaluator::default();
+    let const_decls: Vec<_> = source_file
+        .const_decls()
+        .map(|const_decl| (const_decl, const_decl.eval_with(&mut evaluator)))
+        .collect();
+
+    let is_type = |named_const: &NamedConst, name: &str| match &named_const.constant.r#type {
+        Some(Type::TypeName(TypeName::Identifier(type_name))) => type_name == name,
+        _ => false,
+    };
+
+    let (name, named_consts) = if let Some(name) = input.name {
+        let mut named_consts = vec![];
+        for (const_decl, named_consts_ret) in const_decls {
+            match named_consts_ret {
+                Ok(x) => named_consts.extend(x.into_iter().filter(|x| is_type(x, &name))),
+                // Declarations of other types are allowed to fail, e.g. `time.Second` is unknown.
+                Err(err) => {
+                    let is_related = const_decl.const_specs.iter().any(|const_spec| {
+                        const_spec.r#type
+                            == Some(Type::TypeName(TypeName::Identifier(name.to_owned())))
+                    });
+                    if is_related {
+                        let err = err.to_string();
+                        return quote!(compile_error!(#err));
+                    }
+                }
+            }
+        }
+        (name, named_consts)
+    } else {
+        let named_consts = match const_decls.into_iter().nth(input.nth) {
+            Some((_, Ok(named_consts))) => named_consts,
+            Some((_, Err(err))) => {
+                let err = err.to_string();
+                return quote!(compile_error!(#err));
+            }
+            None => {
+                let err = "Require [Constant declarations](https://golang.org/ref/spec#Constant_declarations)";
+                return quote!(compile_error!(#err));
+            }
+        };
+        let name =
+            match named_consts
+                .iter()
+                .find_map(|named_const| match &named_const.constant.r#type {
+                    Some(Type::TypeName(TypeName::Identifier(type_name))) => {
+                        Some(type_name.to_owned())
+                    }
+                    _ => None,
+                }) {
+                Some(name) => name,
+                None => {
+                    let err = "Require typed constants, e.g. `A Kind = iota`";
+                    return quote!(compile_error!(#err));
+                }
+            };
+        let named_consts = named_consts
+            .into_iter()
+            .filter(|x| is_type(x, &name))
+            .collect();
+        (name, named_consts)
+    };
+
+    let mut variants: Vec<ConstEnumVariant> = vec![];
+    for named_const in named_consts {
+        let value = match (
+            &named_const.constant.value,
+            named_const.constant.value.to_i64(),
+        ) {
+            (_, Some(value)) => ConstEnumValue::Int(value),
+            (ConstValue::String(_), _) => match named_const.constant.value.as_str() {
+                Some(value) => ConstEnumValue::String(value.to_owned()),
+                None => {
+                    let err = format!(
+                        "Require UTF-8 string constants, {} is {}",
+                        named_const.name, named_const.constant.value
+                    );
+                    return quote!(compile_error!(#err));
+                }
+            },
+            // The enum is `#[repr(i64)]`, e.g. `uint64` constants above `math.MaxInt64`.
+            (_, None) if named_const.constant.value.to_int().is_some() => {
+                let err = format!(
+                    "Require integer constants within i64, {} = {} overflows i64",
+                    named_const.name, named_const.constant.value
+                );
+                return quote!(compile_error!(#err));
+            }
+            _ => {
+                let err = format!(
+                    "Require integer or string constants, {} is {}",
+                    named_const.name, named_const.constant.value
+                );
+                return quote!(compile_error!(#err));
+            }
+        };
+        if let Some(first) = variants.first() {
+            if std::mem::discriminant(&first.value) != std::mem::discriminant(&value) {
+                let err = format!(
+                    "Require constants of the same kind, {} and {} differ",
+                    first.name, named_const.name
+                );
+                return quote!(compile_error!(#err));
+            }
+        }
+        variants.push(ConstEnumVariant {
+            name: named_const.name,
+            value,
+        });
+    }
+    if input.enable_other_variant
+        && matches!(
+            variants.first().map(|x| &x.value),
+            Some(ConstEnumValue::Int(_))
+        )
+    {
+        let err = "enable_other_variant requires string constants";
+        return quote!(compile_error!(#err));
+    }
+    if variants.iter().all(|x| x.name == "_") {
+        let err = format!("constants of type {} not found", name);
+        return quote!(compile_error!(#err));
+    }
+
+    let const_enum = ConstEnum {
+        name,
+        variants,
+        opt: ConstEnumOption {
+            enable_derive_serde_ser: !input.disable_derive_serde_ser,
+            enable_derive_serde_de: !input.disable_derive_serde_de,
+            custom_derive: input.custom_derive,
+            alias_name: input.alias_name,
+            enable_other_variant: input.enable_other_variant,
+        },
+    };
+
+    quote!(#const_enum)
+}
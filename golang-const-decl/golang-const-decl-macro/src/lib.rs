@@ -1 +1,12 @@
+extern crate proc_macro;
 
+use syn::parse_macro_input;
+
+mod gen_const_enum;
+
+#[proc_macro]
+pub fn gen_const_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as gen_const_enum::Input);
+    let output = gen_const_enum::get_output(input);
+    output.into()
+}
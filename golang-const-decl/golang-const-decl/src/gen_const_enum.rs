@@ -0,0 +1,35 @@
+#[macro_export]
+macro_rules! gen_const_enum {
+    //
+    ($code:literal) => {
+        golang_const_decl_macro::gen_const_enum!(code = $code,);
+    };
+    //
+    (
+        $code:literal,
+        $( $opt_k:ident = $opt_v:literal ),+ $(,)?
+    ) => {
+        golang_const_decl_macro::gen_const_enum!(
+            code = $code,
+            $( $opt_k = $opt_v ,)*
+        );
+    };
+}
+
+#[macro_export]
+macro_rules! gen_const_enum_from_file {
+    //
+    ($path:literal) => {
+        golang_const_decl_macro::gen_const_enum!(path = $path,);
+    };
+    //
+    (
+        $path:literal,
+        $( $opt_k:ident = $opt_v:literal ),+ $(,)?
+    ) => {
+        golang_const_decl_macro::gen_const_enum!(
+            path = $path,
+            $( $opt_k = $opt_v ,)*
+        );
+    };
+}
@@ -1 +1,4 @@
+pub use golang_const_decl_core::*;
+pub use golang_const_decl_macro;
 
+mod gen_const_enum;
@@ -0,0 +1,27 @@
+package status
+
+import "time"
+
+const Timeout = 30 * time.Second
+
+type Status int
+
+const (
+	StatusActive Status = iota + 1
+	StatusDisabled
+	StatusDeleted
+)
+
+type Level uint8
+
+const (
+	_ Level = iota
+	LevelDebug
+	LevelInfo
+	LevelWarn
+	LevelWarning = LevelWarn
+)
+
+const (
+	StatusUnknown Status = -1
+)
@@ -0,0 +1,126 @@
+use std::{convert::TryFrom as _, error};
+
+use golang_const_decl::gen_const_enum;
+
+#[test]
+fn simple() -> Result<(), Box<dyn error::Error>> {
+    gen_const_enum!(
+        r#"
+    type Weekday int
+
+    const (
+        Sunday Weekday = iota
+        Monday
+        Tuesday
+    )
+    "#
+    );
+
+    assert_eq!(Weekday::Monday as i64, 1);
+    assert_eq!(i64::from(Weekday::Tuesday), 2);
+    assert_eq!(Weekday::try_from(0), Ok(Weekday::Sunday));
+    assert_eq!(Weekday::try_from(3), Err(3));
+    assert_eq!(Weekday::Sunday.to_string(), "Sunday");
+
+    assert_eq!(serde_json::to_string(&Weekday::Monday)?, "1");
+    assert_eq!(serde_json::from_str::<Weekday>("2")?, Weekday::Tuesday);
+    assert!(serde_json::from_str::<Weekday>("3").is_err());
+    assert!(serde_json::from_str::<Weekday>(r#""Monday""#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn with_expression() {
+    gen_const_enum!(
+        r#"
+    const (
+        _           = iota
+        KB ByteSize = 1 << (10 * iota)
+        MB
+        GB
+    )
+    "#,
+        alias_name = "Unit",
+        custom_derive = "PartialOrd, Ord"
+    );
+
+    assert_eq!(Unit::MB as i64, 1 << 20);
+    assert_eq!(Unit::GB.to_string(), "GB");
+    assert!(Unit::KB < Unit::GB);
+}
+
+#[test]
+fn with_other_opts() {
+    gen_const_enum!(
+        "const ( A Kind = iota; B; )",
+        disable_derive_serde_ser = true,
+        disable_derive_serde_de = true
+    );
+
+    assert_eq!(Kind::B as i64, 1);
+}
+
+#[test]
+fn with_string() -> Result<(), Box<dyn error::Error>> {
+    gen_const_enum!(
+        r#"
+    type Region string
+
+    const (
+        USEast  Region = "us-east-1"
+        USWest  Region = "us-west-" + "2"
+        Default Region = USEast
+    )
+    "#
+    );
+
+    assert_eq!(Region::USWest.as_str(), "us-west-2");
+    assert_eq!(Region::USEast.as_ref(), "us-east-1");
+    assert_eq!(Region::Default, Region::USEast);
+    assert_eq!("us-west-2".parse::<Region>(), Ok(Region::USWest));
+    assert_eq!("eu-west-1".parse::<Region>(), Err("eu-west-1".to_owned()));
+    assert_eq!(Region::USEast.to_string(), "us-east-1");
+
+    assert_eq!(serde_json::to_string(&Region::USWest)?, r#""us-west-2""#);
+    assert_eq!(
+        serde_json::from_str::<Region>(r#""us-east-1""#)?,
+        Region::USEast
+    );
+    assert!(serde_json::from_str::<Region>(r#""USEast""#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn with_string_and_other_variant() -> Result<(), Box<dyn error::Error>> {
+    gen_const_enum!(
+        r#"
+    type Region string
+
+    const (
+        USEast Region = "us-east-1"
+        USWest Region = "us-west-2"
+    )
+    "#,
+        enable_other_variant = true
+    );
+
+    assert_eq!("us-east-1".parse::<Region>(), Ok(Region::USEast));
+    assert_eq!(
+        "eu-west-1".parse::<Region>(),
+        Ok(Region::Other("eu-west-1".to_owned()))
+    );
+    assert_eq!(Region::Other("eu-west-1".to_owned()).as_str(), "eu-west-1");
+
+    assert_eq!(
+        serde_json::from_str::<Region>(r#""eu-west-1""#)?,
+        Region::Other("eu-west-1".to_owned())
+    );
+    assert_eq!(
+        serde_json::to_string(&Region::Other("eu-west-1".to_owned()))?,
+        r#""eu-west-1""#
+    );
+
+    Ok(())
+}
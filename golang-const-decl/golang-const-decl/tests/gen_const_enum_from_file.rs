@@ -0,0 +1,30 @@
+use std::{convert::TryFrom as _, error};
+
+use golang_const_decl::gen_const_enum_from_file;
+
+#[test]
+fn with_nth() -> Result<(), Box<dyn error::Error>> {
+    gen_const_enum_from_file!("tests/files/status.go", nth = 1);
+
+    assert_eq!(Status::StatusActive as i64, 1);
+    assert_eq!(Status::try_from(3), Ok(Status::StatusDeleted));
+    // Declared in another const block.
+    assert!(Status::try_from(-1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn with_name() -> Result<(), Box<dyn error::Error>> {
+    gen_const_enum_from_file!("tests/files/status.go", name = "Status");
+    assert_eq!(Status::StatusUnknown as i64, -1);
+    assert_eq!(serde_json::to_string(&Status::StatusUnknown)?, "-1");
+
+    gen_const_enum_from_file!("tests/files/status.go", name = "Level");
+    // The blank identifier is skipped, and constants of the same value become aliases.
+    assert_eq!(Level::try_from(0), Err(0));
+    assert_eq!(Level::LevelWarning, Level::LevelWarn);
+    assert_eq!(Level::LevelWarning.to_string(), "LevelWarn");
+
+    Ok(())
+}
@@ -1,11 +1,18 @@
 pub use tree_sitter;
 
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
 use tree_sitter::{Node, Tree};
 
 pub const NODE_KIND_COMMENT: &str = "comment";
 
 pub struct Parser {
     code: String,
+    // Without the appended `;`
+    code_len: usize,
     tree: Tree,
 }
 impl Parser {
@@ -15,7 +22,9 @@ impl Parser {
             .set_language(tree_sitter_go::language())
             .map_err(|err| Error::TreeSitterLanguageError(err.to_string()))?;
 
-        let code = code.as_ref();
+        // A `;` after a trailing newline would be parsed as a broken empty statement.
+        let code = code.as_ref().trim_end();
+        let code_len = code.len();
         let code = if code.ends_with(';') {
             code.to_owned()
         } else {
@@ -28,11 +37,70 @@ impl Parser {
 
         debug_assert!(tree.root_node().kind() == "source_file");
 
-        Ok(Self { code, tree })
+        Ok(Self {
+            code,
+            code_len,
+            tree,
+        })
+    }
+
+    // Fails with every ERROR/MISSING node of the tree instead of leaving them to the callers.
+    pub fn new_strict(code: impl AsRef<str>) -> Result<Self, Error> {
+        let parser = Self::new(code)?;
+
+        let syntax_errors = parser.get_syntax_errors();
+        if !syntax_errors.is_empty() {
+            return Err(Error::SyntaxErrors(syntax_errors));
+        }
+
+        Ok(parser)
+    }
+
+    pub fn get_syntax_errors(&self) -> Vec<SyntaxError> {
+        let mut syntax_errors = vec![];
+        if self.tree.root_node().has_error() {
+            self.push_syntax_errors(self.tree.root_node(), &mut syntax_errors);
+        }
+        syntax_errors
+    }
+
+    fn push_syntax_errors(&self, node: Node, syntax_errors: &mut Vec<SyntaxError>) {
+        let code = &self.code[..self.code_len];
+
+        if node.is_error() {
+            let snippet = code
+                .get(node.start_byte()..node.end_byte().min(self.code_len))
+                .unwrap_or_default();
+            syntax_errors.push(SyntaxError {
+                kind: SyntaxErrorKind::Unexpected,
+                span: Span::from(node),
+                snippet: snippet.to_owned(),
+            });
+            return;
+        }
+        if node.is_missing() {
+            // Missing nodes are empty, so the snippet is the line they are missing from.
+            let line = code
+                .lines()
+                .nth(node.start_position().row)
+                .unwrap_or_default();
+            syntax_errors.push(SyntaxError {
+                kind: SyntaxErrorKind::Missing(node.kind().to_owned()),
+                span: Span::from(node),
+                snippet: line.trim().to_owned(),
+            });
+            return;
+        }
+
+        for child in node.children(&mut node.walk()) {
+            if child.has_error() {
+                self.push_syntax_errors(child, syntax_errors);
+            }
+        }
     }
 
     pub fn get_source(&self) -> &[u8] {
-        &self.code.as_bytes()
+        self.code.as_bytes()
     }
 
     pub fn get_root_node(&self) -> Node<'_> {
@@ -40,12 +108,104 @@ impl Parser {
     }
 }
 
+// Location of a node in the parsed source.
+//
+// Spans are ignored by `PartialEq` and `Hash`, so a parsed value still equals the same value
+// built by hand or parsed from other source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Span {
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub start: Position,
+    pub end: Position,
+}
+
+// 1-based, the column counts bytes.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl From<Node<'_>> for Span {
+    fn from(node: Node<'_>) -> Self {
+        let start_position = node.start_position();
+        let end_position = node.end_position();
+        Self {
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+            start: Position {
+                line: start_position.row + 1,
+                column: start_position.column + 1,
+            },
+            end: Position {
+                line: end_position.row + 1,
+                column: end_position.column + 1,
+            },
+        }
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Span {}
+impl Hash for Span {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.start)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SyntaxError {
+    pub kind: SyntaxErrorKind,
+    pub span: Span,
+    pub snippet: String,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SyntaxErrorKind {
+    // ERROR node
+    Unexpected,
+    // MISSING node, e.g. `}`
+    Missing(String),
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SyntaxErrorKind::Unexpected => {
+                write!(f, "unexpected `{}` at {}", self.snippet, self.span)
+            }
+            SyntaxErrorKind::Missing(kind) => write!(
+                f,
+                "missing `{}` at {} in `{}`",
+                kind, self.span, self.snippet
+            ),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("TreeSitterLanguageError {0}")]
     TreeSitterLanguageError(String),
     #[error("TreeSitterParseCodeFailed")]
     TreeSitterParseCodeFailed,
+    #[error("SyntaxErrors {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    SyntaxErrors(Vec<SyntaxError>),
 }
 
 #[cfg(test)]
@@ -62,14 +222,48 @@ mod tests {
                     Ok("var _ int;")
                 );
             }
-            Err(err) => assert!(false, "{:?}", err),
+            Err(err) => panic!("{:?}", err),
         }
 
         match Parser::new("var _ int;") {
             Ok(parser) => {
                 assert_eq!(parser.get_source(), b"var _ int;");
             }
-            Err(err) => assert!(false, "{:?}", err),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    #[test]
+    fn strict() {
+        match Parser::new_strict("type T struct { x int }") {
+            Ok(parser) => assert!(parser.get_syntax_errors().is_empty()),
+            Err(err) => panic!("{:?}", err),
+        }
+
+        match Parser::new_strict("type T struct {\n\tx int\n\ty int int\n}") {
+            Ok(_) => panic!(),
+            Err(Error::SyntaxErrors(syntax_errors)) => {
+                assert_eq!(syntax_errors.len(), 1);
+                assert_eq!(syntax_errors[0].kind, SyntaxErrorKind::Unexpected);
+                assert_eq!(syntax_errors[0].span.start.line, 3);
+                assert_eq!(syntax_errors[0].snippet, "int");
+            }
+            Err(err) => panic!("{:?}", err),
+        }
+
+        match Parser::new_strict("var _ = f(\n\t1") {
+            Ok(_) => panic!(),
+            Err(Error::SyntaxErrors(syntax_errors)) => {
+                assert_eq!(syntax_errors.len(), 1);
+                assert_eq!(
+                    syntax_errors[0].kind,
+                    SyntaxErrorKind::Missing(")".to_owned())
+                );
+                assert_eq!(syntax_errors[0].span.start.line, 2);
+                assert_eq!(syntax_errors[0].snippet, "1");
+                assert_eq!(syntax_errors[0].to_string(), "missing `)` at 2:3 in `1`");
+            }
+            Err(err) => panic!("{:?}", err),
         }
     }
 }
@@ -0,0 +1,85 @@
+use golang_parser::{tree_sitter::Node, Span};
+use golang_type_core::{FunctionType, ParameterDecl, TypeParamDecl};
+
+use crate::SourceFileParseError;
+
+// https://golang.org/ref/spec#Function_declarations
+// The body is not parsed.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FunctionDecl {
+    pub name: String,
+    pub type_params: Vec<TypeParamDecl>,
+    pub signature: FunctionType,
+    pub span: Span,
+}
+
+// https://golang.org/ref/spec#Method_declarations
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MethodDecl {
+    pub receiver: ParameterDecl,
+    pub name: String,
+    pub signature: FunctionType,
+    pub span: Span,
+}
+
+impl FunctionDecl {
+    pub(crate) fn from_function_declaration_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Self, SourceFileParseError> {
+        let node_name = node.child_by_field_name("name").ok_or_else(|| {
+            SourceFileParseError::NodeMissing("function name".to_string(), Span::from(node))
+        })?;
+        let name = node_name.utf8_text(source)?.to_owned();
+
+        let type_params =
+            if let Some(node_type_parameters) = node.child_by_field_name("type_parameters") {
+                TypeParamDecl::from_type_parameter_list_node(node_type_parameters, source)?
+            } else {
+                vec![]
+            };
+
+        let signature = FunctionType::from_signature_node(node, source)?;
+
+        Ok(Self {
+            name,
+            type_params,
+            signature,
+            span: Span::from(node),
+        })
+    }
+}
+
+impl MethodDecl {
+    pub(crate) fn from_method_declaration_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Self, SourceFileParseError> {
+        let node_receiver = node.child_by_field_name("receiver").ok_or_else(|| {
+            SourceFileParseError::NodeMissing("method receiver".to_string(), Span::from(node))
+        })?;
+        let receiver = ParameterDecl::from_parameter_list_node(node_receiver, source)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                SourceFileParseError::NodeMissing(
+                    "method receiver parameter_declaration".to_string(),
+                    Span::from(node_receiver),
+                )
+            })?;
+
+        let node_name = node.child_by_field_name("name").ok_or_else(|| {
+            SourceFileParseError::NodeMissing("method name".to_string(), Span::from(node))
+        })?;
+        let name = node_name.utf8_text(source)?.to_owned();
+
+        let signature = FunctionType::from_signature_node(node, source)?;
+
+        Ok(Self {
+            receiver,
+            name,
+            signature,
+            span: Span::from(node),
+        })
+    }
+}
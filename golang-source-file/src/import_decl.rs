@@ -0,0 +1,101 @@
+use golang_parser::{tree_sitter::Node, Span, NODE_KIND_COMMENT};
+
+use crate::SourceFileParseError;
+
+// https://golang.org/ref/spec#Import_declarations
+// e.g. `"fmt"`, `m "math"`, `. "strings"`, `_ "embed"`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ImportSpec {
+    // The alias, `.` or `_`; None if the package name is used.
+    pub name: Option<String>,
+    // Unquoted import path.
+    pub path: String,
+    pub span: Span,
+}
+
+impl ImportSpec {
+    pub(crate) fn from_import_declaration_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Vec<Self>, SourceFileParseError> {
+        let mut import_specs = vec![];
+
+        for node in node
+            .named_children(&mut node.walk())
+            .filter(|x| x.kind() != NODE_KIND_COMMENT)
+        {
+            match node.kind() {
+                "import_spec" => import_specs.push(Self::from_import_spec_node(node, source)?),
+                "import_spec_list" => {
+                    for node_import_spec in node
+                        .named_children(&mut node.walk())
+                        .filter(|x| x.kind() != NODE_KIND_COMMENT)
+                    {
+                        import_specs.push(Self::from_import_spec_node(node_import_spec, source)?);
+                    }
+                }
+                _ => {
+                    return Err(SourceFileParseError::NodeKindUnknown(
+                        node.kind().to_owned(),
+                        Span::from(node),
+                    ))
+                }
+            }
+        }
+
+        Ok(import_specs)
+    }
+
+    fn from_import_spec_node(node: Node, source: &[u8]) -> Result<Self, SourceFileParseError> {
+        if node.kind() != "import_spec" {
+            return Err(SourceFileParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        let name = if let Some(node_name) = node.child_by_field_name("name") {
+            Some(node_name.utf8_text(source)?.to_owned())
+        } else {
+            None
+        };
+
+        let node_path = node.child_by_field_name("path").ok_or_else(|| {
+            SourceFileParseError::NodeMissing("import_spec path".to_string(), Span::from(node))
+        })?;
+        let path = node_path.utf8_text(source)?;
+        // Import paths are interpreted or raw string literals without escapes.
+        let path = path[1..path.len() - 1].to_owned();
+
+        Ok(Self {
+            name,
+            path,
+            span: Span::from(node),
+        })
+    }
+
+    // The identifier the package is referred to by in this file, e.g. `json` of `"encoding/json"`.
+    // None for dot and blank imports.
+    pub fn package_name(&self) -> Option<&str> {
+        match self.name.as_deref() {
+            Some(".") | Some("_") => None,
+            Some(name) => Some(name),
+            // By convention, e.g. `chi` of `"github.com/go-chi/chi/v5"`.
+            None => {
+                let mut segments = self.path.rsplit('/');
+                match (segments.next(), segments.next()) {
+                    (Some(last), Some(prev)) if is_major_version(last) => Some(prev),
+                    (last, _) => last,
+                }
+            }
+        }
+    }
+}
+
+// https://golang.org/ref/mod#major-version-suffixes
+fn is_major_version(s: &str) -> bool {
+    match s.strip_prefix('v') {
+        Some(n) => !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    }
+}
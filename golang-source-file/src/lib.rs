@@ -0,0 +1,229 @@
+pub use golang_const_decl_core;
+pub use golang_type_core;
+pub use golang_type_decl_core;
+pub use golang_var_decl_core;
+
+use std::{
+    collections::HashMap,
+    str::{self, FromStr},
+};
+
+use golang_const_decl_core::{ConstDecl, ConstDeclParseError};
+use golang_parser::{tree_sitter::Node, Parser, Span, NODE_KIND_COMMENT};
+use golang_type_core::{ConstEvaluator, TypeParseError};
+use golang_type_decl_core::{TypeDecl, TypeDeclParseError, TypeSpec};
+use golang_var_decl_core::{VarDecl, VarDeclParseError};
+
+pub mod function_decl;
+pub mod import_decl;
+
+pub use self::function_decl::{FunctionDecl, MethodDecl};
+pub use self::import_decl::ImportSpec;
+
+// https://golang.org/ref/spec#Source_file_organization
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SourceFile {
+    // None if the source is a fragment without `package` clause.
+    pub package_name: Option<String>,
+    pub import_specs: Vec<ImportSpec>,
+    pub top_level_decls: Vec<TopLevelDecl>,
+}
+
+// https://golang.org/ref/spec#TopLevelDecl
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum TopLevelDecl {
+    ConstDecl(ConstDecl),
+    TypeDecl(TypeDecl),
+    VarDecl(VarDecl),
+    FunctionDecl(FunctionDecl),
+    MethodDecl(MethodDecl),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SourceFileParseError {
+    #[error("GolangParserError {0:?}")]
+    GolangParserError(#[from] golang_parser::Error),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(#[from] str::Utf8Error),
+    //
+    #[error("TypeParseError {0:?}")]
+    TypeParseError(#[from] TypeParseError),
+    #[error("TypeDeclParseError {0:?}")]
+    TypeDeclParseError(#[from] TypeDeclParseError),
+    #[error("ConstDeclParseError {0:?}")]
+    ConstDeclParseError(#[from] ConstDeclParseError),
+    #[error("VarDeclParseError {0:?}")]
+    VarDeclParseError(#[from] VarDeclParseError),
+}
+
+impl FromStr for SourceFile {
+    type Err = SourceFileParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new_strict(s)?;
+        let source = parser.get_source();
+        let root_node = parser.get_root_node();
+
+        Self::from_source_file_node(root_node, source)
+    }
+}
+
+impl SourceFile {
+    pub fn from_source_file_node(node: Node, source: &[u8]) -> Result<Self, SourceFileParseError> {
+        if node.kind() != "source_file" {
+            return Err(SourceFileParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        let mut package_name = None;
+        let mut import_specs = vec![];
+        let mut top_level_decls = vec![];
+
+        for node in node
+            .named_children(&mut node.walk())
+            .filter(|x| x.kind() != NODE_KIND_COMMENT)
+        {
+            match node.kind() {
+                "package_clause" => {
+                    let node_package_identifier = node.named_child(0).ok_or_else(|| {
+                        SourceFileParseError::NodeMissing(
+                            "package_identifier".to_string(),
+                            Span::from(node),
+                        )
+                    })?;
+                    package_name = Some(node_package_identifier.utf8_text(source)?.to_owned());
+                }
+                "import_declaration" => {
+                    import_specs.extend(ImportSpec::from_import_declaration_node(node, source)?);
+                }
+                "const_declaration" => {
+                    top_level_decls.push(TopLevelDecl::ConstDecl(
+                        ConstDecl::from_const_declaration_node(node, source)?,
+                    ));
+                }
+                "type_declaration" => {
+                    top_level_decls.push(TopLevelDecl::TypeDecl(
+                        TypeDecl::from_type_declaration_node(node, source)?,
+                    ));
+                }
+                "var_declaration" => {
+                    top_level_decls.push(TopLevelDecl::VarDecl(
+                        VarDecl::from_var_declaration_node(node, source)?,
+                    ));
+                }
+                "function_declaration" => {
+                    top_level_decls.push(TopLevelDecl::FunctionDecl(
+                        FunctionDecl::from_function_declaration_node(node, source)?,
+                    ));
+                }
+                "method_declaration" => {
+                    top_level_decls.push(TopLevelDecl::MethodDecl(
+                        MethodDecl::from_method_declaration_node(node, source)?,
+                    ));
+                }
+                _ => {
+                    return Err(SourceFileParseError::NodeKindUnknown(
+                        node.kind().to_owned(),
+                        Span::from(node),
+                    ))
+                }
+            }
+        }
+
+        let mut source_file = Self {
+            package_name,
+            import_specs,
+            top_level_decls,
+        };
+        source_file.resolve_array_lengths();
+
+        Ok(source_file)
+    }
+
+    // The constants of the const declarations, and the commonly used ones of the standard library.
+    // The ones failing to evaluate are skipped, e.g. the ones referring to other packages.
+    pub fn const_evaluator(&self) -> ConstEvaluator {
+        let mut evaluator = ConstEvaluator::with_std_constants();
+        // Package-level constants may refer to the ones declared later, so repeats while resolving more.
+        loop {
+            let len = evaluator.constants.len();
+            for const_decl in self.const_decls() {
+                let _ = const_decl.eval_with(&mut evaluator);
+            }
+            if evaluator.constants.len() == len {
+                break evaluator;
+            }
+        }
+    }
+
+    // e.g. `[Size]byte` with `const Size = 16`.
+    pub fn resolve_array_lengths(&mut self) {
+        let evaluator = self.const_evaluator();
+        for top_level_decl in self.top_level_decls.iter_mut() {
+            match top_level_decl {
+                TopLevelDecl::TypeDecl(type_decl) => {
+                    for type_spec in type_decl.type_specs.iter_mut() {
+                        match type_spec {
+                            TypeSpec::AliasDecl(alias_decl) => {
+                                alias_decl.r#type.resolve_array_lengths(&evaluator)
+                            }
+                            TypeSpec::TypeDef(type_def) => {
+                                type_def.r#type.resolve_array_lengths(&evaluator)
+                            }
+                        }
+                    }
+                }
+                TopLevelDecl::VarDecl(var_decl) => {
+                    for r#type in var_decl
+                        .var_specs
+                        .iter_mut()
+                        .filter_map(|var_spec| var_spec.r#type.as_mut())
+                    {
+                        r#type.resolve_array_lengths(&evaluator);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // The import paths by package name, e.g. `json` to `encoding/json`.
+    pub fn import_paths(&self) -> HashMap<String, String> {
+        self.import_specs
+            .iter()
+            .filter_map(|import_spec| {
+                import_spec
+                    .package_name()
+                    .map(|name| (name.to_owned(), import_spec.path.to_owned()))
+            })
+            .collect()
+    }
+
+    // All type specs of all type declarations, in source order.
+    pub fn type_specs(&self) -> impl Iterator<Item = &TypeSpec> {
+        self.top_level_decls.iter().flat_map(|x| match x {
+            TopLevelDecl::TypeDecl(type_decl) => type_decl.type_specs.iter(),
+            _ => [].iter(),
+        })
+    }
+
+    pub fn const_decls(&self) -> impl Iterator<Item = &ConstDecl> {
+        self.top_level_decls.iter().filter_map(|x| match x {
+            TopLevelDecl::ConstDecl(const_decl) => Some(const_decl),
+            _ => None,
+        })
+    }
+
+    pub fn var_decls(&self) -> impl Iterator<Item = &VarDecl> {
+        self.top_level_decls.iter().filter_map(|x| match x {
+            TopLevelDecl::VarDecl(var_decl) => Some(var_decl),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+// Package models is a sample of a typical upstream Go file.
+package models
+
+import "errors"
+
+import (
+	"encoding/json"
+	"fmt"
+	m "math"
+	_ "embed"
+	. "strings"
+)
+
+// Status of a user.
+type Status int
+
+const (
+	StatusActive Status = iota + 1
+	StatusBanned
+)
+
+var ErrNotFound = errors.New("not found")
+
+var (
+	maxAge  = m.MaxInt8
+	version string
+)
+
+type (
+	User struct {
+		ID     int64  `json:"id"`
+		Name   string `json:"name"`
+		Status Status `json:"status"`
+	}
+
+	Users = []User
+)
+
+func (s Status) String() string {
+	switch s {
+	case StatusActive:
+		return "active"
+	}
+	return fmt.Sprintf("Status(%d)", int(s))
+}
+
+func (u *User) MarshalJSON() ([]byte, error) {
+	type alias User
+	return json.Marshal((*alias)(u))
+}
+
+func Map[T, U any](s []T, f func(T) U) []U {
+	r := make([]U, 0, len(s))
+	for _, v := range s {
+		r = append(r, f(v))
+	}
+	return r
+}
+
+type Group struct {
+	Name  string `json:"name"`
+	Users Users  `json:"users"`
+}
+
+func init() {
+	_ = ToUpper(version)
+}
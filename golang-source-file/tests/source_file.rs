@@ -0,0 +1,214 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_source_file::{
+    golang_type_core::{FunctionResult, ParameterDecl, PointerType, Type, TypeName},
+    golang_type_decl_core::TypeSpec,
+    ImportSpec, SourceFile, TopLevelDecl,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/models.go"))?;
+
+    let source_file: SourceFile = content.parse()?;
+
+    assert_eq!(source_file.package_name, Some("models".to_owned()));
+
+    assert_eq!(
+        source_file.import_specs,
+        vec![
+            ImportSpec {
+                name: None,
+                path: "errors".to_owned(),
+                span: Default::default(),
+            },
+            ImportSpec {
+                name: None,
+                path: "encoding/json".to_owned(),
+                span: Default::default(),
+            },
+            ImportSpec {
+                name: None,
+                path: "fmt".to_owned(),
+                span: Default::default(),
+            },
+            ImportSpec {
+                name: Some("m".to_owned()),
+                path: "math".to_owned(),
+                span: Default::default(),
+            },
+            ImportSpec {
+                name: Some("_".to_owned()),
+                path: "embed".to_owned(),
+                span: Default::default(),
+            },
+            ImportSpec {
+                name: Some(".".to_owned()),
+                path: "strings".to_owned(),
+                span: Default::default(),
+            },
+        ]
+    );
+    assert_eq!(
+        source_file
+            .import_specs
+            .iter()
+            .map(|x| x.package_name())
+            .collect::<Vec<_>>(),
+        vec![
+            Some("errors"),
+            Some("json"),
+            Some("fmt"),
+            Some("m"),
+            None,
+            None
+        ]
+    );
+
+    let kinds: Vec<_> = source_file
+        .top_level_decls
+        .iter()
+        .map(|x| match x {
+            TopLevelDecl::ConstDecl(_) => "const",
+            TopLevelDecl::TypeDecl(_) => "type",
+            TopLevelDecl::VarDecl(_) => "var",
+            TopLevelDecl::FunctionDecl(_) => "func",
+            TopLevelDecl::MethodDecl(_) => "method",
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec!["type", "const", "var", "var", "type", "method", "method", "func", "type", "func"]
+    );
+
+    assert_eq!(
+        source_file
+            .type_specs()
+            .map(|x| x.name())
+            .collect::<Vec<_>>(),
+        vec!["Status", "User", "Users", "Group"]
+    );
+
+    assert_eq!(source_file.const_decls().count(), 1);
+    assert_eq!(source_file.var_decls().count(), 2);
+
+    match &source_file.top_level_decls[6] {
+        TopLevelDecl::MethodDecl(method_decl) => {
+            assert_eq!(method_decl.name, "MarshalJSON");
+            assert_eq!(
+                method_decl.receiver,
+                ParameterDecl {
+                    names: vec!["u".to_owned()],
+                    r#type: Type::PointerType(PointerType(
+                        Type::TypeName(TypeName::Identifier("User".to_owned())).into()
+                    ))
+                    .into(),
+                    is_variadic: false,
+                }
+            );
+            assert!(matches!(
+                &method_decl.signature.result,
+                Some(FunctionResult::Parameters(parameters)) if parameters.len() == 2
+            ));
+        }
+        x => panic!("{:?}", x),
+    }
+
+    match &source_file.top_level_decls[7] {
+        TopLevelDecl::FunctionDecl(function_decl) => {
+            assert_eq!(function_decl.name, "Map");
+            assert_eq!(function_decl.type_params.len(), 1);
+            assert_eq!(function_decl.type_params[0].names, vec!["T", "U"]);
+            assert_eq!(function_decl.signature.parameters.len(), 2);
+        }
+        x => panic!("{:?}", x),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_fragment() -> Result<(), Box<dyn error::Error>> {
+    let source_file: SourceFile = "type A int\ntype B string".parse()?;
+    assert_eq!(source_file.package_name, None);
+    assert!(source_file.import_specs.is_empty());
+    assert_eq!(source_file.type_specs().count(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_resolve_array_lengths() -> Result<(), Box<dyn error::Error>> {
+    let source_file: SourceFile = r#"
+type Hash [Size]byte
+
+const Size = 2 * BlockSize
+
+const BlockSize = 8
+
+var (
+	Zero   Hash
+	Blocks [Size / BlockSize][BlockSize]byte
+	Other  [pkg.Size]byte
+)
+"#
+    .parse()?;
+
+    let types: Vec<_> = source_file
+        .type_specs()
+        .map(|type_spec| match type_spec {
+            TypeSpec::TypeDef(type_def) => type_def.r#type.to_string(),
+            TypeSpec::AliasDecl(alias_decl) => alias_decl.r#type.to_string(),
+        })
+        .chain(
+            source_file
+                .var_decls()
+                .flat_map(|var_decl| var_decl.var_specs.iter())
+                .filter_map(|var_spec| var_spec.r#type.as_ref().map(ToString::to_string)),
+        )
+        .collect();
+    assert_eq!(
+        types,
+        vec!["[16]byte", "Hash", "[2][8]byte", "[pkg.Size]byte"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_import_paths() -> Result<(), Box<dyn error::Error>> {
+    let source_file: SourceFile = r#"
+package models
+
+import (
+	"github.com/go-chi/chi/v5"
+	"github.com/shopspring/decimal"
+	id "github.com/google/uuid"
+	_ "embed"
+)
+"#
+    .parse()?;
+
+    let mut import_paths: Vec<_> = source_file.import_paths().into_iter().collect();
+    import_paths.sort();
+    assert_eq!(
+        import_paths,
+        vec![
+            ("chi".to_owned(), "github.com/go-chi/chi/v5".to_owned()),
+            (
+                "decimal".to_owned(),
+                "github.com/shopspring/decimal".to_owned()
+            ),
+            ("id".to_owned(), "github.com/google/uuid".to_owned()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_syntax_error() {
+    assert!("package models\n\ntype A struct {"
+        .parse::<SourceFile>()
+        .is_err());
+}
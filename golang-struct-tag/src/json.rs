@@ -1,3 +1,5 @@
+use std::fmt;
+
 use pest::iterators::Pairs;
 
 use crate::{convention_struct_tag_parser::Rule, StructTagParseError};
@@ -54,3 +56,34 @@ impl JsonStructTag {
         Ok(Self::Normal(name, options))
     }
 }
+
+// The tag value, e.g. `name,omitempty`
+impl fmt::Display for JsonStructTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ignored => write!(f, "-"),
+            Self::Normal(name, options) => {
+                let name = name.as_deref().unwrap_or_default();
+                write!(f, "{}", name)?;
+                // `-,` names the field "-" instead of ignoring it.
+                if name == "-" && options.is_empty() {
+                    write!(f, ",")?;
+                }
+                for option in options {
+                    write!(f, ",{}", option)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for JsonStructTagOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => write!(f, "string"),
+            Self::Omitempty => write!(f, "omitempty"),
+            Self::Unknown(s) => write!(f, "{}", s),
+        }
+    }
+}
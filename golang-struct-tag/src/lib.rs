@@ -1,11 +1,12 @@
 use std::{
     cmp::PartialEq,
     collections::HashSet,
+    fmt,
     hash::{Hash, Hasher},
     str::{self, FromStr},
 };
 
-use golang_parser::{tree_sitter::Node, Parser};
+use golang_parser::{tree_sitter::Node, Parser, Span};
 use pest::{iterators::Pairs, Parser as _};
 
 // https://github.com/pest-parser/pest/issues/490#issuecomment-808942497
@@ -62,14 +63,41 @@ impl StructTag {
     }
 }
 
+impl fmt::Display for StructTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RawStringLiteral(s) | Self::InterpretedStringLiteral(s) => write!(f, "{}", s),
+            Self::Convention(set) => {
+                // The set is unordered, so print `json` first and the others sorted by key.
+                let mut pairs: Vec<_> = set.iter().collect();
+                pairs.sort_by_key(|pair| match pair {
+                    ConventionStructTag::Json(_) => (0, ""),
+                    ConventionStructTag::Unknown(key, _) => (1, key.as_str()),
+                });
+                let pairs: Vec<_> = pairs.iter().map(ToString::to_string).collect();
+                write!(f, "`{}`", pairs.join(" "))
+            }
+        }
+    }
+}
+
+impl fmt::Display for ConventionStructTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(json_struct_tag) => write!(f, r#"json:"{}""#, json_struct_tag),
+            Self::Unknown(key, value) => write!(f, r#"{}:"{}""#, key, value),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum StructTagParseError {
     #[error("GolangParserError {0:?}")]
     GolangParserError(#[from] golang_parser::Error),
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
-    #[error("NodeKindUnknown {0}")]
-    NodeKindUnknown(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
     #[error("Utf8Error {0:?}")]
     Utf8Error(str::Utf8Error),
     #[error("Unknown")]
@@ -80,7 +108,7 @@ impl FromStr for StructTag {
     type Err = StructTagParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parser = Parser::new(format!("type _ struct {{ string {} }};", s))?;
+        let parser = Parser::new_strict(format!("type _ struct {{ string {} }};", s))?;
         let source = parser.get_source();
         let root_node = parser.get_root_node();
 
@@ -89,33 +117,64 @@ impl FromStr for StructTag {
         let node_type_declaration = root_node
             .named_children(&mut cursor)
             .find(|node| node.kind() == "type_declaration")
-            .ok_or_else(|| StructTagParseError::NodeMissing("type_declaration".to_string()))?;
+            .ok_or_else(|| {
+                StructTagParseError::NodeMissing(
+                    "type_declaration".to_string(),
+                    Span::from(root_node),
+                )
+            })?;
         let node_type_spec = node_type_declaration
             .named_children(&mut cursor)
             .find(|node| node.kind() == "type_spec")
-            .ok_or_else(|| StructTagParseError::NodeMissing("type_spec".to_string()))?;
-        let _ = node_type_spec
-            .named_child(0)
-            .ok_or_else(|| StructTagParseError::NodeMissing("type_spec name".to_string()))?;
-        let node_struct_type = node_type_spec
-            .named_child(1)
-            .ok_or_else(|| StructTagParseError::NodeMissing("type_spec type".to_string()))?;
+            .ok_or_else(|| {
+                StructTagParseError::NodeMissing(
+                    "type_spec".to_string(),
+                    Span::from(node_type_declaration),
+                )
+            })?;
+        let _ = node_type_spec.named_child(0).ok_or_else(|| {
+            StructTagParseError::NodeMissing(
+                "type_spec name".to_string(),
+                Span::from(node_type_spec),
+            )
+        })?;
+        let node_struct_type = node_type_spec.named_child(1).ok_or_else(|| {
+            StructTagParseError::NodeMissing(
+                "type_spec type".to_string(),
+                Span::from(node_type_spec),
+            )
+        })?;
         let node_field_declaration_list = node_struct_type
             .named_children(&mut cursor)
             .find(|node| node.kind() == "field_declaration_list")
             .ok_or_else(|| {
-                StructTagParseError::NodeMissing("field_declaration_list".to_string())
+                StructTagParseError::NodeMissing(
+                    "field_declaration_list".to_string(),
+                    Span::from(node_struct_type),
+                )
             })?;
         let node_field_declaration = node_field_declaration_list
             .named_children(&mut cursor)
             .find(|node| node.kind() == "field_declaration")
-            .ok_or_else(|| StructTagParseError::NodeMissing("field_declaration".to_string()))?;
+            .ok_or_else(|| {
+                StructTagParseError::NodeMissing(
+                    "field_declaration".to_string(),
+                    Span::from(node_field_declaration_list),
+                )
+            })?;
         let _ = node_field_declaration.named_child(0).ok_or_else(|| {
-            StructTagParseError::NodeMissing("field_declaration type".to_string())
+            StructTagParseError::NodeMissing(
+                "field_declaration type".to_string(),
+                Span::from(node_field_declaration),
+            )
         })?;
-        let node_field_declaration_tag = node_field_declaration
-            .named_child(1)
-            .ok_or_else(|| StructTagParseError::NodeMissing("field_declaration tag".to_string()))?;
+        let node_field_declaration_tag =
+            node_field_declaration.named_child(1).ok_or_else(|| {
+                StructTagParseError::NodeMissing(
+                    "field_declaration tag".to_string(),
+                    Span::from(node_field_declaration),
+                )
+            })?;
 
         match node_field_declaration_tag.kind() {
             "raw_string_literal" => {
@@ -126,6 +185,7 @@ impl FromStr for StructTag {
             }
             _ => Err(StructTagParseError::NodeKindUnknown(
                 node_field_declaration_tag.kind().to_owned(),
+                Span::from(node_field_declaration_tag),
             )),
         }
     }
@@ -228,6 +288,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_display() -> Result<(), Box<dyn error::Error>> {
+        for s in &[
+            r#"`foo"bar`"#,
+            r#""foo`bar""#,
+            r#"`json:"-"`"#,
+            r#"`json:"-,"`"#,
+            r#"`json:",omitempty"`"#,
+            r#"`json:"foo,string,omitempty" xml:"foo" yaml:"bar"`"#,
+        ] {
+            let struct_tag: StructTag = s.parse()?;
+            assert_eq!(&struct_tag.to_string(), s);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_json_struct_tag() {
         assert_eq!(
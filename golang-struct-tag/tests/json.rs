@@ -147,7 +147,7 @@ fn test_parse() -> Result<(), Box<dyn error::Error>> {
                 ),
                 str.parse()?
             ),
-            _ => assert!(false),
+            _ => panic!(),
         }
     }
 
@@ -1,6 +1,6 @@
-use std::str;
+use std::{fmt, str};
 
-use golang_parser::tree_sitter::Node;
+use golang_parser::{tree_sitter::Node, Span};
 use golang_type_core::{Type, TypeParseError};
 
 #[cfg(feature = "enable-quote-to_tokens")]
@@ -10,12 +10,13 @@ pub mod type_alias;
 pub struct AliasDecl {
     pub name: String,
     pub r#type: Type,
+    pub span: Span,
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum AliasDeclParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(&'static str),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(&'static str, Span),
     #[error("Utf8Error {0:?}")]
     Utf8Error(#[from] str::Utf8Error),
     #[error("TypeParseError {0:?}")]
@@ -31,17 +32,24 @@ impl AliasDecl {
 
         let node_name = node
             .named_child(0)
-            .ok_or(AliasDeclParseError::NodeMissing("name"))?;
+            .ok_or(AliasDeclParseError::NodeMissing("name", Span::from(node)))?;
         let name = node_name.utf8_text(source)?;
 
         let node_type = node
             .named_child(1)
-            .ok_or(AliasDeclParseError::NodeMissing("type"))?;
+            .ok_or(AliasDeclParseError::NodeMissing("type", Span::from(node)))?;
         let r#type = Type::from_node(node_type, source)?;
 
         Ok(Self {
             name: name.to_owned(),
             r#type,
+            span: Span::from(node),
         })
     }
 }
+
+impl fmt::Display for AliasDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.r#type)
+    }
+}
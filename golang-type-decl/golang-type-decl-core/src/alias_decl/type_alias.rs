@@ -1,10 +1,11 @@
 use convert_case::{Case, Casing as _};
-use golang_type_core::Type;
+use golang_type_core::{Type, TypeMappings, TypeParamDecl};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
 
 pub struct TypeAlias {
     pub name: String,
+    pub type_params: Vec<TypeParamDecl>,
     pub r#type: Type,
     pub opt: TypeAliasOption,
 }
@@ -13,6 +14,8 @@ pub struct TypeAlias {
 pub struct TypeAliasOption {
     pub alias_name: Option<String>,
     pub special_type: Option<TokenStream>,
+    // The Rust types of the types of other packages, e.g. `time.Time`.
+    pub type_mappings: TypeMappings,
 }
 
 impl ToTokens for TypeAlias {
@@ -28,12 +31,24 @@ impl ToTokens for TypeAlias {
         let type_token = if let Some(special_type) = &self.opt.special_type {
             special_type.to_owned()
         } else {
-            let r#type = &self.r#type;
-            quote!(#r#type)
+            self.r#type.to_token_stream_with(&self.opt.type_mappings)
+        };
+
+        // Bounds on type aliases are not enforced, so only the names are emitted.
+        let type_param_names: Vec<_> = self
+            .type_params
+            .iter()
+            .flat_map(|type_param| type_param.names.iter())
+            .map(|name| format_ident!("{}", name))
+            .collect();
+        let generics = if type_param_names.is_empty() {
+            quote!()
+        } else {
+            quote!(<#(#type_param_names),*>)
         };
 
         let token = quote! {
-            pub type #name = #type_token;
+            pub type #name #generics = #type_token;
         };
 
         tokens.append_all(token);
@@ -1,8 +1,9 @@
 pub use golang_type_core;
 
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use golang_parser::{Parser, NODE_KIND_COMMENT};
+use golang_parser::{tree_sitter::Node, Parser, Span, NODE_KIND_COMMENT};
+use golang_type_core::{printer, TypeParamDecl};
 
 pub mod alias_decl;
 pub mod type_def;
@@ -25,8 +26,8 @@ pub enum TypeSpec {
 pub enum TypeDeclParseError {
     #[error("GolangParserError {0:?}")]
     GolangParserError(#[from] golang_parser::Error),
-    #[error("NodeKindUnknown {0}")]
-    NodeKindUnknown(String),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
     //
     #[error("AliasDeclParseError {0:?}")]
     AliasDeclParseError(#[from] AliasDeclParseError),
@@ -38,43 +39,123 @@ impl FromStr for TypeDecl {
     type Err = TypeDeclParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parser = Parser::new(s)?;
+        let parser = Parser::new_strict(s)?;
         let source = parser.get_source();
         let root_node = parser.get_root_node();
 
-        let mut type_specs = vec![];
-
         let mut cursor = root_node.walk();
         let mut node_source_file_named_children_iter = root_node
             .named_children(&mut cursor)
             .filter(|x| x.kind() != NODE_KIND_COMMENT);
 
-        if let Some(node_type_declaration) = node_source_file_named_children_iter.next() {
-            for node in node_type_declaration
-                .named_children(&mut node_type_declaration.walk())
-                .filter(|x| x.kind() != NODE_KIND_COMMENT)
-            {
-                match node.kind() {
-                    "type_alias" => {
-                        let type_spec =
-                            TypeSpec::AliasDecl(AliasDecl::from_type_alias_node(node, source)?);
-                        type_specs.push(type_spec);
-                    }
-                    "type_spec" => {
-                        let type_spec =
-                            TypeSpec::TypeDef(TypeDef::from_type_spec_node(node, source)?);
-                        type_specs.push(type_spec);
-                    }
-                    _ => return Err(TypeDeclParseError::NodeKindUnknown(node.kind().to_owned())),
+        let type_decl =
+            if let Some(node_type_declaration) = node_source_file_named_children_iter.next() {
+                Self::from_type_declaration_node(node_type_declaration, source)?
+            } else {
+                Self { type_specs: vec![] }
+            };
+
+        if let Some(node) = node_source_file_named_children_iter.next() {
+            return Err(TypeDeclParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        Ok(type_decl)
+    }
+}
+
+impl TypeDecl {
+    pub fn from_type_declaration_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Self, TypeDeclParseError> {
+        if node.kind() != "type_declaration" {
+            return Err(TypeDeclParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        let mut type_specs = vec![];
+
+        for node in node
+            .named_children(&mut node.walk())
+            .filter(|x| x.kind() != NODE_KIND_COMMENT)
+        {
+            match node.kind() {
+                "type_alias" => {
+                    let type_spec =
+                        TypeSpec::AliasDecl(AliasDecl::from_type_alias_node(node, source)?);
+                    type_specs.push(type_spec);
+                }
+                "type_spec" => {
+                    let type_spec = TypeSpec::TypeDef(TypeDef::from_type_spec_node(node, source)?);
+                    type_specs.push(type_spec);
+                }
+                _ => {
+                    return Err(TypeDeclParseError::NodeKindUnknown(
+                        node.kind().to_owned(),
+                        Span::from(node),
+                    ))
                 }
             }
         }
 
-        if let Some(node) = node_source_file_named_children_iter.next() {
-            return Err(TypeDeclParseError::NodeKindUnknown(node.kind().to_owned()));
+        Ok(Self { type_specs })
+    }
+}
+
+// https://golang.org/ref/spec#Type_declarations
+impl fmt::Display for TypeDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.type_specs.as_slice() {
+            [type_spec] => write!(f, "type {}", type_spec),
+            type_specs => {
+                if type_specs.is_empty() {
+                    return write!(f, "type ()");
+                }
+                let rows: Vec<_> = type_specs.iter().map(TypeSpec::printer_row).collect();
+                write!(f, "{}", printer::block("type (", &rows, ")"))
+            }
         }
+    }
+}
 
-        Ok(Self { type_specs })
+impl fmt::Display for TypeSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AliasDecl(alias_decl) => write!(f, "{}", alias_decl),
+            Self::TypeDef(type_def) => write!(f, "{}", type_def),
+        }
+    }
+}
+
+impl TypeSpec {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::AliasDecl(alias_decl) => &alias_decl.name,
+            Self::TypeDef(type_def) => &type_def.name,
+        }
+    }
+
+    // Names of grouped specs are aligned.
+    fn printer_row(&self) -> Vec<String> {
+        match self {
+            Self::AliasDecl(alias_decl) => vec![
+                alias_decl.name.to_owned(),
+                format!("= {}", alias_decl.r#type),
+            ],
+            Self::TypeDef(type_def) => vec![
+                format!(
+                    "{}{}",
+                    type_def.name,
+                    TypeParamDecl::list(&type_def.type_params)
+                ),
+                type_def.r#type.to_string(),
+            ],
+        }
     }
 }
 
@@ -86,7 +167,22 @@ mod tests {
     fn test_parse_empty() {
         match "type ()".parse::<TypeDecl>() {
             Ok(type_decl) => assert_eq!(type_decl.type_specs.len(), 0),
-            Err(err) => assert!(false, "{:?}", err),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_parse_syntax_error() {
+        match "type User struct {\n\tName string\n\tAge uint uint\n}".parse::<TypeDecl>() {
+            Ok(_) => panic!(),
+            Err(TypeDeclParseError::GolangParserError(golang_parser::Error::SyntaxErrors(
+                syntax_errors,
+            ))) => {
+                assert_eq!(syntax_errors.len(), 1);
+                assert_eq!(syntax_errors[0].span.start.line, 3);
+                assert_eq!(syntax_errors[0].snippet, "uint");
+            }
+            Err(err) => panic!("{:?}", err),
         }
     }
 
@@ -102,7 +198,7 @@ mod tests {
         .parse::<TypeDecl>()
         {
             Ok(type_decl) => assert_eq!(type_decl.type_specs.len(), 0),
-            Err(err) => assert!(false, "{:?}", err),
+            Err(err) => panic!("{:?}", err),
         }
     }
 }
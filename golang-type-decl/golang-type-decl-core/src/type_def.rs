@@ -1,7 +1,7 @@
-use std::str;
+use std::{fmt, str};
 
-use golang_parser::tree_sitter::Node;
-use golang_type_core::{Type, TypeParseError};
+use golang_parser::{tree_sitter::Node, Span};
+use golang_type_core::{Type, TypeParamDecl, TypeParseError};
 
 #[cfg(feature = "enable-quote-to_tokens")]
 pub mod json_struct;
@@ -9,13 +9,15 @@ pub mod json_struct;
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct TypeDef {
     pub name: String,
+    pub type_params: Vec<TypeParamDecl>,
     pub r#type: Type,
+    pub span: Span,
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum TypeDefParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(&'static str),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(&'static str, Span),
     #[error("Utf8Error {0:?}")]
     Utf8Error(#[from] str::Utf8Error),
     #[error("TypeParseError {0:?}")]
@@ -30,18 +32,39 @@ impl TypeDef {
         debug_assert!(node.kind() == "type_spec");
 
         let node_name = node
-            .named_child(0)
-            .ok_or(TypeDefParseError::NodeMissing("name"))?;
+            .child_by_field_name("name")
+            .ok_or(TypeDefParseError::NodeMissing("name", Span::from(node)))?;
         let name = node_name.utf8_text(source)?;
 
+        let type_params =
+            if let Some(node_type_params) = node.child_by_field_name("type_parameters") {
+                TypeParamDecl::from_type_parameter_list_node(node_type_params, source)?
+            } else {
+                vec![]
+            };
+
         let node_type = node
-            .named_child(1)
-            .ok_or(TypeDefParseError::NodeMissing("type"))?;
+            .child_by_field_name("type")
+            .ok_or(TypeDefParseError::NodeMissing("type", Span::from(node)))?;
         let r#type = Type::from_node(node_type, source)?;
 
         Ok(Self {
             name: name.to_owned(),
+            type_params,
             r#type,
+            span: Span::from(node),
         })
     }
 }
+
+impl fmt::Display for TypeDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{} {}",
+            self.name,
+            TypeParamDecl::list(&self.type_params),
+            self.r#type
+        )
+    }
+}
@@ -3,25 +3,42 @@ use std::collections::HashMap;
 use convert_case::{Case, Casing as _};
 use golang_type_core::{
     golang_struct_tag::{JsonStructTag, JsonStructTagOption},
-    StructField, StructType, Type, TypeName,
+    is_exported, is_recursive, ArrayLength, ArrayType, EmbeddedField, FieldDecl, GenericType,
+    MapType, ParenthesizedType, PointerType, SliceType, StructField, StructType, Type,
+    TypeMappings, TypeName, TypeParamDecl,
 };
-use proc_macro2::{Punct, Spacing, TokenStream};
+use proc_macro2::{Ident, Punct, Spacing, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
 
+mod promotion;
+
+use self::promotion::Promotion;
+
 pub struct JsonStruct {
     pub name: String,
+    pub type_params: Vec<TypeParamDecl>,
     pub struct_type: StructType,
     pub opt: JsonStructOption,
     pub field_opts: HashMap<JsonStructFieldName, JsonStructFieldOption>,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct JsonStructOption {
     pub enable_derive_serde_ser: bool,
     pub enable_derive_serde_de: bool,
     pub custom_derive: Vec<String>,
     //
     pub alias_name: Option<String>,
+    // `[16]byte` to `[u8; 16]` instead of `Vec<u8>`.
+    pub enable_fixed_size_array: bool,
+    // The Rust types of the types of other packages, e.g. `time.Time`.
+    pub type_mappings: TypeMappings,
+    // The declared types, the fields of the embedded struct types of which are promoted.
+    pub types: HashMap<String, Type>,
+    // (Un)marshal the unexported fields too, e.g. `bar uint`.
+    pub enable_unexported_fields: bool,
+    // Match the object keys to the fields case-insensitively on decode, like encoding/json.
+    pub enable_case_insensitive_de: bool,
 }
 impl JsonStructOption {
     fn has_derive(&self) -> bool {
@@ -33,6 +50,11 @@ impl JsonStructOption {
     fn has_serde_derive(&self) -> bool {
         self.enable_derive_serde_ser || self.enable_derive_serde_de
     }
+
+    // The derived impls are the inherent functions, wrapped by the custom impls.
+    fn has_serde_remote(&self) -> bool {
+        self.enable_derive_serde_de && self.enable_case_insensitive_de
+    }
 }
 
 pub type JsonStructFieldName = String;
@@ -43,140 +65,486 @@ pub struct JsonStructFieldOption {
     //
     pub attr_serde_deserialize_with: Option<String>,
     pub box_type: bool,
+    // `*T` to `T` instead of `Option<T>`, `null` is rejected then.
+    pub flatten_pointer: bool,
 }
 
-impl ToTokens for JsonStruct {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let struct_name = format_ident!(
-            "{}",
-            self.opt
-                .alias_name
-                .to_owned()
-                .unwrap_or_else(|| self.name.to_case(Case::Pascal))
+impl JsonStruct {
+    // The Rust struct of the Go struct type, the anonymous struct types of which are hoisted and
+    // the fields of the embedded struct types are promoted.
+    pub fn layout(&self) -> JsonStructLayout<'_> {
+        let struct_name = self
+            .opt
+            .alias_name
+            .to_owned()
+            .unwrap_or_else(|| self.name.to_case(Case::Pascal));
+        let (struct_type, mut nested_json_structs) = self.hoist_struct_types(&struct_name);
+        let promotion = Promotion::new(
+            &struct_name,
+            &struct_type,
+            &self.opt.types,
+            &|depth, name| self.is_serde_visible(depth, name),
         );
-        let struct_fields: Vec<_> = self
-            .struct_type
+        nested_json_structs.extend(promotion.copies.iter().map(|copy| {
+            let mut types = self.opt.types.to_owned();
+            types.extend(copy.types.to_owned());
+            JsonStruct {
+                name: copy.name.to_owned(),
+                type_params: self.referenced_type_params(&copy.struct_type),
+                struct_type: copy.struct_type.to_owned(),
+                opt: JsonStructOption {
+                    alias_name: None,
+                    types,
+                    ..self.opt.to_owned()
+                },
+                field_opts: copy
+                    .embedded_copies
+                    .iter()
+                    .map(|(field_name, copy_name)| {
+                        let copy_name = format_ident!("{}", copy_name);
+                        (
+                            field_name.to_owned(),
+                            JsonStructFieldOption {
+                                special_type: Some(quote!(#copy_name)),
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect(),
+            }
+        }));
+
+        let fields = struct_type
             .field_decls
             .iter()
-            .map(|field_decl| {
-                let as_json_struct_tag = if let Some(tag) = &field_decl.tag {
-                    tag.as_json_struct_tag()
-                } else {
-                    None
-                };
+            .enumerate()
+            .flat_map(|(i, field_decl)| self.layout_fields(i, field_decl, &promotion))
+            .collect();
 
-                let is_ignored = if let Some(JsonStructTag::Ignored) = as_json_struct_tag {
-                    Some(true)
-                } else {
-                    None
-                };
+        JsonStructLayout {
+            json_field_names: self.json_field_names(&struct_type, &promotion),
+            struct_name,
+            fields,
+            nested_json_structs,
+        }
+    }
 
-                let rename = if let Some(JsonStructTag::Normal(rename, _)) = as_json_struct_tag {
-                    rename.to_owned()
-                } else {
-                    None
-                };
-                let is_omitempty =
-                    if let Some(JsonStructTag::Normal(_, options)) = as_json_struct_tag {
-                        Some(options.contains(&JsonStructTagOption::Omitempty))
+    fn layout_fields(
+        &self,
+        i: usize,
+        field_decl: &FieldDecl,
+        promotion: &Promotion,
+    ) -> Vec<JsonStructField<'_>> {
+        let as_json_struct_tag = if let Some(tag) = &field_decl.tag {
+            tag.as_json_struct_tag()
+        } else {
+            None
+        };
+
+        let is_ignored = if let Some(JsonStructTag::Ignored) = as_json_struct_tag {
+            Some(true)
+        } else {
+            None
+        };
+
+        let rename = if let Some(JsonStructTag::Normal(rename, _)) = as_json_struct_tag {
+            rename.to_owned()
+        } else {
+            None
+        };
+        let is_omitempty = if let Some(JsonStructTag::Normal(_, options)) = as_json_struct_tag {
+            Some(options.contains(&JsonStructTagOption::Omitempty))
+        } else {
+            None
+        };
+        let is_string = if let Some(JsonStructTag::Normal(_, options)) = as_json_struct_tag {
+            Some(options.contains(&JsonStructTagOption::String))
+        } else {
+            None
+        };
+
+        match &field_decl.struct_field {
+            StructField::IdentifierListType(names, r#type) => names
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| x != &"_")
+                .map(|(j, name)| {
+                    if promotion.unexported.contains(&(i, j)) {
+                        return self.unexported_field(name, *r#type.to_owned());
+                    }
+
+                    // Hidden by the dominant fields of the same name.
+                    let is_ignored = if promotion.hidden.contains(&(i, j)) {
+                        Some(true)
                     } else {
-                        None
+                        is_ignored
                     };
-                let is_string = if let Some(JsonStructTag::Normal(_, options)) = as_json_struct_tag
-                {
-                    Some(options.contains(&JsonStructTagOption::String))
-                } else {
-                    None
-                };
-
-                match &field_decl.struct_field {
-                    StructField::IdentifierListType(names, r#type) => names
-                        .iter()
-                        .filter(|x| x != &"_")
-                        .map(|name| {
-                            let field_opt = self
-                                .field_opts
-                                .get(name)
-                                .map(ToOwned::to_owned)
-                                .unwrap_or_default();
-
-                            let field_name = format_ident!("r#{}", name.to_case(Case::Snake));
-                            let field_type = JsonStructFieldType {
-                                r#type: *r#type.to_owned(),
-                                is_ignored,
-                                is_string,
-                                is_omitempty,
-                                special_type: field_opt.special_type,
-                                box_type: field_opt.box_type,
-                            };
-
-                            if self.opt.has_serde_derive() {
-                                let field_serde_attr = JsonStructFieldSerdeAttr {
-                                    rename: rename.to_owned().unwrap_or_else(|| name.to_owned()),
-                                    is_ignored,
-                                    is_omitempty,
-                                    attr_serde_deserialize_with: field_opt
-                                        .attr_serde_deserialize_with,
-                                    enable_serde_ser: self.opt.enable_derive_serde_ser,
-                                    enable_serde_de: self.opt.enable_derive_serde_de,
-                                };
-
-                                quote! {
-                                    #[serde(#field_serde_attr)]
-                                    pub #field_name: #field_type,
-                                }
-                            } else {
-                                quote! {
-                                    pub #field_name: #field_type,
-                                }
-                            }
-                        })
-                        .collect(),
-                    StructField::EmbeddedField(embedded_field) => {
-                        let name = embedded_field.name();
 
-                        let field_opt = self
-                            .field_opts
-                            .get(&name)
-                            .map(ToOwned::to_owned)
-                            .unwrap_or_default();
+                    let field_opt = self
+                        .field_opts
+                        .get(name)
+                        .map(ToOwned::to_owned)
+                        .unwrap_or_default();
 
-                        let field_name = format_ident!("r#{}", name.to_case(Case::Snake));
-                        let field_type = JsonStructFieldType {
-                            r#type: embedded_field.r#type(),
+                    JsonStructField {
+                        name: name.to_owned(),
+                        kind: JsonStructFieldKind::Normal,
+                        rename: rename.to_owned().unwrap_or_else(|| name.to_owned()),
+                        attr_serde_deserialize_with: field_opt.attr_serde_deserialize_with,
+                        field_type: JsonStructFieldType {
+                            r#type: *r#type.to_owned(),
                             is_ignored,
                             is_string,
                             is_omitempty,
                             special_type: field_opt.special_type,
+                            box_type: field_opt.box_type
+                                || (!field_opt.flatten_pointer && self.is_recursive(r#type)),
+                            flatten_pointer: field_opt.flatten_pointer,
+                            enable_fixed_size_array: self.opt.enable_fixed_size_array,
+                            type_mappings: &self.opt.type_mappings,
+                            types: &self.opt.types,
+                        },
+                        opt: &self.opt,
+                    }
+                })
+                .collect(),
+            StructField::EmbeddedField(embedded_field) => {
+                let name = embedded_field.name();
+
+                if promotion.unexported.contains(&(i, 0)) {
+                    return vec![self.unexported_field(&name, embedded_field_type(embedded_field))];
+                }
+
+                let field_opt = self
+                    .field_opts
+                    .get(&name)
+                    .map(ToOwned::to_owned)
+                    .unwrap_or_default();
+
+                if promotion.flattened.contains(&i) {
+                    let copy_name = promotion.embedded_copies.get(&i).cloned();
+                    let special_type = field_opt.special_type.or_else(|| {
+                        copy_name.as_ref().map(|copy_name| {
+                            let copy_name = format_ident!("{}", copy_name);
+                            quote!(#copy_name)
+                        })
+                    });
+                    return vec![JsonStructField {
+                        name: name.to_owned(),
+                        kind: JsonStructFieldKind::Flattened {
+                            copy_name,
+                            is_pointer: matches!(embedded_field, EmbeddedField::PointerType(_)),
+                        },
+                        rename: name,
+                        attr_serde_deserialize_with: None,
+                        field_type: JsonStructFieldType {
+                            r#type: embedded_field.r#type(),
+                            is_ignored: None,
+                            is_string: None,
+                            is_omitempty: None,
+                            special_type,
                             box_type: field_opt.box_type,
-                        };
-
-                        let token = if self.opt.has_serde_derive() {
-                            let field_serde_attr = JsonStructFieldSerdeAttr {
-                                rename: rename.unwrap_or_else(|| name.to_owned()),
-                                is_ignored,
-                                is_omitempty,
-                                attr_serde_deserialize_with: field_opt.attr_serde_deserialize_with,
-                                enable_serde_ser: self.opt.enable_derive_serde_ser,
-                                enable_serde_de: self.opt.enable_derive_serde_de,
-                            };
-
-                            quote! {
-                                #[serde(#field_serde_attr)]
-                                pub #field_name: #field_type,
-                            }
-                        } else {
-                            quote! {
-                                pub #field_name: #field_type,
-                            }
-                        };
+                            flatten_pointer: true,
+                            enable_fixed_size_array: self.opt.enable_fixed_size_array,
+                            type_mappings: &self.opt.type_mappings,
+                            types: &self.opt.types,
+                        },
+                        opt: &self.opt,
+                    }];
+                }
+
+                let is_ignored = if promotion.hidden.contains(&(i, 0)) {
+                    Some(true)
+                } else {
+                    is_ignored
+                };
+                let r#type = embedded_field_type(embedded_field);
+
+                vec![JsonStructField {
+                    name: name.to_owned(),
+                    kind: JsonStructFieldKind::Normal,
+                    rename: rename.unwrap_or(name),
+                    attr_serde_deserialize_with: field_opt.attr_serde_deserialize_with,
+                    field_type: JsonStructFieldType {
+                        box_type: field_opt.box_type
+                            || (!field_opt.flatten_pointer && self.is_recursive(&r#type)),
+                        r#type,
+                        is_ignored,
+                        is_string,
+                        is_omitempty,
+                        special_type: field_opt.special_type,
+                        flatten_pointer: field_opt.flatten_pointer,
+                        enable_fixed_size_array: self.opt.enable_fixed_size_array,
+                        type_mappings: &self.opt.type_mappings,
+                        types: &self.opt.types,
+                    },
+                    opt: &self.opt,
+                }]
+            }
+        }
+    }
+
+    // Anonymous struct types are hoisted into the structs named after the fields,
+    // e.g. `Meta struct { Page int }` of `User` to `UserMeta`.
+    fn hoist_struct_types(&self, struct_name: &str) -> (StructType, Vec<JsonStruct>) {
+        let mut field_decls = vec![];
+        let mut json_structs = vec![];
+        let type_param_names: Vec<_> = self
+            .type_params
+            .iter()
+            .flat_map(|type_param| type_param.names.to_owned())
+            .collect();
 
-                        vec![token]
+        for field_decl in &self.struct_type.field_decls {
+            match &field_decl.struct_field {
+                StructField::IdentifierListType(names, r#type) if has_struct_type(r#type) => {
+                    for name in names {
+                        let name_prefix = format!("{}{}", struct_name, name.to_case(Case::Pascal));
+                        let mut struct_types = vec![];
+                        let r#type = hoist_struct_type(
+                            r#type,
+                            &name_prefix,
+                            &type_param_names,
+                            &mut struct_types,
+                        );
+
+                        field_decls.push(FieldDecl {
+                            struct_field: StructField::IdentifierListType(
+                                vec![name.to_owned()],
+                                r#type.into(),
+                            ),
+                            ..field_decl.to_owned()
+                        });
+                        json_structs.extend(struct_types.into_iter().map(
+                            |(nested_name, struct_type)| JsonStruct {
+                                name: nested_name,
+                                type_params: self.referenced_type_params(&struct_type),
+                                struct_type,
+                                opt: JsonStructOption {
+                                    alias_name: None,
+                                    ..self.opt.to_owned()
+                                },
+                                field_opts: HashMap::new(),
+                            },
+                        ));
                     }
                 }
-            })
-            .flatten()
+                _ => field_decls.push(field_decl.to_owned()),
+            }
+        }
+
+        (StructType { field_decls }, json_structs)
+    }
+
+    // encoding/json ignores the unexported fields, unless configured by the field opts.
+    fn is_serde_visible(&self, depth: usize, name: &str) -> bool {
+        self.opt.enable_unexported_fields
+            || is_exported(name)
+            || (depth == 0 && self.field_opts.contains_key(name))
+    }
+
+    // A private field, e.g. `bar uint` to `bar: usize`. `#[serde(skip)]` requires `Default`,
+    // so the other ones are `Option`, e.g. `at time.Time` to `at: Option<DateTime<FixedOffset>>`.
+    fn unexported_field(&self, name: &str, r#type: Type) -> JsonStructField<'_> {
+        let is_ignored = if self.has_default(&r#type, &mut vec![]) {
+            None
+        } else {
+            Some(true)
+        };
+        JsonStructField {
+            name: name.to_owned(),
+            kind: JsonStructFieldKind::Unexported,
+            rename: name.to_owned(),
+            attr_serde_deserialize_with: None,
+            field_type: JsonStructFieldType {
+                box_type: self.is_recursive(&r#type),
+                r#type,
+                is_ignored,
+                is_string: None,
+                is_omitempty: None,
+                special_type: None,
+                flatten_pointer: false,
+                enable_fixed_size_array: self.opt.enable_fixed_size_array,
+                type_mappings: &self.opt.type_mappings,
+                types: &self.opt.types,
+            },
+            opt: &self.opt,
+        }
+    }
+
+    // Whether the Rust type implements `Default`, not including the structs, the derives of
+    // which are unknown.
+    fn has_default<'a>(&'a self, r#type: &'a Type, visited: &mut Vec<&'a str>) -> bool {
+        match r#type {
+            // `serde_json::Value::Null`.
+            _ if r#type.is_empty_interface_with(&|name| self.opt.types.contains_key(name)) => true,
+            Type::TypeName(type_name) => {
+                if let Some(type_mapping) = self.opt.type_mappings.get_by_type_name(type_name) {
+                    return is_primitive(&type_mapping.rust_type);
+                }
+                match type_name {
+                    TypeName::Complex64 | TypeName::Complex128 | TypeName::QualifiedIdent(_, _) => {
+                        false
+                    }
+                    TypeName::Identifier(name) => {
+                        if visited.contains(&name.as_str()) {
+                            return false;
+                        }
+                        visited.push(name);
+                        match self.opt.types.get(name) {
+                            Some(Type::StructType(_)) | None => false,
+                            Some(r#type) => self.has_default(r#type, visited),
+                        }
+                    }
+                    _ => true,
+                }
+            }
+            Type::PointerType(_) | Type::SliceType(_) | Type::MapType(_) => true,
+            // std implements `Default` for the arrays up to 32 elements.
+            Type::ArrayType(ArrayType { length, element }) => {
+                let is_array = self.opt.enable_fixed_size_array
+                    && !matches!(length, ArrayLength::IntLiteral(length) if *length > 32);
+                !is_array || self.has_default(element, visited)
+            }
+            Type::ParenthesizedType(ParenthesizedType(element)) => {
+                self.has_default(element, visited)
+            }
+            _ => false,
+        }
+    }
+
+    // The names of the fields (un)marshaled, not including the flattened ones.
+    fn json_field_names(&self, struct_type: &StructType, promotion: &Promotion) -> Vec<String> {
+        let mut json_field_names = vec![];
+        for (i, field_decl) in struct_type.field_decls.iter().enumerate() {
+            let rename = match field_decl
+                .tag
+                .as_ref()
+                .and_then(|tag| tag.as_json_struct_tag())
+            {
+                Some(JsonStructTag::Ignored) => continue,
+                Some(JsonStructTag::Normal(rename, _)) => rename.to_owned(),
+                None => None,
+            };
+
+            let names = match &field_decl.struct_field {
+                StructField::IdentifierListType(names, _) => names.to_owned(),
+                StructField::EmbeddedField(_) if promotion.flattened.contains(&i) => continue,
+                StructField::EmbeddedField(embedded_field) => vec![embedded_field.name()],
+            };
+            for (j, name) in names.iter().enumerate() {
+                if name == "_"
+                    || promotion.unexported.contains(&(i, j))
+                    || promotion.hidden.contains(&(i, j))
+                {
+                    continue;
+                }
+                json_field_names.push(rename.to_owned().unwrap_or_else(|| name.to_owned()));
+            }
+        }
+        json_field_names
+    }
+
+    fn serde_remote_impls(&self, struct_name: &Ident, json_field_names: &[String]) -> TokenStream {
+        let type_params = &self.type_params;
+        let type_param_names: Vec<_> = self
+            .type_params
+            .iter()
+            .flat_map(|type_param| type_param.names.iter())
+            .map(|name| format_ident!("{}", name))
             .collect();
+        let type_args = if type_param_names.is_empty() {
+            quote!()
+        } else {
+            quote!(<#(#type_param_names),*>)
+        };
+
+        let mut token = quote! {
+            impl<'de, #(#type_params),*> ::serde::Deserialize<'de> for #struct_name #type_args
+            where
+                #(#type_param_names: ::serde::Deserialize<'de>),*
+            {
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'de>,
+                {
+                    Self::deserialize(
+                        ::golang_type_decl::serde_helpers::case_insensitive::Deserializer::new(
+                            deserializer,
+                            &[#(#json_field_names),*],
+                        ),
+                    )
+                }
+            }
+        };
+
+        if self.opt.enable_derive_serde_ser {
+            token.append_all(quote! {
+                impl<#(#type_params),*> ::serde::Serialize for #struct_name #type_args
+                where
+                    #(#type_param_names: ::serde::Serialize),*
+                {
+                    fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: ::serde::Serializer,
+                    {
+                        Self::serialize(self, serializer)
+                    }
+                }
+            });
+        }
+
+        token
+    }
+
+    // Contains itself by value, e.g. `*TreeNode` of `TreeNode` to `Option<Box<TreeNode>>`.
+    fn is_recursive(&self, r#type: &Type) -> bool {
+        is_recursive(&self.name, r#type, &self.opt.types)
+    }
+
+    fn referenced_type_params(&self, struct_type: &StructType) -> Vec<TypeParamDecl> {
+        let r#type = Type::StructType(struct_type.to_owned());
+        self.type_params
+            .iter()
+            .filter_map(|type_param| {
+                let names: Vec<_> = type_param
+                    .names
+                    .iter()
+                    .filter(|name| references(&r#type, name))
+                    .cloned()
+                    .collect();
+                if names.is_empty() {
+                    None
+                } else {
+                    Some(TypeParamDecl {
+                        names,
+                        ..type_param.to_owned()
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+impl ToTokens for JsonStruct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let layout = self.layout();
+
+        if self.opt.has_serde_derive() {
+            if let Some(err) = layout.fields.iter().find_map(|field| field.unsupported()) {
+                tokens.append_all(quote!(compile_error!(#err);));
+                return;
+            }
+        }
+
+        let struct_name = format_ident!("{}", layout.struct_name);
+        let struct_generics = if self.type_params.is_empty() {
+            quote!()
+        } else {
+            let type_params = &self.type_params;
+            quote!(<#(#type_params),*>)
+        };
+        let struct_fields = &layout.fields;
 
         let token = if self.opt.has_derive() {
             let derive_attr = JsonStructSerdeDeriveAttr {
@@ -185,21 +553,163 @@ impl ToTokens for JsonStruct {
                 custom: self.opt.custom_derive.to_owned(),
             };
 
-            quote! {
-                #[derive(#derive_attr)]
-                pub struct #struct_name {
-                    #(#struct_fields)*
+            if self.opt.has_serde_remote() {
+                let serde_remote_impls =
+                    self.serde_remote_impls(&struct_name, &layout.json_field_names);
+                quote! {
+                    #[derive(#derive_attr)]
+                    #[serde(remote = "Self")]
+                    pub struct #struct_name #struct_generics {
+                        #(#struct_fields)*
+                    }
+
+                    #serde_remote_impls
+                }
+            } else {
+                quote! {
+                    #[derive(#derive_attr)]
+                    pub struct #struct_name #struct_generics {
+                        #(#struct_fields)*
+                    }
                 }
             }
         } else {
             quote! {
-                pub struct #struct_name {
+                pub struct #struct_name #struct_generics {
                     #(#struct_fields)*
                 }
             }
         };
 
         tokens.append_all(token);
+
+        for json_struct in &layout.nested_json_structs {
+            json_struct.to_tokens(tokens);
+        }
+    }
+}
+
+// The Rust struct of a Go struct type, shared by `gen_json_struct!` and the struct values of
+// `gen_var!`.
+pub struct JsonStructLayout<'a> {
+    pub struct_name: String,
+    pub fields: Vec<JsonStructField<'a>>,
+    // The hoisted anonymous struct types and the copies of the embedded struct types.
+    pub nested_json_structs: Vec<JsonStruct>,
+    json_field_names: Vec<String>,
+}
+
+pub struct JsonStructField<'a> {
+    // The Go field name, e.g. `Backend` of `*Backend`.
+    pub name: String,
+    pub kind: JsonStructFieldKind,
+    rename: String,
+    attr_serde_deserialize_with: Option<String>,
+    field_type: JsonStructFieldType<'a>,
+    opt: &'a JsonStructOption,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum JsonStructFieldKind {
+    Normal,
+    // The fields of the embedded struct are promoted by `#[serde(flatten)]`, e.g. the ones of
+    // the copy `ServerBackend`.
+    Flattened {
+        copy_name: Option<String>,
+        is_pointer: bool,
+    },
+    // Private, skipped by serde.
+    Unexported,
+}
+
+impl<'a> JsonStructField<'a> {
+    pub fn ident(&self) -> Ident {
+        format_ident!("r#{}", self.name.to_case(Case::Snake))
+    }
+
+    // The Go type of the values, e.g. `Backend` of `*Backend`, `[]byte` of `[40]byte` without
+    // `enable_fixed_size_array`.
+    pub fn value_type(&self) -> Type {
+        self.field_type.value_type()
+    }
+
+    // The Rust type of the values, i.e. without the `Option` and `Box` wrapping, e.g. `String`
+    // of `json:",string"`.
+    pub fn value_type_token(&self) -> TokenStream {
+        self.field_type.value_type_token()
+    }
+
+    // `json:",string"`
+    pub fn is_string(&self) -> bool {
+        self.field_type.is_string == Some(true)
+    }
+
+    fn unsupported(&self) -> Option<String> {
+        match self.kind {
+            JsonStructFieldKind::Normal if self.field_type.has_unsupported_bytes() => {
+                Some(format!(
+                    "{} {} unsupported, the nested []byte are not base64 encoded",
+                    self.name, self.field_type.r#type
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+impl<'a> ToTokens for JsonStructField<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field_name = self.ident();
+        let field_type = &self.field_type;
+
+        let token = match &self.kind {
+            JsonStructFieldKind::Unexported if self.opt.has_serde_derive() => quote! {
+                #[serde(skip)]
+                #field_name: #field_type,
+            },
+            JsonStructFieldKind::Unexported => quote! {
+                #field_name: #field_type,
+            },
+            JsonStructFieldKind::Flattened { is_pointer, .. } => {
+                // The fields of a nil embedded pointer are omitted.
+                let field_type = if *is_pointer {
+                    quote!(::core::option::Option<#field_type>)
+                } else {
+                    quote!(#field_type)
+                };
+                if self.opt.has_serde_derive() {
+                    quote! {
+                        #[serde(flatten)]
+                        pub #field_name: #field_type,
+                    }
+                } else {
+                    quote! {
+                        pub #field_name: #field_type,
+                    }
+                }
+            }
+            JsonStructFieldKind::Normal if self.opt.has_serde_derive() => {
+                let field_serde_attr = JsonStructFieldSerdeAttr {
+                    rename: self.rename.to_owned(),
+                    is_ignored: field_type.is_ignored,
+                    is_omitempty: field_type.is_omitempty,
+                    is_pointer: field_type.is_pointer(),
+                    serde_with: field_type.serde_with(),
+                    attr_serde_deserialize_with: self.attr_serde_deserialize_with.to_owned(),
+                    enable_serde_ser: self.opt.enable_derive_serde_ser,
+                    enable_serde_de: self.opt.enable_derive_serde_de,
+                };
+
+                quote! {
+                    #[serde(#field_serde_attr)]
+                    pub #field_name: #field_type,
+                }
+            }
+            JsonStructFieldKind::Normal => quote! {
+                pub #field_name: #field_type,
+            },
+        };
+
+        tokens.append_all(token);
     }
 }
 
@@ -232,6 +742,9 @@ struct JsonStructFieldSerdeAttr {
     rename: String,
     is_ignored: Option<bool>,
     is_omitempty: Option<bool>,
+    is_pointer: bool,
+    // The module path of `serialize` and `deserialize`.
+    serde_with: Option<String>,
     attr_serde_deserialize_with: Option<String>,
     enable_serde_ser: bool,
     enable_serde_de: bool,
@@ -266,6 +779,11 @@ impl ToTokens for JsonStructFieldSerdeAttr {
                 let skip_serializing_if_val = "Option::is_none";
                 tokens.append_all(quote!(#skip_serializing_if_val));
             }
+        } else if self.is_pointer {
+            // A missing key is nil, which `with` requires to be explicit.
+            tokens.append(Punct::new(',', Spacing::Alone));
+
+            tokens.append(format_ident!("default"));
         }
 
         if let Some(serde_deserialize_with) = &self.attr_serde_deserialize_with {
@@ -276,39 +794,209 @@ impl ToTokens for JsonStructFieldSerdeAttr {
                 tokens.append(Punct::new('=', Spacing::Alone));
                 tokens.append_all(quote!(#serde_deserialize_with));
             }
+
+            if let Some(serde_with) = &self.serde_with {
+                if self.enable_serde_ser {
+                    tokens.append(Punct::new(',', Spacing::Alone));
+
+                    tokens.append(format_ident!("serialize_with"));
+                    tokens.append(Punct::new('=', Spacing::Alone));
+                    let serialize_with = format!("{}::serialize", serde_with);
+                    tokens.append_all(quote!(#serialize_with));
+                }
+            }
+        } else if let Some(serde_with) = &self.serde_with {
+            tokens.append(Punct::new(',', Spacing::Alone));
+
+            tokens.append(format_ident!("with"));
+            tokens.append(Punct::new('=', Spacing::Alone));
+            tokens.append_all(quote!(#serde_with));
         }
     }
 }
 
-struct JsonStructFieldType {
+struct JsonStructFieldType<'a> {
     r#type: Type,
     is_ignored: Option<bool>,
     is_string: Option<bool>,
     is_omitempty: Option<bool>,
     special_type: Option<TokenStream>,
     box_type: bool,
+    flatten_pointer: bool,
+    enable_fixed_size_array: bool,
+    type_mappings: &'a TypeMappings,
+    types: &'a HashMap<String, Type>,
 }
-impl ToTokens for JsonStructFieldType {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let mut token = if let Some(special_type) = &self.special_type {
+impl<'a> JsonStructFieldType<'a> {
+    // `*T` to `Option<T>`, nil is `null`.
+    fn is_pointer(&self) -> bool {
+        !self.flatten_pointer
+            && self.special_type.is_none()
+            && matches!(self.r#type, Type::PointerType(_))
+    }
+
+    // e.g. `T` of `**T`.
+    fn element_type(&self) -> &Type {
+        let mut r#type = &self.r#type;
+        if self.is_pointer() {
+            while let Type::PointerType(PointerType(element)) = r#type {
+                r#type = element;
+            }
+        }
+        r#type
+    }
+
+    // The element type of which the arrays are the ones of the Rust type.
+    fn value_type(&self) -> Type {
+        if self.enable_fixed_size_array {
+            big_array_to_slice(self.element_type(), self.is_big_array())
+        } else {
+            self.element_type().array_to_slice()
+        }
+    }
+
+    fn value_type_token(&self) -> TokenStream {
+        if let Some(special_type) = &self.special_type {
             special_type.to_owned()
+        } else if self.is_string == Some(true) {
+            Type::TypeName(TypeName::String).to_token_stream()
         } else {
-            let r#type = &self.r#type;
-            let mut token = if self.box_type {
-                quote!(Box<#r#type>)
-            } else {
-                quote!(#r#type)
+            self.value_type().to_token_stream_with(self.type_mappings)
+        }
+    }
+
+    // serde implements the arrays up to 32 elements, the longer ones of the fields are
+    // `big_array`, the nested ones are Vec, see `big_array_to_slice`.
+    fn is_big_array(&self) -> bool {
+        self.enable_fixed_size_array
+            && self.special_type.is_none()
+            && !self.box_type
+            && self.is_ignored != Some(true)
+            && self.is_string != Some(true)
+            && matches!(
+                self.element_type(),
+                Type::ArrayType(ArrayType {
+                    length: ArrayLength::IntLiteral(length),
+                    ..
+                }) if *length > 32
+            )
+    }
+
+    // Follows the declared types, e.g. `[]byte` of `type Blob []byte`.
+    fn underlying_type<'b>(&'b self, r#type: &'b Type) -> &'b Type {
+        let mut r#type = r#type;
+        // Bounded in case of invalid recursive declarations.
+        for _ in 0..=self.types.len() {
+            r#type = match r#type {
+                Type::TypeName(TypeName::Identifier(name)) => match self.types.get(name) {
+                    Some(underlying_type) => underlying_type,
+                    None => break,
+                },
+                Type::ParenthesizedType(ParenthesizedType(element)) => element,
+                _ => break,
             };
+        }
+        r#type
+    }
 
-            if self.is_string == Some(true) {
-                let r#type = Type::TypeName(TypeName::String);
-                token = quote!(#r#type);
-            }
+    // `[]byte`, also the pointers of the elements, e.g. `*[]byte` of `[]*[]byte`.
+    fn is_bytes(&self, r#type: &Type) -> bool {
+        match self.underlying_type(r#type) {
+            Type::SliceType(SliceType { element }) => matches!(
+                self.underlying_type(element),
+                Type::TypeName(TypeName::Byte) | Type::TypeName(TypeName::Uint8)
+            ),
+            Type::PointerType(PointerType(element)) => self.is_bytes(element),
+            _ => false,
+        }
+    }
+
+    // The `[]byte` nested deeper than the ones of the serde helpers, e.g. `[][][]byte`.
+    fn has_unsupported_bytes(&self) -> bool {
+        self.serde_with().is_none()
+            && self.special_type.is_none()
+            && self.is_ignored != Some(true)
+            && self.is_string != Some(true)
+            && contains_bytes(self, self.element_type(), &mut vec![])
+    }
+
+    // The helpers of `golang_type_decl::serde_helpers`, only if the field type is the one,
+    // e.g. not `Vec<Vec<u8>>`.
+    fn serde_with(&self) -> Option<String> {
+        if self.special_type.is_some()
+            || self.box_type
+            || self.is_ignored == Some(true)
+            || self.is_string == Some(true)
+        {
+            return None;
+        }
+        let is_omitempty = self.is_omitempty == Some(true);
+        let is_pointer = self.is_pointer();
 
-            token
+        let name = match self.underlying_type(self.element_type()) {
+            // encoding/json encodes `[]byte` as a base64 string, also the ones of the slices
+            // and the maps, e.g. `map[string][]byte`.
+            r#type if self.is_bytes(r#type) => {
+                if is_omitempty || is_pointer {
+                    "base64_option"
+                } else {
+                    "base64"
+                }
+            }
+            Type::SliceType(SliceType { element }) if self.is_bytes(element) => {
+                if is_omitempty || is_pointer {
+                    "base64_slice_option"
+                } else {
+                    "base64_slice"
+                }
+            }
+            Type::MapType(MapType { value, .. }) if self.is_bytes(value) => {
+                if is_omitempty || is_pointer {
+                    "base64_map_option"
+                } else {
+                    "base64_map"
+                }
+            }
+            _ if self.is_big_array() => {
+                if is_omitempty || is_pointer {
+                    "big_array_option"
+                } else {
+                    "big_array"
+                }
+            }
+            // e.g. `time.Time`, the `Option` ones of `*big.Int` and `omitempty` to
+            // `serde_with_option`.
+            _ => {
+                let r#type = match self.element_type() {
+                    Type::PointerType(PointerType(element)) => element.as_ref(),
+                    r#type => r#type,
+                };
+                return match r#type {
+                    Type::TypeName(type_name) => self
+                        .type_mappings
+                        .get_by_type_name(type_name)
+                        .and_then(|type_mapping| {
+                            if is_omitempty || is_pointer {
+                                type_mapping.serde_with_option
+                            } else {
+                                type_mapping.serde_with
+                            }
+                        }),
+                    _ => None,
+                };
+            }
         };
+        Some(format!("::golang_type_decl::serde_helpers::{}", name))
+    }
+}
+impl<'a> ToTokens for JsonStructFieldType<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut token = self.value_type_token();
+        if self.box_type && self.special_type.is_none() && self.is_string != Some(true) {
+            token = quote!(Box<#token>);
+        }
 
-        if self.is_ignored == Some(true) || self.is_omitempty == Some(true) {
+        if self.is_ignored == Some(true) || self.is_omitempty == Some(true) || self.is_pointer() {
             let mut tokens_tmp = TokenStream::new();
             tokens_tmp.append_all(quote!(::core::option::Option));
             tokens_tmp.append(Punct::new('<', Spacing::Alone));
@@ -320,3 +1008,208 @@ impl ToTokens for JsonStructFieldType {
         tokens.append_all(token);
     }
 }
+
+// e.g. `i64` of `time.Duration`.
+fn is_primitive(rust_type: &str) -> bool {
+    matches!(
+        rust_type,
+        "bool"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "f32"
+            | "f64"
+            | "String"
+            | "::std::string::String"
+    )
+}
+
+// Whether the type contains `[]byte`, not including the ones of the struct types.
+fn contains_bytes<'a>(
+    field_type: &'a JsonStructFieldType,
+    r#type: &'a Type,
+    visited: &mut Vec<&'a str>,
+) -> bool {
+    if field_type.is_bytes(r#type) {
+        return true;
+    }
+    match r#type {
+        Type::TypeName(TypeName::Identifier(name)) => {
+            if visited.contains(&name.as_str()) {
+                return false;
+            }
+            visited.push(name);
+            match field_type.types.get(name) {
+                Some(Type::StructType(_)) | None => false,
+                Some(r#type) => contains_bytes(field_type, r#type, visited),
+            }
+        }
+        Type::ArrayType(ArrayType { element, .. })
+        | Type::SliceType(SliceType { element })
+        | Type::PointerType(PointerType(element))
+        | Type::ParenthesizedType(ParenthesizedType(element)) => {
+            contains_bytes(field_type, element, visited)
+        }
+        Type::MapType(MapType { key, value }) => {
+            contains_bytes(field_type, key, visited) || contains_bytes(field_type, value, visited)
+        }
+        _ => false,
+    }
+}
+
+// e.g. `*Base` of `EmbeddedField::PointerType`.
+fn embedded_field_type(embedded_field: &EmbeddedField) -> Type {
+    match embedded_field {
+        EmbeddedField::TypeName(_) => embedded_field.r#type(),
+        EmbeddedField::PointerType(_) => {
+            Type::PointerType(PointerType(embedded_field.r#type().into()))
+        }
+    }
+}
+
+// The arrays longer than 32 to Vec, except the one of the field with `big_array`.
+fn big_array_to_slice(r#type: &Type, is_big_array: bool) -> Type {
+    match r#type {
+        Type::ArrayType(ArrayType { length, element }) => {
+            let element = big_array_to_slice(element, false).into();
+            match length {
+                ArrayLength::IntLiteral(length) if *length > 32 && !is_big_array => {
+                    Type::SliceType(SliceType { element })
+                }
+                _ => Type::ArrayType(ArrayType {
+                    length: length.to_owned(),
+                    element,
+                }),
+            }
+        }
+        Type::SliceType(SliceType { element }) => Type::SliceType(SliceType {
+            element: big_array_to_slice(element, false).into(),
+        }),
+        Type::PointerType(PointerType(element)) => {
+            Type::PointerType(PointerType(big_array_to_slice(element, false).into()))
+        }
+        Type::MapType(MapType { key, value }) => Type::MapType(MapType {
+            key: big_array_to_slice(key, false).into(),
+            value: big_array_to_slice(value, false).into(),
+        }),
+        Type::ParenthesizedType(ParenthesizedType(element)) => {
+            Type::ParenthesizedType(ParenthesizedType(big_array_to_slice(element, false).into()))
+        }
+        _ => r#type.to_owned(),
+    }
+}
+
+// e.g. `struct { Page int }`, `[]struct { Name string }`
+fn has_struct_type(r#type: &Type) -> bool {
+    match r#type {
+        Type::StructType(_) => true,
+        Type::ArrayType(ArrayType { element, .. })
+        | Type::SliceType(SliceType { element })
+        | Type::PointerType(PointerType(element))
+        | Type::ParenthesizedType(ParenthesizedType(element)) => has_struct_type(element),
+        Type::MapType(MapType { key, value }) => has_struct_type(key) || has_struct_type(value),
+        _ => false,
+    }
+}
+
+// Replaces the struct types with the names, the subsequent ones are numbered, e.g. `UserMeta2`.
+// The type parameters referred to are the type arguments, e.g. `PageData[T]`.
+fn hoist_struct_type(
+    r#type: &Type,
+    name: &str,
+    type_param_names: &[String],
+    struct_types: &mut Vec<(String, StructType)>,
+) -> Type {
+    let mut hoist = |r#type: &Type| hoist_struct_type(r#type, name, type_param_names, struct_types);
+
+    match r#type {
+        Type::StructType(struct_type) => {
+            let name = if struct_types.is_empty() {
+                name.to_owned()
+            } else {
+                format!("{}{}", name, struct_types.len() + 1)
+            };
+            struct_types.push((name.to_owned(), struct_type.to_owned()));
+
+            let type_args: Vec<_> = type_param_names
+                .iter()
+                .filter(|type_param_name| references(r#type, type_param_name))
+                .map(|type_param_name| {
+                    Type::TypeName(TypeName::Identifier(type_param_name.to_owned()))
+                })
+                .collect();
+            if type_args.is_empty() {
+                Type::TypeName(TypeName::Identifier(name))
+            } else {
+                Type::GenericType(GenericType {
+                    type_name: TypeName::Identifier(name),
+                    type_args,
+                })
+            }
+        }
+        Type::ArrayType(ArrayType { element, length }) => Type::ArrayType(ArrayType {
+            element: hoist(element).into(),
+            length: length.to_owned(),
+        }),
+        Type::SliceType(SliceType { element }) => Type::SliceType(SliceType {
+            element: hoist(element).into(),
+        }),
+        Type::PointerType(PointerType(element)) => {
+            Type::PointerType(PointerType(hoist(element).into()))
+        }
+        Type::ParenthesizedType(ParenthesizedType(element)) => {
+            Type::ParenthesizedType(ParenthesizedType(hoist(element).into()))
+        }
+        Type::MapType(MapType { key, value }) => Type::MapType(MapType {
+            key: hoist(key).into(),
+            value: hoist(value).into(),
+        }),
+        _ => r#type.to_owned(),
+    }
+}
+
+// Whether the type refers to the type parameter.
+fn references(r#type: &Type, type_param_name: &str) -> bool {
+    match r#type {
+        Type::TypeName(TypeName::Identifier(name)) => name == type_param_name,
+        Type::GenericType(GenericType {
+            type_name,
+            type_args,
+        }) => {
+            matches!(type_name, TypeName::Identifier(name) if name == type_param_name)
+                || type_args.iter().any(|x| references(x, type_param_name))
+        }
+        Type::ArrayType(ArrayType { element, .. })
+        | Type::SliceType(SliceType { element })
+        | Type::PointerType(PointerType(element))
+        | Type::ParenthesizedType(ParenthesizedType(element)) => {
+            references(element, type_param_name)
+        }
+        Type::MapType(MapType { key, value }) => {
+            references(key, type_param_name) || references(value, type_param_name)
+        }
+        Type::StructType(struct_type) => {
+            struct_type
+                .field_decls
+                .iter()
+                .any(|field_decl| match &field_decl.struct_field {
+                    StructField::IdentifierListType(_, r#type) => {
+                        references(r#type, type_param_name)
+                    }
+                    StructField::EmbeddedField(embedded_field) => {
+                        references(&embedded_field.r#type(), type_param_name)
+                    }
+                })
+        }
+        _ => false,
+    }
+}
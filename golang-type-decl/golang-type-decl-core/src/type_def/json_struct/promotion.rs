@@ -0,0 +1,442 @@
+use std::collections::{HashMap, HashSet};
+
+use golang_type_core::{
+    golang_struct_tag::JsonStructTag, EmbeddedField, FieldDecl, ParenthesizedType, StructField,
+    StructType, Type, TypeName,
+};
+
+// The fields of embedded structs are promoted the way encoding/json does,
+// https://pkg.go.dev/encoding/json#Marshal
+pub(super) struct Promotion {
+    // The indexes of the field decls embedding structs untagged, i.e. `#[serde(flatten)]`.
+    pub(super) flattened: HashSet<usize>,
+    // The indexes of the field decls and the names, hidden by the dominant fields.
+    pub(super) hidden: HashSet<(usize, usize)>,
+    // The names of the copies of the embedded structs, by the indexes of the field decls.
+    pub(super) embedded_copies: HashMap<usize, String>,
+    pub(super) copies: Vec<StructCopy>,
+    // The indexes of the field decls and the names, unexported and ignored by encoding/json.
+    pub(super) unexported: HashSet<(usize, usize)>,
+}
+
+// The embedded struct without the fields hidden by the dominant ones, named after the
+// embedding struct, e.g. `UserBase`.
+pub(super) struct StructCopy {
+    pub(super) name: String,
+    pub(super) struct_type: StructType,
+    // The copies of its embedded structs, by type name.
+    pub(super) types: HashMap<String, Type>,
+    // The names of the copies of its embedded structs, by field name.
+    pub(super) embedded_copies: HashMap<String, String>,
+}
+
+// The index of the field decl and the index of the name, from the root struct.
+type FieldIndex = Vec<(usize, usize)>;
+
+struct JsonField {
+    name: String,
+    is_tagged: bool,
+    index: FieldIndex,
+}
+
+enum EmbeddedKind<'a> {
+    // Promoted.
+    Struct(String, &'a StructType),
+    // Promoted, the fields of which are unknown, e.g. the ones of other packages.
+    Unknown,
+    // A field named by the type, e.g. `type ID string`.
+    NonStruct,
+}
+
+impl Promotion {
+    pub(super) fn new(
+        struct_name: &str,
+        struct_type: &StructType,
+        types: &HashMap<String, Type>,
+        is_serde_visible: &dyn Fn(usize, &str) -> bool,
+    ) -> Self {
+        let (fields, explored) = json_fields(struct_name, struct_type, types, is_serde_visible);
+        let dominant = dominant_fields(&fields);
+
+        let resolver = Resolver {
+            types,
+            fields: &fields,
+            dominant: &dominant,
+            explored: &explored,
+        };
+
+        let mut flattened = HashSet::new();
+        let mut hidden = HashSet::new();
+        let mut embedded_copies = HashMap::new();
+        let mut copies = vec![];
+        let mut unexported = HashSet::new();
+        for (i, field_decl) in struct_type.field_decls.iter().enumerate() {
+            match &field_decl.struct_field {
+                StructField::IdentifierListType(names, _) => {
+                    for (j, name) in names.iter().enumerate() {
+                        if !is_serde_visible(0, name) {
+                            unexported.insert((i, j));
+                        } else if !resolver.is_visible(&[(i, j)]) {
+                            hidden.insert((i, j));
+                        }
+                    }
+                }
+                StructField::EmbeddedField(embedded_field) => {
+                    if is_ignored_embedded(embedded_field, types, 0, is_serde_visible) {
+                        unexported.insert((i, 0));
+                        continue;
+                    }
+                    let kind = if has_tag_name(field_decl) {
+                        EmbeddedKind::NonStruct
+                    } else {
+                        embedded_kind(embedded_field, types)
+                    };
+                    match kind {
+                        EmbeddedKind::NonStruct => {
+                            if !resolver.is_visible(&[(i, 0)]) {
+                                hidden.insert((i, 0));
+                            }
+                        }
+                        EmbeddedKind::Unknown => {
+                            flattened.insert(i);
+                        }
+                        // Skipped by Go, e.g. embedding itself.
+                        EmbeddedKind::Struct(_, _) if !explored.contains(&vec![(i, 0)]) => {
+                            hidden.insert((i, 0));
+                        }
+                        EmbeddedKind::Struct(_, embedded_struct_type) => {
+                            flattened.insert(i);
+                            if let Some(name) = resolver.copy_embedded(
+                                embedded_field,
+                                embedded_struct_type,
+                                &[(i, 0)],
+                                struct_name,
+                                &mut copies,
+                            ) {
+                                embedded_copies.insert(i, name);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            flattened,
+            hidden,
+            embedded_copies,
+            copies,
+            unexported,
+        }
+    }
+}
+
+struct Resolver<'a> {
+    types: &'a HashMap<String, Type>,
+    fields: &'a [JsonField],
+    dominant: &'a HashSet<FieldIndex>,
+    explored: &'a HashSet<FieldIndex>,
+}
+
+impl<'a> Resolver<'a> {
+    // The fields ignored, e.g. `json:"-"`, are not recorded.
+    fn is_visible(&self, index: &[(usize, usize)]) -> bool {
+        self.dominant.contains(index) || !self.fields.iter().any(|field| field.index == index)
+    }
+
+    // The name of the copy if some fields are hidden.
+    fn copy_embedded(
+        &self,
+        embedded_field: &EmbeddedField,
+        embedded_struct_type: &StructType,
+        index: &[(usize, usize)],
+        name_prefix: &str,
+        copies: &mut Vec<StructCopy>,
+    ) -> Option<String> {
+        if self.is_all_visible(index, embedded_struct_type) {
+            return None;
+        }
+
+        let name = format!("{}{}", name_prefix, embedded_field.name());
+
+        let mut field_decls = vec![];
+        let mut types = HashMap::new();
+        let mut embedded_copies = HashMap::new();
+        for (i, field_decl) in embedded_struct_type.field_decls.iter().enumerate() {
+            let mut field_index = index.to_vec();
+            field_index.push((i, 0));
+
+            match &field_decl.struct_field {
+                StructField::IdentifierListType(names, r#type) => {
+                    let names: Vec<_> = names
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| {
+                            let mut field_index = index.to_vec();
+                            field_index.push((i, *j));
+                            self.is_visible(&field_index)
+                        })
+                        .map(|(_, name)| name.to_owned())
+                        .collect();
+                    if !names.is_empty() {
+                        field_decls.push(FieldDecl {
+                            struct_field: StructField::IdentifierListType(names, r#type.to_owned()),
+                            ..field_decl.to_owned()
+                        });
+                    }
+                }
+                StructField::EmbeddedField(embedded_field) => {
+                    let kind = if has_tag_name(field_decl) {
+                        EmbeddedKind::NonStruct
+                    } else {
+                        embedded_kind(embedded_field, self.types)
+                    };
+                    match kind {
+                        EmbeddedKind::NonStruct => {
+                            if self.is_visible(&field_index) {
+                                field_decls.push(field_decl.to_owned());
+                            }
+                        }
+                        EmbeddedKind::Unknown => field_decls.push(field_decl.to_owned()),
+                        EmbeddedKind::Struct(_, _) if !self.explored.contains(&field_index) => {}
+                        EmbeddedKind::Struct(type_name, embedded_struct_type) => {
+                            if let Some(copy_name) = self.copy_embedded(
+                                embedded_field,
+                                embedded_struct_type,
+                                &field_index,
+                                &name,
+                                copies,
+                            ) {
+                                if let Some(copy) = copies.iter().find(|x| x.name == copy_name) {
+                                    types.insert(
+                                        type_name,
+                                        Type::StructType(copy.struct_type.to_owned()),
+                                    );
+                                }
+                                embedded_copies.insert(embedded_field.name(), copy_name);
+                            }
+                            field_decls.push(field_decl.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+        copies.push(StructCopy {
+            name: name.to_owned(),
+            struct_type: StructType { field_decls },
+            types,
+            embedded_copies,
+        });
+
+        Some(name)
+    }
+
+    fn is_all_visible(&self, index: &[(usize, usize)], struct_type: &StructType) -> bool {
+        self.fields
+            .iter()
+            .filter(|field| field.index.len() == index.len() + 1 && field.index.starts_with(index))
+            .all(|field| self.dominant.contains(&field.index))
+            && struct_type
+                .field_decls
+                .iter()
+                .enumerate()
+                .all(|(i, field_decl)| match &field_decl.struct_field {
+                    StructField::EmbeddedField(embedded_field) if !has_tag_name(field_decl) => {
+                        match embedded_kind(embedded_field, self.types) {
+                            EmbeddedKind::Struct(_, embedded_struct_type) => {
+                                let mut index = index.to_vec();
+                                index.push((i, 0));
+                                self.explored.contains(&index)
+                                    && self.is_all_visible(&index, embedded_struct_type)
+                            }
+                            _ => true,
+                        }
+                    }
+                    _ => true,
+                })
+    }
+}
+
+// The fields in breadth-first order, and the indexes of the embedded structs explored.
+// `typeFields` of https://github.com/golang/go/blob/go1.16.3/src/encoding/json/encode.go
+fn json_fields(
+    struct_name: &str,
+    struct_type: &StructType,
+    types: &HashMap<String, Type>,
+    is_serde_visible: &dyn Fn(usize, &str) -> bool,
+) -> (Vec<JsonField>, HashSet<FieldIndex>) {
+    let mut fields = vec![];
+    let mut explored = HashSet::new();
+
+    let mut visited = HashSet::new();
+    let mut next: Vec<(FieldIndex, String, &StructType)> =
+        vec![(vec![], struct_name.to_owned(), struct_type)];
+    let mut next_count: HashMap<String, usize> = HashMap::new();
+
+    while !next.is_empty() {
+        let current = std::mem::take(&mut next);
+        let count = std::mem::take(&mut next_count);
+
+        for (index, type_name, struct_type) in current {
+            if !visited.insert(type_name.to_owned()) {
+                continue;
+            }
+            if !index.is_empty() {
+                explored.insert(index.to_owned());
+            }
+            // Embedded more than once at the same depth, the fields of which annihilate.
+            let times = if count.get(&type_name).copied().unwrap_or(1) > 1 {
+                2
+            } else {
+                1
+            };
+
+            for (i, field_decl) in struct_type.field_decls.iter().enumerate() {
+                let tag_name = match field_decl
+                    .tag
+                    .as_ref()
+                    .and_then(|tag| tag.as_json_struct_tag())
+                {
+                    Some(JsonStructTag::Ignored) => continue,
+                    Some(JsonStructTag::Normal(rename, _)) => rename.to_owned(),
+                    None => None,
+                };
+
+                let names: Vec<_> = match &field_decl.struct_field {
+                    StructField::IdentifierListType(names, _) => names.to_owned(),
+                    StructField::EmbeddedField(embedded_field) => {
+                        if is_ignored_embedded(embedded_field, types, index.len(), is_serde_visible)
+                        {
+                            continue;
+                        }
+                        if tag_name.is_none() {
+                            match embedded_kind(embedded_field, types) {
+                                EmbeddedKind::Struct(embedded_type_name, embedded_struct_type) => {
+                                    let mut index = index.to_owned();
+                                    index.push((i, 0));
+                                    let n = next_count
+                                        .entry(embedded_type_name.to_owned())
+                                        .or_default();
+                                    *n += 1;
+                                    if *n == 1 {
+                                        next.push((
+                                            index,
+                                            embedded_type_name,
+                                            embedded_struct_type,
+                                        ));
+                                    }
+                                    continue;
+                                }
+                                EmbeddedKind::Unknown => continue,
+                                EmbeddedKind::NonStruct => {}
+                            }
+                        }
+                        vec![embedded_field.name()]
+                    }
+                };
+
+                let is_embedded = matches!(field_decl.struct_field, StructField::EmbeddedField(_));
+                for (j, name) in names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, x)| *x != "_" && (is_embedded || is_serde_visible(index.len(), x)))
+                {
+                    let mut index = index.to_owned();
+                    index.push((i, j));
+                    for _ in 0..times {
+                        fields.push(JsonField {
+                            name: tag_name.to_owned().unwrap_or_else(|| name.to_owned()),
+                            is_tagged: tag_name.is_some(),
+                            index: index.to_owned(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    (fields, explored)
+}
+
+// The shallowest one, or the only tagged one of the shallowest ones; none if ambiguous.
+// `dominantField` of https://github.com/golang/go/blob/go1.16.3/src/encoding/json/encode.go
+fn dominant_fields(fields: &[JsonField]) -> HashSet<FieldIndex> {
+    let mut fields_by_name: HashMap<&str, Vec<&JsonField>> = HashMap::new();
+    for field in fields {
+        fields_by_name
+            .entry(field.name.as_str())
+            .or_default()
+            .push(field);
+    }
+
+    fields_by_name
+        .into_values()
+        .filter_map(|mut fields| {
+            fields.sort_by_key(|field| (field.index.len(), !field.is_tagged));
+            match fields.as_slice() {
+                [a, b, ..] if a.index.len() == b.index.len() && a.is_tagged == b.is_tagged => None,
+                [a, ..] => Some(a.index.to_owned()),
+                [] => None,
+            }
+        })
+        .collect()
+}
+
+// The embedded fields of unexported non-struct types, the embedded unexported struct types
+// of which are not ignored since they may have exported fields.
+// `typeFields` of https://github.com/golang/go/blob/go1.16.3/src/encoding/json/encode.go
+fn is_ignored_embedded(
+    embedded_field: &EmbeddedField,
+    types: &HashMap<String, Type>,
+    depth: usize,
+    is_serde_visible: &dyn Fn(usize, &str) -> bool,
+) -> bool {
+    !is_serde_visible(depth, &embedded_field.name())
+        && matches!(
+            embedded_kind(embedded_field, types),
+            EmbeddedKind::NonStruct
+        )
+}
+
+// e.g. `json:"base"`, the embedded fields of which are not promoted.
+fn has_tag_name(field_decl: &FieldDecl) -> bool {
+    matches!(
+        field_decl
+            .tag
+            .as_ref()
+            .and_then(|tag| tag.as_json_struct_tag()),
+        Some(JsonStructTag::Normal(Some(_), _))
+    )
+}
+
+fn embedded_kind<'a>(
+    embedded_field: &EmbeddedField,
+    types: &'a HashMap<String, Type>,
+) -> EmbeddedKind<'a> {
+    let type_name = match embedded_field {
+        EmbeddedField::TypeName(type_name) | EmbeddedField::PointerType(type_name) => type_name,
+    };
+    let mut r#type = match type_name {
+        TypeName::Identifier(name) => match types.get(name) {
+            Some(r#type) => r#type,
+            None => return EmbeddedKind::Unknown,
+        },
+        TypeName::QualifiedIdent(_, _) => return EmbeddedKind::Unknown,
+        _ => return EmbeddedKind::NonStruct,
+    };
+    // Follows the declared types, bounded in case of invalid recursive declarations.
+    for _ in 0..=types.len() {
+        r#type = match r#type {
+            Type::TypeName(TypeName::Identifier(name)) => match types.get(name) {
+                Some(r#type) => r#type,
+                None => return EmbeddedKind::Unknown,
+            },
+            Type::ParenthesizedType(ParenthesizedType(element)) => element,
+            Type::StructType(struct_type) => {
+                return EmbeddedKind::Struct(type_name.to_string(), struct_type)
+            }
+            _ => return EmbeddedKind::NonStruct,
+        };
+    }
+    EmbeddedKind::NonStruct
+}
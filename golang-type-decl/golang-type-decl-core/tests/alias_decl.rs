@@ -8,13 +8,14 @@ use golang_type_decl_core::{
 #[test]
 fn test_parse_single() -> Result<(), Box<dyn error::Error>> {
     let content = fs::read_to_string(PathBuf::new().join("tests/files/simple.go"))?;
-    let str = content.lines().skip(2).next().unwrap();
+    let str = content.lines().nth(2).unwrap();
 
     assert_eq!(
         TypeDecl {
             type_specs: vec![TypeSpec::AliasDecl(AliasDecl {
                 name: "Node".to_owned(),
-                r#type: Type::TypeName(TypeName::Int)
+                r#type: Type::TypeName(TypeName::Int),
+                span: Default::default(),
             })]
         },
         str.parse()?
@@ -43,11 +44,13 @@ fn test_parse_multi() -> Result<(), Box<dyn error::Error>> {
                             Type::TypeName(TypeName::Identifier("Node".to_owned())).into()
                         ))
                         .into()
-                    })
+                    }),
+                    span: Default::default(),
                 }),
                 TypeSpec::AliasDecl(AliasDecl {
                     name: "Polar".to_owned(),
-                    r#type: Type::TypeName(TypeName::Identifier("polar".to_owned()))
+                    r#type: Type::TypeName(TypeName::Identifier("polar".to_owned())),
+                    span: Default::default(),
                 }),
             ]
         },
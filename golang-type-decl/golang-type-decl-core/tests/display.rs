@@ -0,0 +1,43 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_type_decl_core::TypeDecl;
+
+#[test]
+fn test_display_gofmt() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/simple.go"))?;
+    for (skip, take) in &[(2, 1), (15, 4), (20, 3), (24, 6)] {
+        let str = content
+            .lines()
+            .skip(*skip)
+            .take(*take)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let type_decl: TypeDecl = str.parse()?;
+        assert_eq!(type_decl.to_string(), str);
+    }
+
+    let str = "type Page[K comparable, V any] struct {\n\tItems []V\n\tIndex map[K]int `json:\"index\"`\n}";
+    let type_decl: TypeDecl = str.parse()?;
+    assert_eq!(type_decl.to_string(), str);
+
+    Ok(())
+}
+
+#[test]
+fn test_display_roundtrip() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/simple.go"))?;
+    for (skip, take) in &[(5, 4), (10, 4)] {
+        let str = content
+            .lines()
+            .skip(*skip)
+            .take(*take)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let type_decl: TypeDecl = str.parse()?;
+        assert_eq!(type_decl, type_decl.to_string().parse()?);
+    }
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use std::{error, fs, path::PathBuf};
 
 use golang_type_decl_core::{
-    golang_type_core::{FieldDecl, PointerType, StructField, StructType, Type, TypeName},
+    golang_type_core::{
+        FieldDecl, MapType, PointerType, SliceType, StructField, StructType, Type, TypeElem,
+        TypeName, TypeParamDecl, TypeTerm,
+    },
     TypeDecl, TypeDef, TypeSpec,
 };
 
@@ -19,6 +22,7 @@ fn test_parse_single() -> Result<(), Box<dyn error::Error>> {
         TypeDecl {
             type_specs: vec![TypeSpec::TypeDef(TypeDef {
                 name: "TreeNode".to_owned(),
+                type_params: vec![],
                 r#type: Type::StructType(StructType {
                     field_decls: vec![
                         FieldDecl {
@@ -31,6 +35,8 @@ fn test_parse_single() -> Result<(), Box<dyn error::Error>> {
                                 .into()
                             ),
                             tag: None,
+                            span: Default::default(),
+                            tag_span: None,
                         },
                         FieldDecl {
                             struct_field: StructField::IdentifierListType(
@@ -42,9 +48,12 @@ fn test_parse_single() -> Result<(), Box<dyn error::Error>> {
                                 .into()
                             ),
                             tag: None,
+                            span: Default::default(),
+                            tag_span: None,
                         },
                     ]
-                })
+                }),
+                span: Default::default(),
             })]
         },
         str.parse()?
@@ -68,6 +77,7 @@ fn test_parse_multi() -> Result<(), Box<dyn error::Error>> {
             type_specs: vec![
                 TypeSpec::TypeDef(TypeDef {
                     name: "Point".to_owned(),
+                    type_params: vec![],
                     r#type: Type::StructType(StructType {
                         field_decls: vec![FieldDecl {
                             struct_field: StructField::IdentifierListType(
@@ -75,12 +85,17 @@ fn test_parse_multi() -> Result<(), Box<dyn error::Error>> {
                                 Type::TypeName(TypeName::Float64).into(),
                             ),
                             tag: None,
+                            span: Default::default(),
+                            tag_span: None,
                         },]
-                    })
+                    }),
+                    span: Default::default(),
                 }),
                 TypeSpec::TypeDef(TypeDef {
                     name: "polar".to_owned(),
+                    type_params: vec![],
                     r#type: Type::TypeName(TypeName::Identifier("Point".to_owned())),
+                    span: Default::default(),
                 })
             ]
         },
@@ -89,3 +104,121 @@ fn test_parse_multi() -> Result<(), Box<dyn error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_generic() -> Result<(), Box<dyn error::Error>> {
+    let type_decl: TypeDecl = r#"type Page[T any, K comparable] struct {
+        Items []T
+        Index map[K]T
+    }"#
+    .parse()?;
+
+    assert_eq!(
+        TypeDecl {
+            type_specs: vec![TypeSpec::TypeDef(TypeDef {
+                name: "Page".to_owned(),
+                type_params: vec![
+                    TypeParamDecl {
+                        names: vec!["T".to_owned()],
+                        constraint: TypeElem(vec![TypeTerm {
+                            r#type: Type::TypeName(TypeName::Identifier("any".to_owned())).into(),
+                            is_underlying: false,
+                        }]),
+                        span: Default::default(),
+                    },
+                    TypeParamDecl {
+                        names: vec!["K".to_owned()],
+                        constraint: TypeElem(vec![TypeTerm {
+                            r#type: Type::TypeName(TypeName::Identifier("comparable".to_owned()))
+                                .into(),
+                            is_underlying: false,
+                        }]),
+                        span: Default::default(),
+                    },
+                ],
+                r#type: Type::StructType(StructType {
+                    field_decls: vec![
+                        FieldDecl {
+                            struct_field: StructField::IdentifierListType(
+                                vec!["Items".to_owned()],
+                                Type::SliceType(SliceType {
+                                    element: Type::TypeName(TypeName::Identifier("T".to_owned()))
+                                        .into()
+                                })
+                                .into()
+                            ),
+                            tag: None,
+                            span: Default::default(),
+                            tag_span: None,
+                        },
+                        FieldDecl {
+                            struct_field: StructField::IdentifierListType(
+                                vec!["Index".to_owned()],
+                                Type::MapType(MapType {
+                                    key: Type::TypeName(TypeName::Identifier("K".to_owned()))
+                                        .into(),
+                                    value: Type::TypeName(TypeName::Identifier("T".to_owned()))
+                                        .into()
+                                })
+                                .into()
+                            ),
+                            tag: None,
+                            span: Default::default(),
+                            tag_span: None,
+                        },
+                    ]
+                }),
+                span: Default::default(),
+            })]
+        },
+        type_decl
+    );
+    if let TypeSpec::TypeDef(type_def) = &type_decl.type_specs[0] {
+        assert!(!type_def.type_params[0].is_comparable());
+        assert!(type_def.type_params[1].is_comparable());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_span() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/simple.go"))?;
+    let str = content
+        .lines()
+        .skip(10)
+        .take(4)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let type_decl: TypeDecl = str.parse()?;
+    match &type_decl.type_specs[1] {
+        TypeSpec::TypeDef(type_def) => {
+            assert_eq!(type_def.name, "polar");
+            assert_eq!(type_def.span.start.line, 3);
+            assert_eq!(type_def.span.start.column, 2);
+            assert_eq!(
+                &str[type_def.span.start_byte..type_def.span.end_byte],
+                "polar Point"
+            );
+        }
+        _ => panic!(),
+    }
+
+    let type_decl: TypeDecl =
+        "type User struct {\n\tName string\n\tAge  uint `json:\"age\"`\n}".parse()?;
+    match &type_decl.type_specs[0] {
+        TypeSpec::TypeDef(TypeDef {
+            r#type: Type::StructType(struct_type),
+            ..
+        }) => {
+            let field_decl = &struct_type.field_decls[1];
+            assert_eq!(field_decl.span.start.line, 3);
+            assert_eq!(field_decl.span.to_string(), "3:2");
+            assert_eq!(field_decl.tag_span.map(|span| span.start.column), Some(12));
+        }
+        _ => panic!(),
+    }
+
+    Ok(())
+}
@@ -29,6 +29,9 @@ impl Parse for FieldOpts {
                     } else if field_opt_k == "box_type" {
                         let box_type = input.parse::<LitBool>()?.value();
                         field_opt.box_type = box_type;
+                    } else if field_opt_k == "flatten_pointer" {
+                        let flatten_pointer = input.parse::<LitBool>()?.value();
+                        field_opt.flatten_pointer = flatten_pointer;
                     } else {
                         let err = format!("unexpected opt key: {}", field_opt_k);
                         return Err(SynError::new_spanned(field_opt_k, err));
@@ -4,19 +4,24 @@ use syn::{
     Error as SynError, Ident, LitBool, LitInt, LitStr, Token,
 };
 
-use crate::utils::path_to_code;
+use golang_macro_utils::path_to_code;
 
 use super::{field_opts::FieldOpts, field_types::FieldTypes};
 
 pub struct Input {
     pub code: String,
     pub nth: usize,
+    // Takes precedence over nth.
+    pub name: Option<String>,
     //
     pub disable_derive_serde_ser: bool,
     pub disable_derive_serde_de: bool,
     pub custom_derive: Vec<String>,
 
     pub alias_name: Option<String>,
+    pub disable_fixed_size_array: bool,
+    pub enable_unexported_fields: bool,
+    pub enable_case_insensitive_de: bool,
     //
     pub field_opts: FieldOpts,
 }
@@ -25,12 +30,16 @@ impl Parse for Input {
     fn parse(input: ParseStream) -> Result<Self, SynError> {
         let mut code = String::new();
         let mut nth = 0;
+        let mut name = None;
 
         let mut disable_derive_serde_ser = false;
         let mut disable_derive_serde_de = false;
         let mut custom_derive = vec![];
 
         let mut alias_name = None;
+        let mut disable_fixed_size_array = false;
+        let mut enable_unexported_fields = false;
+        let mut enable_case_insensitive_de = false;
 
         let mut field_types = FieldTypes::default();
         let mut field_opts = FieldOpts::default();
@@ -57,6 +66,9 @@ impl Parse for Input {
             } else if key == "nth" {
                 nth = input.parse::<LitInt>()?.base10_parse::<usize>()?;
                 input.parse::<Token![,]>()?;
+            } else if key == "name" {
+                name = Some(input.parse::<LitStr>()?.value());
+                input.parse::<Token![,]>()?;
             } else if key == "disable_derive_serde_ser" {
                 disable_derive_serde_ser = input.parse::<LitBool>()?.value();
                 input.parse::<Token![,]>()?;
@@ -72,6 +84,15 @@ impl Parse for Input {
             } else if key == "alias_name" {
                 alias_name = Some(input.parse::<LitStr>()?.value());
                 input.parse::<Token![,]>()?;
+            } else if key == "disable_fixed_size_array" {
+                disable_fixed_size_array = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else if key == "enable_unexported_fields" {
+                enable_unexported_fields = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else if key == "enable_case_insensitive_de" {
+                enable_case_insensitive_de = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
             } else if key == "field_types" {
                 field_types = input.parse()?;
                 input.parse::<Token![,]>()?;
@@ -92,10 +113,14 @@ impl Parse for Input {
         Ok(Self {
             code,
             nth,
+            name,
             disable_derive_serde_ser,
             disable_derive_serde_de,
             custom_derive,
             alias_name,
+            disable_fixed_size_array,
+            enable_unexported_fields,
+            enable_case_insensitive_de,
             field_opts,
         })
     }
@@ -1,28 +1,55 @@
+use golang_source_file::SourceFile;
 use golang_type_decl_core::{
-    golang_type_core::{StructField, Type},
+    golang_type_core::{StructField, Type, TypeMappings},
     type_def::json_struct::{JsonStruct, JsonStructOption},
-    TypeDecl, TypeSpec,
+    TypeSpec,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::utils;
+
 mod field_opts;
 mod field_types;
 mod input;
 
 pub use self::input::Input;
 
+// The well-known types of `golang_type_core::TypeMappings`, e.g. `big.Int`, use these.
+const SERDE_HELPERS: &str = "::golang_type_decl::serde_helpers";
+
 #[allow(clippy::needless_collect)]
 pub fn get_output(input: Input) -> TokenStream {
-    let type_decl = match input.code.parse::<TypeDecl>() {
-        Ok(type_decl) => type_decl,
+    let source_file = match input.code.parse::<SourceFile>() {
+        Ok(source_file) => source_file,
         Err(err) => {
             let err = err.to_string();
             return quote!(compile_error!(#err));
         }
     };
 
-    let type_def = match type_decl.type_specs.into_iter().nth(input.nth) {
+    let type_mappings = match TypeMappings::from_manifest_dir() {
+        Ok(type_mappings) => TypeMappings {
+            import_paths: source_file.import_paths(),
+            serde_helpers: Some(SERDE_HELPERS.to_owned()),
+            reexports: Some(utils::REEXPORTS.to_owned()),
+            declared_types: source_file
+                .type_specs()
+                .map(|type_spec| type_spec.name().to_owned())
+                .collect(),
+            ..type_mappings
+        },
+        Err(err) => return quote!(compile_error!(#err)),
+    };
+
+    let type_spec = match &input.name {
+        Some(name) => match utils::type_spec_by_name(&source_file, name) {
+            Ok(type_spec) => Some(type_spec.to_owned()),
+            Err(err) => return quote!(compile_error!(#err)),
+        },
+        None => source_file.type_specs().nth(input.nth).cloned(),
+    };
+    let type_def = match type_spec {
         Some(TypeSpec::TypeDef(type_def)) => type_def,
         Some(TypeSpec::AliasDecl(_)) => {
             let err = "Require [Type definitions](https://golang.org/ref/spec#TypeDef)";
@@ -47,11 +74,10 @@ pub fn get_output(input: Input) -> TokenStream {
     let field_names: Vec<_> = struct_type
         .field_decls
         .iter()
-        .map(|field_decl| match &field_decl.struct_field {
+        .flat_map(|field_decl| match &field_decl.struct_field {
             StructField::IdentifierListType(names, _) => names.to_owned(),
             StructField::EmbeddedField(embedded_field) => vec![embedded_field.name()],
         })
-        .flatten()
         .collect();
     for field_name in input.field_opts.0.keys() {
         if !field_names.contains(field_name) {
@@ -60,14 +86,30 @@ pub fn get_output(input: Input) -> TokenStream {
         }
     }
 
+    let types = source_file
+        .type_specs()
+        .map(|type_spec| match type_spec {
+            TypeSpec::AliasDecl(alias_decl) => {
+                (alias_decl.name.to_owned(), alias_decl.r#type.to_owned())
+            }
+            TypeSpec::TypeDef(type_def) => (type_def.name.to_owned(), type_def.r#type.to_owned()),
+        })
+        .collect();
+
     let json_struct = JsonStruct {
         name: name.to_owned(),
+        type_params: type_def.type_params.to_owned(),
         struct_type: struct_type.to_owned(),
         opt: JsonStructOption {
             enable_derive_serde_ser: !input.disable_derive_serde_ser,
             enable_derive_serde_de: !input.disable_derive_serde_de,
             custom_derive: input.custom_derive,
             alias_name: input.alias_name,
+            enable_fixed_size_array: !input.disable_fixed_size_array,
+            type_mappings,
+            types,
+            enable_unexported_fields: input.enable_unexported_fields,
+            enable_case_insensitive_de: input.enable_case_insensitive_de,
         },
         field_opts: input.field_opts.0,
     };
@@ -3,11 +3,13 @@ use syn::{
     Error as SynError, Ident, LitInt, LitStr, Token, Type,
 };
 
-use crate::utils::path_to_code;
+use golang_macro_utils::path_to_code;
 
 pub struct Input {
     pub code: String,
     pub nth: usize,
+    // Takes precedence over nth.
+    pub name: Option<String>,
     //
     pub alias_name: Option<String>,
     pub r#type: Option<Type>,
@@ -17,6 +19,7 @@ impl Parse for Input {
     fn parse(input: ParseStream) -> Result<Self, SynError> {
         let mut code = String::new();
         let mut nth = 0;
+        let mut name = None;
 
         let mut alias_name = None;
         let mut r#type = None;
@@ -43,6 +46,9 @@ impl Parse for Input {
             } else if key == "nth" {
                 nth = input.parse::<LitInt>()?.base10_parse::<usize>()?;
                 input.parse::<Token![,]>()?;
+            } else if key == "name" {
+                name = Some(input.parse::<LitStr>()?.value());
+                input.parse::<Token![,]>()?;
             } else if key == "alias_name" {
                 alias_name = Some(input.parse::<LitStr>()?.value());
                 input.parse::<Token![,]>()?;
@@ -58,6 +64,7 @@ impl Parse for Input {
         Ok(Self {
             code,
             nth,
+            name,
             alias_name,
             r#type,
         })
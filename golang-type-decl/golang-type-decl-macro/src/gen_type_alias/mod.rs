@@ -1,26 +1,50 @@
+use golang_source_file::SourceFile;
 use golang_type_decl_core::{
     alias_decl::type_alias::{TypeAlias, TypeAliasOption},
-    TypeDecl, TypeSpec,
+    golang_type_core::TypeMappings,
+    TypeSpec,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::utils;
+
 mod input;
 
 pub use self::input::Input;
 
 pub fn get_output(input: Input) -> TokenStream {
-    let type_decl = match input.code.parse::<TypeDecl>() {
-        Ok(type_decl) => type_decl,
+    let source_file = match input.code.parse::<SourceFile>() {
+        Ok(source_file) => source_file,
         Err(err) => {
             let err = err.to_string();
             return quote!(compile_error!(#err));
         }
     };
 
-    let (name, r#type) = match type_decl.type_specs.into_iter().nth(input.nth) {
-        Some(TypeSpec::TypeDef(type_def)) => (type_def.name, type_def.r#type),
-        Some(TypeSpec::AliasDecl(alias_decl)) => (alias_decl.name, alias_decl.r#type),
+    let type_mappings = match TypeMappings::from_manifest_dir() {
+        Ok(type_mappings) => TypeMappings {
+            import_paths: source_file.import_paths(),
+            reexports: Some(utils::REEXPORTS.to_owned()),
+            declared_types: source_file
+                .type_specs()
+                .map(|type_spec| type_spec.name().to_owned())
+                .collect(),
+            ..type_mappings
+        },
+        Err(err) => return quote!(compile_error!(#err)),
+    };
+
+    let type_spec = match &input.name {
+        Some(name) => match utils::type_spec_by_name(&source_file, name) {
+            Ok(type_spec) => Some(type_spec.to_owned()),
+            Err(err) => return quote!(compile_error!(#err)),
+        },
+        None => source_file.type_specs().nth(input.nth).cloned(),
+    };
+    let (name, type_params, r#type) = match type_spec {
+        Some(TypeSpec::TypeDef(type_def)) => (type_def.name, type_def.type_params, type_def.r#type),
+        Some(TypeSpec::AliasDecl(alias_decl)) => (alias_decl.name, vec![], alias_decl.r#type),
         None => {
             let err = "Require [Alias declarations](https://golang.org/ref/spec#AliasDecl)";
             return quote!(compile_error!(#err));
@@ -29,10 +53,12 @@ pub fn get_output(input: Input) -> TokenStream {
 
     let type_alias = TypeAlias {
         name,
+        type_params,
         r#type,
         opt: TypeAliasOption {
             alias_name: input.alias_name,
             special_type: input.r#type.map(|ty| quote!(#ty)),
+            type_mappings,
         },
     };
 
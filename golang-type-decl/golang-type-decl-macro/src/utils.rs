@@ -1,118 +1,75 @@
-use std::{env, fs, path::PathBuf};
-
-use regex::Regex;
-use url::Url;
-
-pub(crate) fn path_to_code(path: &str) -> Result<String, String> {
-    let cargo_manifest_dir = env::var("CARGO_MANIFEST_DIR")
-        .map_err(|_| "CARGO_MANIFEST_DIR is not set; please use Cargo to build".to_owned())?;
-
-    let mut path = PathBuf::from(cargo_manifest_dir).join(path);
-
-    let url = Url::parse(format!("file://{}", path.to_str().unwrap()).as_str())
-        .map_err(|err| format!("failed to read file at {:?}: {}", path, err))?;
-
-    let (line_start, line_end) = if let Some(fragment) = url.fragment() {
-        parse_fragment(fragment)
-            .map(|v| {
-                path = PathBuf::from(url.path());
-                v
-            })
-            .map_err(|err| format!("file invalid at {:?}: {}", path, err))?
-    } else {
-        (None, None)
-    };
-
-    if !path.exists() {
-        return Err(format!("file not exists at {:?}", path));
+use golang_source_file::{golang_type_decl_core::TypeSpec, SourceFile};
+
+// The crates of the well-known types, e.g. `serde_json` of `interface{}`, re-exported by
+// golang_type_decl.
+pub(crate) const REEXPORTS: &str = "::golang_type_decl";
+
+// e.g. `name = "User"`, the error lists the names and the closest one.
+pub(crate) fn type_spec_by_name<'a>(
+    source_file: &'a SourceFile,
+    name: &str,
+) -> Result<&'a TypeSpec, String> {
+    if let Some(type_spec) = source_file
+        .type_specs()
+        .find(|type_spec| type_spec.name() == name)
+    {
+        return Ok(type_spec);
     }
 
-    let content = fs::read_to_string(&path)
-        .map_err(|err| format!("failed to read file at {:?}: {}", path, err))?;
-
-    if let Some(line_start) = line_start {
-        Ok(content
-            .lines()
-            .skip(line_start - 1)
-            .take(
-                if let Some(line_end) = line_end {
-                    line_end - line_start
-                } else {
-                    0
-                } + 1,
-            )
-            .collect::<Vec<_>>()
-            .join("\r\n"))
-    } else {
-        Ok(content)
+    let names: Vec<_> = source_file.type_specs().map(|x| x.name()).collect();
+    match names
+        .iter()
+        .min_by_key(|x| strsim::levenshtein(&x.to_lowercase(), &name.to_lowercase()))
+    {
+        Some(closest_name) => Err(format!(
+            "type {} not found, available: {}; did you mean {}?",
+            name,
+            names.join(", "),
+            closest_name
+        )),
+        None => Err(format!("type {} not found, no type specs", name)),
     }
 }
 
-fn parse_fragment(fragment: &str) -> Result<(Option<usize>, Option<usize>), String> {
-    let re = Regex::new(r"^L(?P<start>[\d]+)(-L(?P<end>[\d]+))?$").unwrap();
-
-    let cap = re
-        .captures_iter(fragment)
-        .next()
-        .ok_or_else(|| "fragment invalid".to_owned())?;
-
-    let start = if let Some(val) = cap.name("start") {
-        Some(
-            val.as_str()
-                .parse::<usize>()
-                .map_err(|err| err.to_string())?,
-        )
-    } else {
-        None
-    };
-
-    let end = if let Some(val) = cap.name("end") {
-        Some(
-            val.as_str()
-                .parse::<usize>()
-                .map_err(|err| err.to_string())?,
-        )
-    } else {
-        None
-    };
-
-    if end.is_some() && start.unwrap_or_default() > end.unwrap_or_default() {
-        return Err("fragment invalid".to_owned());
-    }
-
-    Ok((start, end))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_fragment() {
-        match parse_fragment("L1") {
-            Ok((start, end)) => {
-                assert_eq!(start, Some(1));
-                assert_eq!(end, None);
-            }
-            Err(err) => assert!(false, "{}", err),
-        }
-
-        match parse_fragment("L1-L2") {
-            Ok((start, end)) => {
-                assert_eq!(start, Some(1));
-                assert_eq!(end, Some(2));
-            }
-            Err(err) => assert!(false, "{}", err),
-        }
-
-        match parse_fragment("Ln") {
-            Ok(_) => assert!(false),
-            Err(_) => (),
-        }
-
-        match parse_fragment("L1-L2-L3") {
-            Ok(_) => assert!(false),
-            Err(_) => (),
-        }
+    fn test_type_spec_by_name() {
+        let source_file: SourceFile = r#"
+package models
+
+type (
+    User struct{}
+    Users []User
+    Order struct{}
+)
+"#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            type_spec_by_name(&source_file, "Order").map(|x| x.name()),
+            Ok("Order")
+        );
+        assert_eq!(
+            type_spec_by_name(&source_file, "order").map(|x| x.name()),
+            Err(
+                "type order not found, available: User, Users, Order; did you mean Order?"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            type_spec_by_name(&source_file, "Userz").map(|x| x.name()),
+            Err(
+                "type Userz not found, available: User, Users, Order; did you mean User?"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            type_spec_by_name(&"package models".parse().unwrap(), "User").map(|x| x.name()),
+            Err("type User not found, no type specs".to_owned())
+        );
     }
 }
@@ -1,8 +1,14 @@
 pub use golang_type_decl_core::*;
 pub use golang_type_decl_macro;
 
+// The crates of the well-known Rust types, e.g. `::golang_type_decl::serde_json::Value`.
+#[cfg(feature = "enable-chrono")]
+pub use chrono;
+#[cfg(feature = "enable-num-bigint")]
+pub use num_bigint;
+pub use serde_json;
+
+pub mod serde_helpers;
+
 mod gen_json_struct;
 mod gen_type_alias;
-
-pub use gen_json_struct::*;
-pub use gen_type_alias::*;
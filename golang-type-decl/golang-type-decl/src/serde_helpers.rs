@@ -0,0 +1,864 @@
+// Used by the structs generated by `gen_json_struct!`, e.g. `#[serde(with = "...")]`.
+
+// Arrays longer than 32, serde implements the shorter ones.
+pub mod big_array {
+    use core::{convert::TryInto as _, fmt, marker::PhantomData};
+
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        ser::SerializeTuple as _,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    pub fn serialize<S, T, const N: usize>(array: &[T; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for element in array {
+            tuple.serialize_element(element)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+    }
+
+    struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = [T; N];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of length {}", N)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut elements = Vec::with_capacity(N);
+            while let Some(element) = seq.next_element()? {
+                if elements.len() == N {
+                    return Err(de::Error::invalid_length(N + 1, &self));
+                }
+                elements.push(element);
+            }
+            let len = elements.len();
+            elements
+                .try_into()
+                .map_err(|_| de::Error::invalid_length(len, &self))
+        }
+    }
+}
+
+// Arrays longer than 32 of the pointers and `omitempty`, nil is `null`.
+pub mod big_array_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S, T, const N: usize>(
+        array: &Option<[T; N]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        match array {
+            Some(array) => super::big_array::serialize(array, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D, T, const N: usize>(
+        deserializer: D,
+    ) -> Result<Option<[T; N]>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        struct Wrapper<T, const N: usize>([T; N]);
+
+        impl<'de, T, const N: usize> Deserialize<'de> for Wrapper<T, N>
+        where
+            T: Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::big_array::deserialize(deserializer).map(Wrapper)
+            }
+        }
+
+        Ok(Option::<Wrapper<T, N>>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+    }
+}
+
+// `[]byte`, encoded as a base64 string like encoding/json, nil is `null`.
+pub mod base64 {
+    use serde::{de, Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&::base64::encode(bytes))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) => ::base64::decode(s).map_err(de::Error::custom),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+// `[]byte` with `omitempty`.
+pub mod base64_option {
+    use serde::{de, Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S>(bytes: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match bytes {
+            Some(bytes) => super::base64::serialize(bytes, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) => ::base64::decode(s).map(Some).map_err(de::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+// The `[]byte` elements of the slices and the values of the maps, e.g. `[][]byte`.
+struct Base64<'a>(&'a [u8]);
+
+impl<'a> serde::Serialize for Base64<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        base64::serialize(self.0, serializer)
+    }
+}
+
+struct Base64Buf(Vec<u8>);
+
+impl<'de> serde::Deserialize<'de> for Base64Buf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        base64::deserialize(deserializer).map(Base64Buf)
+    }
+}
+
+// `[][]byte`, null is the empty one like the ones of `base64`.
+pub mod base64_slice {
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    use super::{Base64, Base64Buf};
+
+    pub fn serialize<S>(slice: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(slice.iter().map(|bytes| Base64(bytes)))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Vec<Base64Buf>>::deserialize(deserializer)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|bytes| bytes.0)
+            .collect())
+    }
+}
+
+// `map[K][]byte`.
+pub mod base64_map {
+    use std::{collections::HashMap, hash::Hash};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Base64, Base64Buf};
+
+    pub fn serialize<S, K>(map: &HashMap<K, Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+    {
+        serializer.collect_map(map.iter().map(|(key, bytes)| (key, Base64(bytes))))
+    }
+
+    pub fn deserialize<'de, D, K>(deserializer: D) -> Result<HashMap<K, Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de> + Eq + Hash,
+    {
+        Ok(Option::<HashMap<K, Base64Buf>>::deserialize(deserializer)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, bytes)| (key, bytes.0))
+            .collect())
+    }
+}
+
+// `map[K][]byte` with `omitempty`.
+pub mod base64_map_option {
+    use std::{collections::HashMap, hash::Hash};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Base64Buf;
+
+    pub fn serialize<S, K>(
+        map: &Option<HashMap<K, Vec<u8>>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+    {
+        match map {
+            Some(map) => super::base64_map::serialize(map, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D, K>(deserializer: D) -> Result<Option<HashMap<K, Vec<u8>>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de> + Eq + Hash,
+    {
+        Ok(Option::<HashMap<K, Base64Buf>>::deserialize(deserializer)?
+            .map(|map| map.into_iter().map(|(key, bytes)| (key, bytes.0)).collect()))
+    }
+}
+
+// `time.Time`, e.g. `2006-01-02T15:04:05.999999999Z`.
+#[cfg(feature = "enable-chrono")]
+pub mod rfc3339_nano {
+    use chrono::{DateTime, FixedOffset, TimeZone, Timelike as _};
+    use serde::{de, Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S, Tz>(date_time: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Tz: TimeZone,
+    {
+        let date_time = date_time.fixed_offset();
+
+        // Like Go, the trailing zeros of the fraction are removed, and UTC is `Z`.
+        let mut s = date_time.format("%Y-%m-%dT%H:%M:%S").to_string();
+        let nanos = date_time.nanosecond() % 1_000_000_000;
+        if nanos > 0 {
+            s.push_str(format!(".{:09}", nanos).trim_end_matches('0'));
+        }
+        if date_time.offset().local_minus_utc() == 0 {
+            s.push('Z');
+        } else {
+            s.push_str(&date_time.format("%:z").to_string());
+        }
+
+        serializer.serialize_str(&s)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&s).map_err(de::Error::custom)
+    }
+}
+
+// `big.Int`, a JSON number. Without the `enable-arbitrary-precision` feature, the numbers
+// are limited to 64 bits, as serde_json reads the larger ones as floats.
+#[cfg(feature = "enable-num-bigint")]
+pub mod big_int {
+    use core::{convert::TryFrom as _, fmt};
+
+    use num_bigint::BigInt;
+    use serde::{
+        de::{self, Visitor},
+        ser, Deserializer, Serializer,
+    };
+
+    pub fn serialize<S>(big_int: &BigInt, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Ok(v) = i64::try_from(big_int) {
+            serializer.serialize_i64(v)
+        } else if let Ok(v) = u64::try_from(big_int) {
+            serializer.serialize_u64(v)
+        } else {
+            serialize_out_of_range(big_int, serializer)
+        }
+    }
+
+    #[cfg(not(feature = "enable-arbitrary-precision"))]
+    fn serialize_out_of_range<S>(big_int: &BigInt, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Err(ser::Error::custom(format!(
+            "{} out of range, requires the enable-arbitrary-precision feature",
+            big_int
+        )))
+    }
+
+    #[cfg(feature = "enable-arbitrary-precision")]
+    fn serialize_out_of_range<S>(big_int: &BigInt, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use core::str::FromStr as _;
+        use serde::Serialize as _;
+
+        serde_json::Number::from_str(&big_int.to_string())
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BigInt, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(BigIntVisitor)
+    }
+
+    struct BigIntVisitor;
+
+    impl<'de> Visitor<'de> for BigIntVisitor {
+        type Value = BigInt;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an integer")
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(BigInt::from(v))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(BigInt::from(v))
+        }
+
+        fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(BigInt::from(v))
+        }
+
+        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(BigInt::from(v))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.fract() != 0.0 {
+                return Err(E::invalid_type(de::Unexpected::Float(v), &self));
+            }
+            Err(E::custom(format!(
+                "{} out of range, requires the enable-arbitrary-precision feature",
+                v
+            )))
+        }
+
+        // The numbers of `arbitrary_precision`, as a map even under `#[serde(flatten)]`.
+        #[cfg(feature = "enable-arbitrary-precision")]
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            use core::str::FromStr as _;
+            use serde::Deserialize as _;
+
+            let number =
+                serde_json::Number::deserialize(de::value::MapAccessDeserializer::new(map))?;
+            BigInt::from_str(&number.to_string()).map_err(de::Error::custom)
+        }
+    }
+}
+
+// The `Option` ones of the helpers above, for the pointers, nil is `null`.
+macro_rules! option_helper {
+    ($name:ident, $helper:ident, $ty:ty) => {
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S>(value: &Option<$ty>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match value {
+                    Some(value) => super::$helper::serialize(value, serializer),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<$ty>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct Wrapper($ty);
+
+                impl<'de> Deserialize<'de> for Wrapper {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        super::$helper::deserialize(deserializer).map(Wrapper)
+                    }
+                }
+
+                Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+            }
+        }
+    };
+}
+
+option_helper!(base64_slice_option, base64_slice, Vec<Vec<u8>>);
+#[cfg(feature = "enable-chrono")]
+option_helper!(
+    rfc3339_nano_option,
+    rfc3339_nano,
+    chrono::DateTime<chrono::FixedOffset>
+);
+#[cfg(feature = "enable-num-bigint")]
+option_helper!(big_int_option, big_int, num_bigint::BigInt);
+
+// The object keys matched to the JSON field names case-insensitively, preferring an exact
+// match, like encoding/json. The objects are buffered, since the last one of the keys of
+// the same field wins.
+// https://pkg.go.dev/encoding/json#Unmarshal
+pub mod case_insensitive {
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        de::{
+            self,
+            value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer},
+            Deserialize, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+        },
+        forward_to_deserialize_any,
+    };
+
+    pub struct Deserializer<D> {
+        inner: D,
+        names: &'static [&'static str],
+    }
+
+    impl<D> Deserializer<D> {
+        pub fn new(inner: D, names: &'static [&'static str]) -> Self {
+            Self { inner, names }
+        }
+    }
+
+    impl<'de, D> de::Deserializer<'de> for Deserializer<D>
+    where
+        D: de::Deserializer<'de>,
+    {
+        type Error = D::Error;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.inner.deserialize_any(CaseInsensitiveVisitor {
+                inner: visitor,
+                names: self.names,
+            })
+        }
+
+        fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.inner.deserialize_map(CaseInsensitiveVisitor {
+                inner: visitor,
+                names: self.names,
+            })
+        }
+
+        // As a map, since the flattened ones only take the entries of the exact field names.
+        fn deserialize_struct<V>(
+            self,
+            _name: &'static str,
+            _fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_map(visitor)
+        }
+
+        fn is_human_readable(&self) -> bool {
+            self.inner.is_human_readable()
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct enum identifier ignored_any
+        }
+    }
+
+    struct CaseInsensitiveVisitor<V> {
+        inner: V,
+        names: &'static [&'static str],
+    }
+
+    impl<'de, V> Visitor<'de> for CaseInsensitiveVisitor<V>
+    where
+        V: Visitor<'de>,
+    {
+        type Value = V::Value;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            self.inner.expecting(formatter)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entries: Vec<(String, Content)> = vec![];
+            while let Some(key) = map.next_key::<String>()? {
+                let key = field_name(&key, self.names).to_owned();
+                let value = map.next_value::<Content>()?;
+                match entries.iter_mut().find(|(name, _)| *name == key) {
+                    Some(entry) => entry.1 = value,
+                    None => entries.push((key, value)),
+                }
+            }
+
+            let mut map = MapDeserializer::new(entries.into_iter());
+            let value = self.inner.visit_map(&mut map)?;
+            map.end()?;
+            Ok(value)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            self.inner.visit_seq(seq)
+        }
+    }
+
+    // The buffered values, which keep the order of the keys unlike `serde_json::Value`.
+    enum Content {
+        Unit,
+        Bool(bool),
+        U64(u64),
+        I64(i64),
+        F64(f64),
+        #[cfg(feature = "enable-arbitrary-precision")]
+        Number(serde_json::Number),
+        String(String),
+        Bytes(Vec<u8>),
+        Seq(Vec<Content>),
+        Map(Vec<(Content, Content)>),
+    }
+
+    impl<'de> Deserialize<'de> for Content {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ContentVisitor)
+        }
+    }
+
+    struct ContentVisitor;
+
+    impl<'de> Visitor<'de> for ContentVisitor {
+        type Value = Content;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("any value")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::Bool(v))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::I64(v))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::U64(v))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::F64(v))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::String(v.to_owned()))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::String(v))
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::Bytes(v.to_vec()))
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::Bytes(v))
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::Unit)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Content::Unit)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            Content::deserialize(deserializer)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut elements = vec![];
+            while let Some(element) = seq.next_element()? {
+                elements.push(element);
+            }
+            Ok(Content::Seq(elements))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entries = vec![];
+            while let Some(entry) = map.next_entry()? {
+                entries.push(entry);
+            }
+
+            // The numbers of the `arbitrary_precision` feature of serde_json, kept as numbers
+            // to be read as floats too.
+            #[cfg(feature = "enable-arbitrary-precision")]
+            if let [(Content::String(key), Content::String(number))] = entries.as_slice() {
+                if key == "$serde_json::private::Number" {
+                    return number
+                        .parse()
+                        .map(Content::Number)
+                        .map_err(de::Error::custom);
+                }
+            }
+
+            Ok(Content::Map(entries))
+        }
+    }
+
+    impl<'de, E> IntoDeserializer<'de, E> for Content
+    where
+        E: de::Error,
+    {
+        type Deserializer = ContentDeserializer<E>;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            ContentDeserializer {
+                content: self,
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    struct ContentDeserializer<E> {
+        content: Content,
+        phantom: PhantomData<E>,
+    }
+
+    impl<'de, E> de::Deserializer<'de> for ContentDeserializer<E>
+    where
+        E: de::Error,
+    {
+        type Error = E;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::Unit => visitor.visit_unit(),
+                Content::Bool(v) => visitor.visit_bool(v),
+                Content::U64(v) => visitor.visit_u64(v),
+                Content::I64(v) => visitor.visit_i64(v),
+                Content::F64(v) => visitor.visit_f64(v),
+                #[cfg(feature = "enable-arbitrary-precision")]
+                Content::Number(v) => v.deserialize_any(visitor).map_err(de::Error::custom),
+                Content::String(v) => visitor.visit_string(v),
+                Content::Bytes(v) => visitor.visit_byte_buf(v),
+                Content::Seq(v) => {
+                    let mut seq = SeqDeserializer::new(v.into_iter());
+                    let value = visitor.visit_seq(&mut seq)?;
+                    seq.end()?;
+                    Ok(value)
+                }
+                Content::Map(v) => {
+                    let mut map = MapDeserializer::new(v.into_iter());
+                    let value = visitor.visit_map(&mut map)?;
+                    map.end()?;
+                    Ok(value)
+                }
+            }
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::Unit => visitor.visit_none(),
+                _ => visitor.visit_some(self),
+            }
+        }
+
+        fn deserialize_newtype_struct<V>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_newtype_struct(self)
+        }
+
+        // `"Variant"` or `{"Variant": ...}`.
+        fn deserialize_enum<V>(
+            self,
+            _name: &'static str,
+            _variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::String(v) => visitor.visit_enum(v.into_deserializer()),
+                Content::Map(v) => visitor.visit_enum(MapAccessDeserializer::new(
+                    MapDeserializer::new(v.into_iter()),
+                )),
+                _ => self.deserialize_any(visitor),
+            }
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+            identifier ignored_any
+        }
+    }
+
+    // The exact one, or the first one equal under simple case folding.
+    pub fn field_name<'a>(key: &'a str, names: &[&'a str]) -> &'a str {
+        if names.contains(&key) {
+            return key;
+        }
+        names
+            .iter()
+            .find(|name| equal_fold(name, key))
+            .copied()
+            .unwrap_or(key)
+    }
+
+    // e.g. `K` (U+212A KELVIN SIGN) and `k`, `ſ` (U+017F) and `S`, but not `ß` and `ss`.
+    // https://pkg.go.dev/strings#EqualFold
+    pub fn equal_fold(a: &str, b: &str) -> bool {
+        let mut a = a.chars();
+        let mut b = b.chars();
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return true,
+                (Some(x), Some(y))
+                    if x == y
+                        || x.to_lowercase().eq(y.to_lowercase())
+                        || x.to_uppercase().eq(y.to_uppercase()) => {}
+                _ => return false,
+            }
+        }
+    }
+}
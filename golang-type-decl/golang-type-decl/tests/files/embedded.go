@@ -0,0 +1,71 @@
+package models
+
+type Base struct {
+	ID        int64  `json:"id"`
+	CreatedAt string `json:"created_at"`
+}
+
+type Audit struct {
+	CreatedAt string `json:"created_at"`
+	By        string `json:"by"`
+}
+
+type Named struct {
+	Name string
+}
+
+type Tagged struct {
+	Name string `json:"Name"`
+}
+
+// Base is flattened, Named is optional, Audit is nested.
+type Page struct {
+	Base
+	*Named
+	Audit `json:"audit"`
+	Total int `json:"total"`
+}
+
+// ID dominates Base.ID, Base.CreatedAt and Audit.CreatedAt annihilate.
+type Record struct {
+	Base
+	Audit
+	ID string `json:"id"`
+}
+
+// Tagged.Name dominates Named.Name.
+type Person struct {
+	Named
+	Tagged
+}
+
+type Inner struct {
+	ID   int64  `json:"id"`
+	Note string `json:"note"`
+}
+
+type Mid struct {
+	Inner
+	Extra string `json:"extra"`
+}
+
+// ID dominates Mid.Inner.ID.
+type Outer struct {
+	Mid
+	ID string `json:"id"`
+}
+
+type timestamps struct {
+	CreatedAt string `json:"created_at"`
+	revision  int
+}
+
+type level int
+
+// timestamps promotes CreatedAt, level and token are ignored.
+type Post struct {
+	timestamps
+	level
+	Title string `json:"title"`
+	token string
+}
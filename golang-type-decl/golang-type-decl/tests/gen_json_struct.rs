@@ -49,7 +49,49 @@ fn with_nth() {
     "#,
         nth = 1
     );
-    Foo { bar: 0 };
+    let _ = Foo { bar: 0 };
+}
+
+#[test]
+fn with_name() {
+    gen_json_struct!(
+        r#"
+    type (
+        Bar = int
+        Foo struct {
+            bar uint
+        }
+    )
+    "#,
+        name = "Foo"
+    );
+    let _ = Foo { bar: 0 };
+}
+
+#[test]
+fn with_type_params() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Page[T any, K comparable] struct {
+        Items []T
+        Index map[K]int
+    }
+    "#
+    );
+
+    let page: Page<String, String> = serde_json::from_str(
+        r#"
+    {
+        "Items": ["foo", "bar"],
+        "Index": {"foo": 0, "bar": 1}
+    }
+    "#,
+    )?;
+
+    assert_eq!(page.items, vec!["foo", "bar"]);
+    assert_eq!(page.index.get("bar"), Some(&1));
+
+    Ok(())
 }
 
 #[test]
@@ -68,7 +110,7 @@ fn with_field_types_and_field_opts() -> Result<(), Box<dyn error::Error>> {
             "attr_serde_deserialize_with": "deserialize_bool_from_anything"
         }
     );
-    User {
+    let _ = User {
         age: 18_u8,
         actived: true,
     };
@@ -83,7 +125,7 @@ fn with_field_types_and_field_opts() -> Result<(), Box<dyn error::Error>> {
     )?;
 
     assert_eq!(user.age, 18);
-    assert_eq!(user.actived, true);
+    assert!(user.actived);
 
     Ok(())
 }
@@ -103,7 +145,149 @@ fn with_nth_and_field_types() {
     ;
         "bar" => Option<bool>
     );
-    Foo { bar: None };
+    let _ = Foo { bar: None };
+}
+
+#[test]
+fn with_unexported_fields() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Foo struct {
+        bar uint
+        Baz uint
+    }
+    "#,
+        enable_unexported_fields = true
+    );
+
+    let json = r#"{"bar":1,"Baz":2}"#;
+    let foo: Foo = serde_json::from_str(json)?;
+    assert_eq!(foo.bar, 1);
+    assert_eq!(serde_json::to_string(&foo)?, json);
+
+    Ok(())
+}
+
+#[test]
+fn with_skipped_unexported_fields() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Session struct {
+        ID    string
+        count int
+        at    time.Time
+    }
+    "#
+    );
+
+    let session: Session = serde_json::from_str(r#"{"ID":"a","count":1,"at":"x"}"#)?;
+    assert_eq!(session.count, 0);
+    // `chrono::DateTime` implements no `Default`.
+    assert_eq!(session.at, None);
+    assert_eq!(serde_json::to_string(&session)?, r#"{"ID":"a"}"#);
+
+    Ok(())
+}
+
+#[test]
+fn with_case_insensitive_de() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Base struct {
+        ID int64
+    }
+    "#,
+        enable_case_insensitive_de = true
+    );
+    gen_json_struct!(
+        r#"
+    type Base struct {
+        ID int64
+    }
+
+    type User struct {
+        Base
+        Name string
+        Kind string
+        Foo  string `json:"foo"`
+        Bar  string `json:"FOO"`
+        Addr string `json:"Address"`
+        Score float64
+        Meta struct {
+            Page int
+        }
+    }
+    "#,
+        name = "User",
+        enable_case_insensitive_de = true
+    );
+
+    let user: User = serde_json::from_str(
+        r#"
+    {
+        "id": 1,
+        "NAME": "foo",
+        "\u212aIND": "k",
+        "Foo": "a",
+        "FOO": "b",
+        "address": "bar",
+        "score": 1.5,
+        "meta": {"page": 2}
+    }
+    "#,
+    )?;
+
+    assert_eq!(user.base.id, 1);
+    assert_eq!(user.name, "foo");
+    assert_eq!(user.kind, "k");
+    assert_eq!(user.foo, "a");
+    assert_eq!(user.bar, "b");
+    assert_eq!(user.addr, "bar");
+    assert_eq!(user.score, 1.5);
+    assert_eq!(user.meta.page, 2);
+    assert_eq!(
+        serde_json::to_string(&user)?,
+        r#"{"ID":1,"Name":"foo","Kind":"k","foo":"a","FOO":"b","Address":"bar","Score":1.5,"Meta":{"Page":2}}"#
+    );
+
+    // The last one of the keys of the same field wins, like encoding/json.
+    let user: User = serde_json::from_str(
+        r#"
+    {
+        "ID": 1,
+        "id": 2,
+        "name": "foo",
+        "Name": "bar",
+        "NAME": "baz",
+        "Kind": "k",
+        "foo": "a",
+        "FOO": "b",
+        "Address": "bar",
+        "score": 1.5,
+        "SCORE": 2,
+        "meta": {"page": 2, "Page": 3}
+    }
+    "#,
+    )?;
+
+    assert_eq!(user.base.id, 2);
+    assert_eq!(user.name, "baz");
+    assert_eq!(user.score, 2.0);
+    assert_eq!(user.meta.page, 3);
+
+    gen_json_struct!(
+        r#"
+    type Page[T any] struct {
+        Items []T
+    }
+    "#,
+        enable_case_insensitive_de = true
+    );
+
+    let page: Page<String> = serde_json::from_str(r#"{"items": ["foo"]}"#)?;
+    assert_eq!(page.items, vec!["foo"]);
+
+    Ok(())
 }
 
 #[test]
@@ -122,3 +306,476 @@ fn with_other_opts() {
 
     println!("{:?}", Bar::default().clone());
 }
+
+#[test]
+fn with_fixed_size_array() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    const Size = 2 * 2
+
+    type Block struct {
+        ID     [16]byte
+        Hash   [64]uint8
+        Counts [Size]int32
+    }
+    "#
+    );
+
+    let json = format!(
+        r#"{{"ID":{:?},"Hash":{:?},"Counts":[1,2,3,4]}}"#,
+        [1_u8; 16], [2_u8; 64]
+    );
+    let block: Block = serde_json::from_str(&json)?;
+    assert_eq!(block.id, [1; 16]);
+    assert_eq!(block.hash, [2; 64]);
+    assert_eq!(block.counts, [1, 2, 3, 4]);
+    assert_eq!(serde_json::to_string(&block)?, json.replace(", ", ","));
+
+    assert!(serde_json::from_str::<Block>(&json.replace("1,2,3,4", "1,2,3")).is_err());
+    assert!(serde_json::from_str::<Block>(&json.replace("2, 2]", "2, 2, 2]")).is_err());
+
+    // The nested ones are Vec.
+    gen_json_struct!(
+        r#"
+    type Header struct {
+        Parent *[64]byte
+        Extra  [64]byte `json:",omitempty"`
+        Leaves [][40]byte
+        Roots  map[string][33]int
+        Blocks [40][40]byte
+    }
+    "#
+    );
+
+    let mut value = serde_json::json!({
+        "Parent": vec![1; 64],
+        "Extra": vec![2; 64],
+        "Leaves": [vec![3; 40]],
+        "Roots": {"a": vec![4; 33]},
+        "Blocks": vec![vec![5; 40]; 40],
+    });
+    let header: Header = serde_json::from_value(value.clone())?;
+    assert_eq!(header.parent, Some([1; 64]));
+    assert_eq!(header.extra, Some([2; 64]));
+    assert_eq!(header.leaves, vec![vec![3; 40]]);
+    assert_eq!(header.roots["a"], vec![4; 33]);
+    assert_eq!(header.blocks.to_vec(), vec![vec![5; 40]; 40]);
+    assert_eq!(serde_json::to_value(&header)?, value);
+
+    value["Extra"] = serde_json::Value::Null;
+    let header: Header = serde_json::from_value(value)?;
+    assert_eq!(header.extra, None);
+
+    Ok(())
+}
+
+#[test]
+fn with_disable_fixed_size_array() {
+    gen_json_struct!(
+        r#"
+    type Block struct {
+        ID [16]byte
+    }
+    "#,
+        disable_fixed_size_array = true
+    );
+
+    let _ = Block { id: vec![1; 16] };
+}
+
+#[test]
+fn with_bytes() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Blob struct {
+        Data     []byte
+        Checksum []uint8 `json:",omitempty"`
+        Key      [4]byte
+    }
+    "#
+    );
+
+    let json = r#"{"Data":"aGVsbG8=","Checksum":"AQI=","Key":[1,2,3,4]}"#;
+    let blob: Blob = serde_json::from_str(json)?;
+    assert_eq!(blob.data, b"hello");
+    assert_eq!(blob.checksum, Some(vec![1, 2]));
+    assert_eq!(blob.key, [1, 2, 3, 4]);
+    assert_eq!(serde_json::to_string(&blob)?, json);
+
+    let blob: Blob = serde_json::from_str(r#"{"Data":null,"Key":[0,0,0,0]}"#)?;
+    assert!(blob.data.is_empty());
+    assert_eq!(blob.checksum, None);
+    assert_eq!(
+        serde_json::to_string(&blob)?,
+        r#"{"Data":"","Key":[0,0,0,0]}"#
+    );
+
+    assert!(serde_json::from_str::<Blob>(r#"{"Data":"!","Key":[0,0,0,0]}"#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn with_nested_bytes() -> Result<(), Box<dyn error::Error>> {
+    type Blob = Vec<u8>;
+    gen_json_struct!(
+        r#"
+    type Blob []byte
+
+    type Bundle struct {
+        Raw    Blob
+        Parts  [][]byte
+        Named  map[string][]byte
+        Extra  map[string]Blob `json:",omitempty"`
+        Chunks []*Blob         `json:",omitempty"`
+    }
+    "#,
+        name = "Bundle"
+    );
+
+    let json = r#"{"Raw":"AQI=","Parts":["aGk=",""],"Named":{"a":"AQ=="},"Chunks":["Aw=="]}"#;
+    let bundle: Bundle = serde_json::from_str(json)?;
+    assert_eq!(bundle.raw, vec![1, 2]);
+    assert_eq!(bundle.parts, vec![b"hi".to_vec(), vec![]]);
+    assert_eq!(bundle.named["a"], vec![1]);
+    assert_eq!(bundle.extra, None);
+    assert_eq!(bundle.chunks, Some(vec![vec![3]]));
+    assert_eq!(serde_json::to_string(&bundle)?, json);
+
+    let bundle: Bundle =
+        serde_json::from_str(r#"{"Raw":null,"Parts":[null],"Named":null,"Extra":{"b":"BA=="}}"#)?;
+    assert!(bundle.raw.is_empty());
+    assert_eq!(bundle.parts, vec![Vec::<u8>::new()]);
+    assert!(bundle.named.is_empty());
+    assert_eq!(
+        bundle.extra.as_ref().map(|x| x["b"].to_owned()),
+        Some(vec![4])
+    );
+    assert_eq!(bundle.chunks, None);
+
+    Ok(())
+}
+
+#[test]
+fn with_declared_any() -> Result<(), Box<dyn error::Error>> {
+    #[allow(non_camel_case_types)]
+    type any = String;
+    gen_json_struct!(
+        r#"
+    type any string
+
+    type Tagged struct {
+        Tag  any
+        Tags map[string]any
+    }
+    "#,
+        name = "Tagged"
+    );
+
+    let tagged: Tagged = serde_json::from_str(r#"{"Tag":"a","Tags":{"b":"c"}}"#)?;
+    assert_eq!(tagged.tag, "a");
+    assert_eq!(tagged.tags["b"], "c");
+    assert!(serde_json::from_str::<Tagged>(r#"{"Tag":1,"Tags":{}}"#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn with_empty_interfaces() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Event struct {
+        Payload interface{}
+        Tags    []any
+        Extra   map[string]interface{}
+        Groups  map[string][]any
+    }
+    "#
+    );
+
+    let json = r#"{"Payload":{"id":1},"Tags":["a",1,null],"Extra":{"b":true,"a":[1.5]},"Groups":{"x":[]}}"#;
+    let event: Event = serde_json::from_str(json)?;
+    assert_eq!(event.payload["id"], 1);
+    assert_eq!(
+        event.tags,
+        vec![serde_json::json!("a"), 1.into(), serde_json::Value::Null]
+    );
+    let extra: &serde_json::Map<String, serde_json::Value> = &event.extra;
+    assert_eq!(extra["a"][0], 1.5);
+    assert_eq!(event.groups["x"], Vec::<serde_json::Value>::new());
+    assert_eq!(
+        serde_json::to_value(&event)?,
+        serde_json::from_str::<serde_json::Value>(json)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn with_std_types() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Event struct {
+        CreatedAt time.Time
+        UpdatedAt time.Time `json:",omitempty"`
+        Timeout   time.Duration
+        Payload   json.RawMessage
+        Amount    json.Number
+        Balance   *big.Int
+        Total     big.Int `json:",omitempty"`
+        Addr      net.IP
+    }
+    "#
+    );
+
+    let json = r#"{"CreatedAt":"2021-06-01T08:30:00.12Z","Timeout":1500000000,"Payload":{"a":[1,2]},"Amount":1.5,"Balance":-9223372036854775808,"Addr":"10.0.0.1"}"#;
+    let event: Event = serde_json::from_str(json)?;
+    assert_eq!(
+        event.created_at,
+        chrono::DateTime::parse_from_rfc3339("2021-06-01T08:30:00.120Z")?
+    );
+    assert_eq!(event.updated_at, None);
+    assert_eq!(event.timeout, 1_500_000_000);
+    assert_eq!(event.payload.get(), r#"{"a":[1,2]}"#);
+    assert_eq!(event.amount.as_f64(), Some(1.5));
+    assert_eq!(event.balance, Some(num_bigint::BigInt::from(i64::MIN)));
+    assert_eq!(event.total, None);
+    assert_eq!(event.addr, std::net::IpAddr::from([10, 0, 0, 1]));
+    assert_eq!(serde_json::to_string(&event)?, json);
+
+    // The `omitempty` ones.
+    let json_omitempty = json.replace(
+        r#""Timeout""#,
+        r#""UpdatedAt":"2021-06-01T08:30:00.000000001+08:00","Timeout""#,
+    );
+    let json_omitempty = json_omitempty.replace(r#""Addr""#, r#""Total":-1,"Addr""#);
+    let event: Event = serde_json::from_str(&json_omitempty)?;
+    assert_eq!(
+        event.updated_at,
+        Some(chrono::DateTime::parse_from_rfc3339(
+            "2021-06-01T08:30:00.000000001+08:00"
+        )?)
+    );
+    assert_eq!(event.total, Some(num_bigint::BigInt::from(-1)));
+    assert_eq!(serde_json::to_string(&event)?, json_omitempty);
+
+    let event: Event = serde_json::from_str(
+        &json
+            .replace("08:30:00.12Z", "10:30:00+02:00")
+            .replace("-9223372036854775808", "-1"),
+    )?;
+    assert_eq!(
+        serde_json::to_value(&event)?["CreatedAt"],
+        "2021-06-01T10:30:00+02:00"
+    );
+    assert_eq!(serde_json::to_value(&event)?["Balance"], -1);
+
+    // Like big.Int, not a string.
+    assert!(
+        serde_json::from_str::<Event>(&json.replace("-9223372036854775808", r#""-1""#)).is_err()
+    );
+
+    let mut event: Event = serde_json::from_str(&json.replace("1500000000", "-1500000000"))?;
+    assert_eq!(event.timeout, -1_500_000_000);
+
+    // Beyond 64 bits.
+    event.balance = Some(num_bigint::BigInt::from(1) << 64);
+    assert_eq!(
+        serde_json::to_string(&event).is_ok(),
+        cfg!(feature = "enable-arbitrary-precision")
+    );
+    assert_eq!(
+        serde_json::from_str::<Event>(
+            &json.replace("-9223372036854775808", "-9223372036854775809")
+        )
+        .is_ok(),
+        cfg!(feature = "enable-arbitrary-precision")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn with_embedded_std_types() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Account struct {
+        Balance big.Int
+        Limit   *big.Int
+    }
+    "#
+    );
+    gen_json_struct!(
+        r#"
+    type Account struct {
+        Balance big.Int
+        Limit   *big.Int
+    }
+
+    type Wallet struct {
+        Account
+        Name string
+    }
+    "#,
+        name = "Wallet"
+    );
+
+    let json = r#"{"Balance":18446744073709551615,"Limit":-1,"Name":"a"}"#;
+    let wallet: Wallet = serde_json::from_str(json)?;
+    assert_eq!(wallet.account.balance, num_bigint::BigInt::from(u64::MAX));
+    assert_eq!(wallet.account.limit, Some(num_bigint::BigInt::from(-1)));
+    assert_eq!(wallet.name, "a");
+    assert_eq!(serde_json::to_string(&wallet)?, json);
+
+    // Beyond 64 bits, the flattened numbers require the enable-arbitrary-precision feature.
+    let json = json.replace("18446744073709551615", "123456789012345678901234567890");
+    if cfg!(feature = "enable-arbitrary-precision") {
+        let wallet: Wallet = serde_json::from_str(&json)?;
+        assert_eq!(
+            wallet.account.balance,
+            "123456789012345678901234567890".parse::<num_bigint::BigInt>()?
+        );
+        assert_eq!(serde_json::to_string(&wallet)?, json);
+    } else {
+        assert!(serde_json::from_str::<Wallet>(&json).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn with_pointer_fields() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type Node struct {
+        Name    *string
+        Count   **int     `json:"count"`
+        Balance *big.Int  `json:"balance,omitempty"`
+        Limit   *big.Int  `json:"limit"`
+        Data    *[]byte   `json:"data"`
+        Next    *Node     `json:"next"`
+        Age     *int      `json:"age"`
+    }
+    "#;
+        "Age" => { "flatten_pointer": true }
+    );
+
+    let json = r#"{"Name":"a","count":1,"balance":-1,"limit":2,"data":"AQI=","next":{"Name":null,"count":null,"limit":null,"data":null,"next":null,"age":2},"age":1}"#;
+    let node: Node = serde_json::from_str(json)?;
+    assert_eq!(node.name.as_deref(), Some("a"));
+    assert_eq!(node.count, Some(1));
+    assert_eq!(node.balance, Some(num_bigint::BigInt::from(-1)));
+    assert_eq!(node.limit, Some(num_bigint::BigInt::from(2)));
+    assert_eq!(node.data, Some(vec![1, 2]));
+    assert_eq!(node.age, 1);
+    let next: &Node = node.next.as_deref().ok_or("next")?;
+    assert_eq!(next.name, None);
+    assert_eq!(next.balance, None);
+    assert!(next.next.is_none());
+    assert_eq!(serde_json::to_string(&node)?, json);
+
+    assert!(serde_json::from_str::<Node>(&json.replace(r#""age":1"#, r#""age":null"#)).is_err());
+
+    // Missing keys are nil, also the ones of the serde helpers.
+    let node: Node = serde_json::from_str(r#"{"age":1}"#)?;
+    assert_eq!(node.name, None);
+    assert_eq!(node.limit, None);
+    assert_eq!(node.data, None);
+    assert!(node.next.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn with_type_mappings() -> Result<(), Box<dyn error::Error>> {
+    // See golang-rs.toml
+    gen_json_struct!(
+        r#"
+    package models
+
+    import (
+        id "github.com/google/uuid"
+        "github.com/shopspring/decimal"
+    )
+
+    type Order struct {
+        ID      id.UUID
+        UserID  UserID
+        Price   decimal.Decimal
+        Total   types.Int128
+        Tax     *types.Int128
+    }
+    "#
+    );
+
+    let json = r#"{"ID":"6ba7b810-9dad-11d1-80b4-00c04fd430c8","UserID":1,"Price":9.99,"Total":-9223372036854775808,"Tax":null}"#;
+    let order: Order = serde_json::from_str(json)?;
+    assert_eq!(order.id, "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(order.user_id, 1_u64);
+    assert_eq!(order.price.to_string(), "9.99");
+    assert_eq!(order.total, num_bigint::BigInt::from(i64::MIN));
+    assert_eq!(order.tax, None);
+    assert_eq!(serde_json::to_string(&order)?, json);
+
+    Ok(())
+}
+
+#[test]
+fn with_anonymous_structs() -> Result<(), Box<dyn error::Error>> {
+    gen_json_struct!(
+        r#"
+    type User struct {
+        Name string `json:"name"`
+        Meta struct {
+            Page  int `json:"page"`
+            Total int `json:"total,omitempty"`
+            Links struct {
+                Next string `json:"next"`
+            } `json:"links"`
+        } `json:"meta"`
+        Tags   []struct{ Name string }     `json:"tags"`
+        Scores map[string]struct{ N int } `json:"scores"`
+        Parent *struct{ ID int64 }        `json:"parent"`
+    }
+    "#
+    );
+
+    let json = r#"{"name":"foo","meta":{"page":1,"links":{"next":"/2"}},"tags":[{"Name":"a"}],"scores":{"x":{"N":2}},"parent":{"ID":3}}"#;
+    let user: User = serde_json::from_str(json)?;
+    assert_eq!(user.meta.page, 1);
+    assert_eq!(user.meta.total, None);
+    assert_eq!(user.meta.links.next, "/2");
+    assert_eq!(user.tags[0].name, "a");
+    assert_eq!(user.scores["x"].n, 2);
+    assert_eq!(user.parent.as_ref().map(|x| x.id), Some(3));
+    assert_eq!(serde_json::to_string(&user)?, json);
+
+    let _ = UserMeta {
+        page: 0,
+        total: None,
+        links: UserMetaLinks {
+            next: "".to_owned(),
+        },
+    };
+    let _ = UserTags {
+        name: "".to_owned(),
+    };
+    let _ = UserScores { n: 0 };
+    let _ = UserParent { id: 0 };
+
+    gen_json_struct!(
+        r#"
+    type Page[T any, K comparable] struct {
+        Data struct {
+            Items []T
+        }
+        Keys []K
+    }
+    "#
+    );
+    let page: Page<String, u8> = serde_json::from_str(r#"{"Data":{"Items":["a"]},"Keys":[1]}"#)?;
+    assert_eq!(page.data.items, vec!["a".to_owned()]);
+    let _: PageData<String> = page.data;
+
+    Ok(())
+}
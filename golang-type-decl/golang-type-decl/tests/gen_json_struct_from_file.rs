@@ -3,25 +3,25 @@ use golang_type_decl::gen_json_struct_from_file;
 #[test]
 fn simple() {
     gen_json_struct_from_file!("../golang-type-decl-core/tests/files/simple.go#L21-L23");
-    Foo { bar: 0 };
+    let _ = Foo { bar: 0 };
 }
 
 #[test]
 fn with_nth() {
     gen_json_struct_from_file!("tests/files/simple.go#L25-L30", nth = 1);
-    Foo { bar: 0 };
+    let _ = Foo { bar: 0 };
 }
 
 #[test]
 fn with_field_types() {
     gen_json_struct_from_file!("tests/files/simple.go#L21-L23"; "bar" => bool);
-    Foo { bar: true };
+    let _ = Foo { bar: true };
 }
 
 #[test]
 fn with_nth_and_field_types() {
     gen_json_struct_from_file!("tests/files/simple.go#L25-L30", nth = 1; "bar" => bool);
-    Foo { bar: true };
+    let _ = Foo { bar: true };
 }
 
 #[test]
@@ -33,4 +33,102 @@ fn with_box_type() {
         "left" => { "box_type": true },
         "right" => { "box_type": true }
     );
+    let _: Option<TreeNode> = None;
+}
+
+#[test]
+fn with_source_file() -> Result<(), Box<dyn std::error::Error>> {
+    use golang_type_decl::gen_type_alias_from_file;
+
+    gen_type_alias_from_file!("tests/files/models.go", nth = 0);
+    gen_json_struct_from_file!("tests/files/models.go", nth = 1);
+    gen_type_alias_from_file!("tests/files/models.go", nth = 2);
+    gen_json_struct_from_file!("tests/files/models.go", nth = 3);
+
+    let group: Group = serde_json::from_str(
+        r#"{"name": "admin", "users": [{"id": 1, "name": "foo", "status": 1}]}"#,
+    )?;
+    assert_eq!(group.name, "admin");
+    assert_eq!(group.users.len(), 1);
+    assert_eq!(group.users[0].status, 1);
+
+    Ok(())
+}
+
+#[test]
+fn with_embedded_structs() -> Result<(), Box<dyn std::error::Error>> {
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Base");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Audit");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Named");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Tagged");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Page");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Record");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Person");
+
+    let json =
+        r#"{"id":1,"created_at":"c","Name":"foo","audit":{"created_at":"d","by":"b"},"total":2}"#;
+    let page: Page = serde_json::from_str(json)?;
+    assert_eq!(page.base.id, 1);
+    assert_eq!(page.named.as_ref().map(|x| x.name.as_str()), Some("foo"));
+    assert_eq!(page.audit.by, "b");
+    assert_eq!(serde_json::to_string(&page)?, json);
+
+    let json = r#"{"id":1,"created_at":"c","audit":{"created_at":"d","by":"b"},"total":2}"#;
+    let page: Page = serde_json::from_str(json)?;
+    assert!(page.named.is_none());
+    assert_eq!(serde_json::to_string(&page)?, json);
+
+    let record: Record = serde_json::from_str(r#"{"id":"x","created_at":"c","by":"b"}"#)?;
+    let RecordBase {} = record.base;
+    assert_eq!(record.audit.by, "b");
+    assert_eq!(record.id, "x");
+    assert_eq!(serde_json::to_string(&record)?, r#"{"by":"b","id":"x"}"#);
+
+    let person: Person = serde_json::from_str(r#"{"Name":"foo"}"#)?;
+    let PersonNamed {} = person.named;
+    assert_eq!(person.tagged.name, "foo");
+    assert_eq!(serde_json::to_string(&person)?, r#"{"Name":"foo"}"#);
+
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Inner");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Mid");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Outer");
+
+    let json = r#"{"note":"n","extra":"e","id":"x"}"#;
+    let outer: Outer = serde_json::from_str(json)?;
+    let _: OuterMid = outer.mid;
+    let _: OuterMidInner = outer.mid.inner;
+    assert_eq!(outer.mid.inner.note, "n");
+    assert_eq!(outer.id, "x");
+    assert_eq!(serde_json::to_string(&outer)?, json);
+
+    let mid: Mid = serde_json::from_str(r#"{"id":1,"note":"n","extra":"e"}"#)?;
+    assert_eq!(mid.inner.id, 1);
+
+    Ok(())
+}
+
+#[test]
+fn with_unexported_fields() -> Result<(), Box<dyn std::error::Error>> {
+    // The type names are kept as is.
+    #[allow(non_camel_case_types)]
+    type level = isize;
+    #[allow(non_camel_case_types)]
+    type timestamps = Timestamps;
+
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "timestamps");
+    gen_json_struct_from_file!("tests/files/embedded.go", name = "Post");
+
+    let json = r#"{"created_at":"c","level":1,"title":"t","token":"x","revision":2}"#;
+    let post: Post = serde_json::from_str(json)?;
+    assert_eq!(post.timestamps.created_at, "c");
+    assert_eq!(post.timestamps.revision, 0);
+    assert_eq!(post.level, 0);
+    assert_eq!(post.title, "t");
+    assert_eq!(post.token, "");
+    assert_eq!(
+        serde_json::to_string(&post)?,
+        r#"{"created_at":"c","title":"t"}"#
+    );
+
+    Ok(())
 }
@@ -42,6 +42,24 @@ fn with_nth_and_type() {
     let _: Bar = None;
 }
 
+#[test]
+fn with_name() {
+    gen_type_alias!(
+        r#"
+    type (
+        Foo struct {
+            bar uint
+        }
+        Bar = int
+    )
+    "#,
+        name = "Bar",
+        alias_name = "Baz"
+    );
+
+    let _: Baz = 1_isize;
+}
+
 #[test]
 fn with_other_opts() {
     gen_type_alias!(
@@ -53,3 +71,25 @@ fn with_other_opts() {
 
     let _: Bar = 1_isize;
 }
+
+#[test]
+fn with_type_params() {
+    gen_type_alias!(
+        r#"
+    type List[T any] []T
+    "#
+    );
+    let _: List<u8> = vec![0];
+}
+
+#[test]
+fn with_type_mappings() {
+    // See golang-rs.toml
+    gen_type_alias!(
+        r#"
+    type Price = decimal.Decimal
+    "#
+    );
+
+    let _: Price = serde_json::Number::from(1);
+}
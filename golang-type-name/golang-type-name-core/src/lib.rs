@@ -1,6 +1,9 @@
-use std::str::{self, FromStr};
+use std::{
+    fmt,
+    str::{self, FromStr},
+};
 
-use golang_parser::{tree_sitter::Node, Parser};
+use golang_parser::{tree_sitter::Node, Parser, Span};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum TypeName {
@@ -41,10 +44,10 @@ pub type PackageName = String;
 pub enum TypeNameParseError {
     #[error("GolangParserError {0:?}")]
     GolangParserError(#[from] golang_parser::Error),
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
-    #[error("NodeKindUnknown {0}")]
-    NodeKindUnknown(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
     #[error("Utf8Error {0:?}")]
     Utf8Error(str::Utf8Error),
     #[error("IdentifierMissing")]
@@ -55,7 +58,11 @@ impl FromStr for TypeName {
     type Err = TypeNameParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parser = Parser::new(format!("var _ {}", s))?;
+        if s.trim().is_empty() {
+            return Err(TypeNameParseError::IdentifierMissing);
+        }
+
+        let parser = Parser::new_strict(format!("var _ {}", s))?;
         let source = parser.get_source();
         let root_node = parser.get_root_node();
 
@@ -64,24 +71,35 @@ impl FromStr for TypeName {
         let node_var_declaration = root_node
             .named_children(&mut cursor)
             .find(|node| node.kind() == "var_declaration")
-            .ok_or_else(|| TypeNameParseError::NodeMissing("var_declaration".to_string()))?;
+            .ok_or_else(|| {
+                TypeNameParseError::NodeMissing(
+                    "var_declaration".to_string(),
+                    Span::from(root_node),
+                )
+            })?;
         let node_var_spec = node_var_declaration
             .named_children(&mut cursor)
             .find(|node| node.kind() == "var_spec")
-            .ok_or_else(|| TypeNameParseError::NodeMissing("var_spec".to_string()))?;
+            .ok_or_else(|| {
+                TypeNameParseError::NodeMissing(
+                    "var_spec".to_string(),
+                    Span::from(node_var_declaration),
+                )
+            })?;
 
-        let _ = node_var_spec
-            .named_child(0)
-            .ok_or_else(|| TypeNameParseError::NodeMissing("var_spec name".to_string()))?;
-        let node_var_spec_type = node_var_spec
-            .named_child(1)
-            .ok_or_else(|| TypeNameParseError::NodeMissing("var_spec type".to_string()))?;
+        let _ = node_var_spec.named_child(0).ok_or_else(|| {
+            TypeNameParseError::NodeMissing("var_spec name".to_string(), Span::from(node_var_spec))
+        })?;
+        let node_var_spec_type = node_var_spec.named_child(1).ok_or_else(|| {
+            TypeNameParseError::NodeMissing("var_spec type".to_string(), Span::from(node_var_spec))
+        })?;
 
         match node_var_spec_type.kind() {
             "qualified_type" => Self::from_qualified_type_node(node_var_spec_type, source),
             "type_identifier" => Self::from_type_identifier_node(node_var_spec_type, source),
             _ => Err(TypeNameParseError::NodeKindUnknown(
                 node_var_spec_type.kind().to_owned(),
+                Span::from(node_var_spec_type),
             )),
         }
     }
@@ -89,12 +107,12 @@ impl FromStr for TypeName {
 
 impl TypeName {
     pub fn from_qualified_type_node(node: Node, source: &[u8]) -> Result<Self, TypeNameParseError> {
-        let node_qualified_type_package = node
-            .named_child(0)
-            .ok_or_else(|| TypeNameParseError::NodeMissing("qualified_type package".to_string()))?;
-        let node_qualified_type_name = node
-            .named_child(1)
-            .ok_or_else(|| TypeNameParseError::NodeMissing("qualified_type name".to_string()))?;
+        let node_qualified_type_package = node.named_child(0).ok_or_else(|| {
+            TypeNameParseError::NodeMissing("qualified_type package".to_string(), Span::from(node))
+        })?;
+        let node_qualified_type_name = node.named_child(1).ok_or_else(|| {
+            TypeNameParseError::NodeMissing("qualified_type name".to_string(), Span::from(node))
+        })?;
 
         let package_str = node_qualified_type_package
             .utf8_text(source)
@@ -121,31 +139,36 @@ impl TypeName {
             return Err(TypeNameParseError::IdentifierMissing);
         }
 
+        Ok(Self::from_identifier(s))
+    }
+
+    // Predeclared type names, e.g. `int`, else `Identifier`.
+    pub fn from_identifier(s: &str) -> Self {
         match s {
             //
-            "bool" => Ok(Self::Bool),
+            "bool" => Self::Bool,
             //
-            "uint8" => Ok(Self::Uint8),
-            "uint16" => Ok(Self::Uint16),
-            "uint32" => Ok(Self::Uint32),
-            "uint64" => Ok(Self::Uint64),
-            "int8" => Ok(Self::Int8),
-            "int16" => Ok(Self::Int16),
-            "int32" => Ok(Self::Int32),
-            "int64" => Ok(Self::Int64),
-            "float32" => Ok(Self::Float32),
-            "float64" => Ok(Self::Float64),
-            "complex64" => Ok(Self::Complex64),
-            "complex128" => Ok(Self::Complex128),
-            "byte" => Ok(Self::Byte),
-            "rune" => Ok(Self::Rune),
-            "uint" => Ok(Self::Uint),
-            "int" => Ok(Self::Int),
-            "uintptr" => Ok(Self::Uintptr),
+            "uint8" => Self::Uint8,
+            "uint16" => Self::Uint16,
+            "uint32" => Self::Uint32,
+            "uint64" => Self::Uint64,
+            "int8" => Self::Int8,
+            "int16" => Self::Int16,
+            "int32" => Self::Int32,
+            "int64" => Self::Int64,
+            "float32" => Self::Float32,
+            "float64" => Self::Float64,
+            "complex64" => Self::Complex64,
+            "complex128" => Self::Complex128,
+            "byte" => Self::Byte,
+            "rune" => Self::Rune,
+            "uint" => Self::Uint,
+            "int" => Self::Int,
+            "uintptr" => Self::Uintptr,
             //
-            "string" => Ok(Self::String),
+            "string" => Self::String,
             //
-            _ => Ok(Self::Identifier(s.to_owned())),
+            _ => Self::Identifier(s.to_owned()),
         }
     }
 
@@ -178,6 +201,12 @@ impl TypeName {
     }
 }
 
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[cfg(feature = "enable-quote-to_tokens")]
 mod enable_quote_to_tokens {
     use super::TypeName;
@@ -332,7 +361,7 @@ mod tests {
                     assert_eq!(TypeName::Identifier("foo".to_owned()), str.parse()?);
                     assert_eq!(TypeName::Identifier("foo".to_owned()).name(), str)
                 }
-                _ => assert!(false),
+                _ => panic!(),
             }
         }
 
@@ -342,9 +371,9 @@ mod tests {
     #[test]
     fn test_parse_with_identifier_missing() {
         match "".parse::<TypeName>() {
-            Ok(_) => assert!(false),
+            Ok(_) => panic!(),
             Err(TypeNameParseError::IdentifierMissing) => {}
-            Err(err) => assert!(false, "{:?}", err),
+            Err(err) => panic!("{:?}", err),
         }
     }
 }
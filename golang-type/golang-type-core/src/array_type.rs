@@ -1,8 +1,8 @@
-use std::{num::ParseIntError, str};
+use std::fmt;
 
-use golang_parser::tree_sitter::Node;
+use golang_parser::{tree_sitter::Node, Span};
 
-use crate::{Type, TypeParseError};
+use crate::{ConstEvaluator, Expression, Type, TypeParseError};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct ArrayType {
@@ -13,40 +13,30 @@ pub struct ArrayType {
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum ArrayLength {
     IntLiteral(usize),
-    Other(String),
+    // Not resolved yet, e.g. `[N]byte` before N is known.
+    Expression(Box<Expression>),
+    // `[...]T` of composite literals.
+    Implicit,
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum ArrayTypeParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
-    #[error("Utf8Error {0:?}")]
-    Utf8Error(str::Utf8Error),
-    #[error("IntLiteralValueInvalid {0:?}")]
-    IntLiteralValueInvalid(ParseIntError),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
 }
 impl ArrayType {
     pub(crate) fn from_array_type_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
-        let node_array_type_length = node
-            .named_child(0)
-            .ok_or_else(|| ArrayTypeParseError::NodeMissing("array_type length".to_string()))?;
-        let node_array_type_element = node
-            .named_child(1)
-            .ok_or_else(|| ArrayTypeParseError::NodeMissing("array_type element".to_string()))?;
-
-        let length_str = node_array_type_length
-            .utf8_text(source)
-            .map_err(ArrayTypeParseError::Utf8Error)?;
-
-        let length = match node_array_type_length.kind() {
-            "int_literal" => {
-                let length: usize = length_str
-                    .parse()
-                    .map_err(ArrayTypeParseError::IntLiteralValueInvalid)?;
-                ArrayLength::IntLiteral(length)
-            }
-            _ => ArrayLength::Other(length_str.to_string()),
-        };
+        let node_array_type_length = node.named_child(0).ok_or_else(|| {
+            ArrayTypeParseError::NodeMissing("array_type length".to_string(), Span::from(node))
+        })?;
+        let node_array_type_element = node.named_child(1).ok_or_else(|| {
+            ArrayTypeParseError::NodeMissing("array_type element".to_string(), Span::from(node))
+        })?;
+
+        // e.g. `[2 * 8]int`, the identifiers are resolved later, e.g. `[N]byte`.
+        let length =
+            ArrayLength::Expression(Expression::from_node(node_array_type_length, source)?.into())
+                .resolved(&ConstEvaluator::default());
 
         let element = Type::from_node(node_array_type_element, source)?;
 
@@ -57,21 +47,76 @@ impl ArrayType {
     }
 }
 
+impl ArrayLength {
+    // https://golang.org/ref/spec#Array_types
+    // The length must be a non-negative constant representable by a value of type int.
+    pub fn eval(&self, evaluator: &ConstEvaluator) -> Option<usize> {
+        match self {
+            Self::IntLiteral(length) => Some(*length),
+            Self::Expression(expression) => evaluator
+                .eval(expression)
+                .ok()
+                .and_then(|constant| constant.value.to_usize()),
+            Self::Implicit => None,
+        }
+    }
+
+    pub fn resolved(self, evaluator: &ConstEvaluator) -> Self {
+        match self.eval(evaluator) {
+            Some(length) => Self::IntLiteral(length),
+            None => self,
+        }
+    }
+}
+
+impl fmt::Display for ArrayType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]{}", self.length, self.element)
+    }
+}
+
+impl fmt::Display for ArrayLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IntLiteral(length) => write!(f, "{}", length),
+            Self::Expression(expression) => write!(f, "{}", expression),
+            Self::Implicit => write!(f, "..."),
+        }
+    }
+}
+
 #[cfg(feature = "enable-quote-to_tokens")]
 mod enable_quote_to_tokens {
-    use super::ArrayType;
+    use super::{ArrayLength, ArrayType};
+    use crate::TypeMappings;
 
-    use proc_macro2::{Punct, Spacing, TokenStream};
+    use proc_macro2::{Literal, Punct, Spacing, TokenStream};
     use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
 
-    impl ToTokens for ArrayType {
-        fn to_tokens(&self, tokens: &mut TokenStream) {
+    impl ArrayType {
+        pub(crate) fn to_token_stream_with(&self, type_mappings: &TypeMappings) -> TokenStream {
+            let mut tokens = TokenStream::new();
+
+            let element = self.element.to_token_stream_with(type_mappings);
+            // Falls back to Vec if the length is unknown, e.g. `[pkg.Size]byte`.
+            if let ArrayLength::IntLiteral(length) = &self.length {
+                let length = Literal::usize_unsuffixed(*length);
+                tokens.append_all(quote!([#element; #length]));
+                return tokens;
+            }
+
             let vec_ident = format_ident!("{}", "Vec");
             tokens.append_all(quote!(#vec_ident));
             tokens.append(Punct::new('<', Spacing::Alone));
-            let element = &self.element;
-            tokens.append_all(quote!(#element));
+            tokens.append_all(element);
             tokens.append(Punct::new('>', Spacing::Alone));
+            tokens
+        }
+    }
+
+    impl ToTokens for ArrayType {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.to_token_stream_with(&TypeMappings::default()));
         }
     }
 }
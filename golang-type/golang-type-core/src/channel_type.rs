@@ -1,18 +1,102 @@
-use golang_parser::tree_sitter::Node;
+use std::fmt;
 
-use crate::TypeParseError;
+use golang_parser::{tree_sitter::Node, Span};
+
+use crate::{Type, TypeParseError};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub struct ChannelType {}
+pub struct ChannelType {
+    pub direction: ChannelDirection,
+    pub element: Box<Type>,
+}
+
+// https://golang.org/ref/spec#Channel_types
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ChannelDirection {
+    // chan T
+    Bidirectional,
+    // chan<- T
+    Send,
+    // <-chan T
+    Receive,
+}
 
 #[derive(thiserror::Error, Debug)]
-pub enum ChannelTypeParseError {}
+pub enum ChannelTypeParseError {
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+}
 impl ChannelType {
     pub(crate) fn from_channel_type_node(
-        _node: Node,
-        _source: &[u8],
+        node: Node,
+        source: &[u8],
     ) -> Result<Self, TypeParseError> {
-        // TODO
-        Ok(Self {})
+        let node_channel_type_element = node.named_child(0).ok_or_else(|| {
+            ChannelTypeParseError::NodeMissing("channel_type element".to_string(), Span::from(node))
+        })?;
+
+        let node_channel_type_first = node.child(0).ok_or_else(|| {
+            ChannelTypeParseError::NodeMissing("channel_type chan".to_string(), Span::from(node))
+        })?;
+        let node_channel_type_second = node.child(1).ok_or_else(|| {
+            ChannelTypeParseError::NodeMissing("channel_type chan".to_string(), Span::from(node))
+        })?;
+
+        let direction = match (
+            node_channel_type_first.kind(),
+            node_channel_type_second.kind(),
+        ) {
+            ("<-", _) => ChannelDirection::Receive,
+            (_, "<-") => ChannelDirection::Send,
+            _ => ChannelDirection::Bidirectional,
+        };
+
+        let element = Type::from_node(node_channel_type_element, source)?;
+
+        Ok(Self::new_with_leftmost_chan(direction, element))
+    }
+
+    // https://golang.org/ref/spec#Channel_types
+    // The <- operator associates with the leftmost chan possible,
+    // but tree-sitter-go parses `chan<- chan int` as `chan (<-chan int)`.
+    fn new_with_leftmost_chan(direction: ChannelDirection, element: Type) -> Self {
+        match (direction, element) {
+            (
+                ChannelDirection::Bidirectional,
+                Type::ChannelType(Self {
+                    direction: ChannelDirection::Receive,
+                    element,
+                }),
+            ) => Self {
+                direction: ChannelDirection::Send,
+                element: Type::ChannelType(Self::new_with_leftmost_chan(
+                    ChannelDirection::Bidirectional,
+                    *element,
+                ))
+                .into(),
+            },
+            (direction, element) => Self {
+                direction,
+                element: element.into(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.direction, self.element.as_ref()) {
+            // `chan <-chan T` would be read as `chan<- (chan T)`
+            (
+                ChannelDirection::Bidirectional,
+                element @ Type::ChannelType(Self {
+                    direction: ChannelDirection::Receive,
+                    ..
+                }),
+            ) => write!(f, "chan ({})", element),
+            (ChannelDirection::Bidirectional, element) => write!(f, "chan {}", element),
+            (ChannelDirection::Send, element) => write!(f, "chan<- {}", element),
+            (ChannelDirection::Receive, element) => write!(f, "<-chan {}", element),
+        }
     }
 }
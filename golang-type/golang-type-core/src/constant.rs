@@ -0,0 +1,861 @@
+use std::{collections::HashMap, convert::TryFrom, fmt, str};
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One as _, Signed as _, ToPrimitive as _, Zero as _};
+
+use crate::{
+    BasicLit, BinaryExpression, BinaryOperator, CallExpression, Expression, ParenthesizedType,
+    Type, TypeName, UnaryOperator,
+};
+
+// https://golang.org/ref/spec#Constants
+// Numeric constants are exact, floats are rationals.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ConstValue {
+    Bool(bool),
+    // Untyped rune constants are integers whose default type is `rune`.
+    Rune(BigInt),
+    Int(BigInt),
+    Float(BigRational),
+    // Go strings may hold arbitrary bytes, e.g. `"\xff"`.
+    String(Vec<u8>),
+}
+
+// Like gc, untyped integer constants are limited to 512 bits.
+const MAX_INT_BITS: u64 = 512;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Constant {
+    pub value: ConstValue,
+    // None if untyped.
+    pub r#type: Option<Type>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConstEvalError {
+    #[error("IdentifierUnknown {0}")]
+    IdentifierUnknown(String),
+    #[error("NotConstant {0}")]
+    NotConstant(String),
+    #[error("IotaOutsideConstDecl")]
+    IotaOutsideConstDecl,
+    #[error("LiteralInvalid {0}")]
+    LiteralInvalid(String),
+    #[error("Overflow {0}")]
+    Overflow(String),
+    #[error("Truncated {0} to {1}")]
+    Truncated(String, String),
+    #[error("DivisionByZero")]
+    DivisionByZero,
+    #[error("MismatchedTypes {0} and {1}")]
+    MismatchedTypes(String, String),
+    #[error("InvalidOperation {0}")]
+    InvalidOperation(String),
+    #[error("Unsupported {0}")]
+    Unsupported(String),
+}
+
+// Evaluates constant expressions.
+// https://golang.org/ref/spec#Constant_expressions
+#[derive(Default, Debug, Clone)]
+pub struct ConstEvaluator {
+    // Keyed by identifier, or `package.identifier` for the ones of other packages.
+    pub constants: HashMap<String, Constant>,
+    // The value of `iota`, only set while evaluating a ConstSpec.
+    pub iota: Option<i128>,
+}
+
+impl ConstEvaluator {
+    // With the commonly used constants of the standard library, e.g. `time.Second`.
+    pub fn with_std_constants() -> Self {
+        let mut constants = HashMap::new();
+
+        // https://pkg.go.dev/time#Duration
+        let duration = Type::TypeName(TypeName::QualifiedIdent(
+            "time".to_owned(),
+            "Duration".to_owned(),
+        ));
+        for (name, value) in &[
+            ("Nanosecond", 1_i128),
+            ("Microsecond", 1_000),
+            ("Millisecond", 1_000_000),
+            ("Second", 1_000_000_000),
+            ("Minute", 60_000_000_000),
+            ("Hour", 3_600_000_000_000),
+        ] {
+            constants.insert(
+                format!("time.{}", name),
+                Constant {
+                    value: ConstValue::Int(BigInt::from(*value)),
+                    r#type: Some(duration.to_owned()),
+                },
+            );
+        }
+
+        Self {
+            constants,
+            iota: None,
+        }
+    }
+
+    pub fn eval(&self, expression: &Expression) -> Result<Constant, ConstEvalError> {
+        match expression {
+            Expression::Identifier(name) => self.eval_identifier(name),
+            Expression::BasicLit(basic_lit) => {
+                ConstValue::from_basic_lit(basic_lit).map(Constant::untyped)
+            }
+            Expression::Selector(operand, name) => match operand.as_ref() {
+                Expression::Identifier(package_name) => {
+                    let name = format!("{}.{}", package_name, name);
+                    self.constants
+                        .get(&name)
+                        .cloned()
+                        .ok_or(ConstEvalError::IdentifierUnknown(name))
+                }
+                _ => Err(ConstEvalError::NotConstant(expression.to_string())),
+            },
+            Expression::Call(call_expression) => self.eval_call(call_expression),
+            Expression::Conversion(r#type, operand) => self.eval(operand)?.convert(r#type),
+            Expression::Unary(operator, operand) => self.eval(operand)?.unary(*operator),
+            Expression::Binary(BinaryExpression {
+                left,
+                operator,
+                right,
+            }) => self.eval(left)?.binary(*operator, self.eval(right)?),
+            Expression::Parenthesized(element) => self.eval(element),
+            Expression::CompositeLit(_) => Err(ConstEvalError::NotConstant(expression.to_string())),
+        }
+    }
+
+    fn eval_identifier(&self, name: &str) -> Result<Constant, ConstEvalError> {
+        match name {
+            "true" => Ok(Constant::untyped(ConstValue::Bool(true))),
+            "false" => Ok(Constant::untyped(ConstValue::Bool(false))),
+            "iota" => self
+                .iota
+                .map(|iota| Constant::untyped(ConstValue::Int(BigInt::from(iota))))
+                .ok_or(ConstEvalError::IotaOutsideConstDecl),
+            "nil" => Err(ConstEvalError::NotConstant(name.to_owned())),
+            _ => self
+                .constants
+                .get(name)
+                .cloned()
+                .ok_or_else(|| ConstEvalError::IdentifierUnknown(name.to_owned())),
+        }
+    }
+
+    fn eval_call(&self, call_expression: &CallExpression) -> Result<Constant, ConstEvalError> {
+        let arguments = call_expression
+            .arguments
+            .iter()
+            .map(|argument| self.eval(argument))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // https://golang.org/ref/spec#Built-in_functions
+        if let Expression::Identifier(name) = call_expression.function.as_ref() {
+            match (name.as_str(), arguments.as_slice()) {
+                ("len", [argument]) => {
+                    return match &argument.value {
+                        ConstValue::String(s) => Ok(Constant {
+                            value: ConstValue::Int(BigInt::from(s.len())),
+                            r#type: Some(Type::TypeName(TypeName::Int)),
+                        }),
+                        _ => Err(ConstEvalError::NotConstant(call_expression.to_string())),
+                    };
+                }
+                ("min", [first, rest @ ..]) | ("max", [first, rest @ ..]) => {
+                    let operator = if name == "min" {
+                        BinaryOperator::Less
+                    } else {
+                        BinaryOperator::Greater
+                    };
+                    let mut result = first.to_owned();
+                    for argument in rest {
+                        let (left, right) = result.unify(argument.to_owned())?;
+                        result = if right.clone().binary(operator, left.clone())?.value
+                            == ConstValue::Bool(true)
+                        {
+                            right
+                        } else {
+                            left
+                        };
+                    }
+                    // The result has the largest kind of the untyped operands.
+                    if let (None, Some(v)) = (&result.r#type, result.value.to_rational()) {
+                        if arguments
+                            .iter()
+                            .any(|x| matches!(x.value, ConstValue::Float(_)))
+                        {
+                            result.value = ConstValue::Float(v);
+                        }
+                    }
+                    return Ok(result);
+                }
+                ("complex", _) | ("real", _) | ("imag", _) => {
+                    return Err(ConstEvalError::Unsupported(call_expression.to_string()))
+                }
+                _ => {}
+            }
+        }
+
+        // Otherwise a conversion, e.g. `Kind(1)`.
+        let r#type = expression_to_type(&call_expression.function)
+            .ok_or_else(|| ConstEvalError::NotConstant(call_expression.to_string()))?;
+        match arguments.as_slice() {
+            [argument] if !call_expression.is_variadic => argument.to_owned().convert(&r#type),
+            _ => Err(ConstEvalError::NotConstant(call_expression.to_string())),
+        }
+    }
+}
+
+// `Kind`, `time.Duration` or `(Kind)`
+fn expression_to_type(expression: &Expression) -> Option<Type> {
+    match expression {
+        Expression::Identifier(name) => Some(Type::TypeName(TypeName::from_identifier(name))),
+        Expression::Selector(operand, name) => match operand.as_ref() {
+            Expression::Identifier(package_name) => Some(Type::TypeName(TypeName::QualifiedIdent(
+                package_name.to_owned(),
+                name.to_owned(),
+            ))),
+            _ => None,
+        },
+        Expression::Parenthesized(element) => expression_to_type(element),
+        _ => None,
+    }
+}
+
+impl Constant {
+    pub fn untyped(value: ConstValue) -> Self {
+        Self {
+            value,
+            r#type: None,
+        }
+    }
+
+    // The type of a typed constant, else the default type of the untyped constant.
+    pub fn type_or_default(&self) -> Type {
+        self.r#type
+            .to_owned()
+            .unwrap_or_else(|| Type::TypeName(self.value.default_type()))
+    }
+
+    // https://golang.org/ref/spec#Conversions
+    // The underlying type of a defined type is unknown, so the value is kept as is.
+    pub fn convert(self, r#type: &Type) -> Result<Self, ConstEvalError> {
+        let type_name = match r#type {
+            Type::TypeName(type_name) => type_name,
+            Type::ParenthesizedType(ParenthesizedType(element)) => return self.convert(element),
+            _ => {
+                return Err(ConstEvalError::Unsupported(format!(
+                    "conversion to {}",
+                    r#type
+                )))
+            }
+        };
+
+        let cannot_convert =
+            |value: &ConstValue| ConstEvalError::InvalidOperation(format!("{}({})", r#type, value));
+
+        let value = match type_name {
+            TypeName::Bool => match self.value {
+                ConstValue::Bool(_) => self.value,
+                _ => return Err(cannot_convert(&self.value)),
+            },
+            TypeName::String => match self.value {
+                ConstValue::String(_) => self.value,
+                ConstValue::Int(v) | ConstValue::Rune(v) => ConstValue::String(
+                    v.to_u32()
+                        .and_then(char::from_u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER)
+                        .to_string()
+                        .into_bytes(),
+                ),
+                _ => return Err(cannot_convert(&self.value)),
+            },
+            // Rounded to the precision of the type.
+            TypeName::Float32 | TypeName::Float64 => {
+                let v = match self.value.to_f64() {
+                    Some(v) => v,
+                    None => return Err(cannot_convert(&self.value)),
+                };
+                let v = if type_name == &TypeName::Float32 {
+                    v as f32 as f64
+                } else {
+                    v
+                };
+                match BigRational::from_float(v) {
+                    Some(v) => ConstValue::Float(v),
+                    None => {
+                        return Err(ConstEvalError::Overflow(format!(
+                            "{} as {}",
+                            self.value, r#type
+                        )))
+                    }
+                }
+            }
+            TypeName::Complex64 | TypeName::Complex128 => {
+                return Err(ConstEvalError::Unsupported(format!(
+                    "conversion to {}",
+                    r#type
+                )))
+            }
+            TypeName::QualifiedIdent(_, _) | TypeName::Identifier(_) => self.value,
+            _ => {
+                let v = match self.value {
+                    ConstValue::Int(v) | ConstValue::Rune(v) => v,
+                    ConstValue::Float(v) if v.is_integer() => v.to_integer(),
+                    ConstValue::Float(v) => {
+                        return Err(ConstEvalError::Truncated(
+                            ConstValue::Float(v).to_string(),
+                            r#type.to_string(),
+                        ))
+                    }
+                    _ => return Err(cannot_convert(&self.value)),
+                };
+                if let Some((min, max)) = int_type_range(type_name) {
+                    if v < BigInt::from(min) || v > BigInt::from(max) {
+                        return Err(ConstEvalError::Overflow(format!("{} as {}", v, r#type)));
+                    }
+                }
+                ConstValue::Int(v)
+            }
+        };
+
+        Ok(Self {
+            value,
+            r#type: Some(r#type.to_owned()),
+        })
+    }
+
+    // https://golang.org/ref/spec#Operators
+    // Untyped operands are converted to the type of the other operand.
+    fn unify(self, other: Self) -> Result<(Self, Self), ConstEvalError> {
+        match (&self.r#type, &other.r#type) {
+            (Some(left_type), Some(right_type)) if left_type != right_type => Err(
+                ConstEvalError::MismatchedTypes(left_type.to_string(), right_type.to_string()),
+            ),
+            (None, Some(r#type)) => Ok((self.convert(&r#type.to_owned())?, other)),
+            (Some(r#type), None) => {
+                let r#type = r#type.to_owned();
+                Ok((self, other.convert(&r#type)?))
+            }
+            _ => Ok((self, other)),
+        }
+    }
+
+    fn unary(self, operator: UnaryOperator) -> Result<Self, ConstEvalError> {
+        let invalid = || ConstEvalError::InvalidOperation(format!("{}{}", operator, self.value));
+
+        let value = match (operator, &self.value) {
+            (UnaryOperator::Plus, ConstValue::Int(_))
+            | (UnaryOperator::Plus, ConstValue::Rune(_))
+            | (UnaryOperator::Plus, ConstValue::Float(_)) => self.value.to_owned(),
+            (UnaryOperator::Minus, ConstValue::Int(v)) => ConstValue::Int(-v),
+            (UnaryOperator::Minus, ConstValue::Rune(v)) => ConstValue::Rune(-v),
+            (UnaryOperator::Minus, ConstValue::Float(v)) => ConstValue::Float(-v),
+            (UnaryOperator::Not, ConstValue::Bool(v)) => ConstValue::Bool(!v),
+            (UnaryOperator::Xor, ConstValue::Int(v))
+            | (UnaryOperator::Xor, ConstValue::Rune(v)) => {
+                // The mask is all 1s for unsigned and -1 for signed and untyped constants.
+                let v = match self.r#type.as_ref().and_then(unsigned_type_bits) {
+                    Some(bits) => !v & ((BigInt::one() << bits) - 1),
+                    None => !v,
+                };
+                match self.value {
+                    ConstValue::Rune(_) => ConstValue::Rune(v),
+                    _ => ConstValue::Int(v),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        self.with_value(value)
+    }
+
+    fn binary(self, operator: BinaryOperator, other: Self) -> Result<Self, ConstEvalError> {
+        if let BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight = operator {
+            return self.shift(operator, other);
+        }
+
+        let (left, right) = self.unify(other)?;
+        let value = binary_values(&left.value, operator, &right.value)?;
+
+        match operator {
+            // Comparisons yield an untyped boolean value.
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::Less
+            | BinaryOperator::LessOrEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterOrEqual => Ok(Self::untyped(value)),
+            _ => left.with_value(value),
+        }
+    }
+
+    // https://golang.org/ref/spec#Arithmetic_operators
+    // The result has the type of the left operand.
+    fn shift(self, operator: BinaryOperator, other: Self) -> Result<Self, ConstEvalError> {
+        let invalid = || {
+            ConstEvalError::InvalidOperation(format!("{} {} {}", self.value, operator, other.value))
+        };
+
+        let n = match &other.value {
+            ConstValue::Int(n) | ConstValue::Rune(n) => n.to_owned(),
+            ConstValue::Float(n) if other.r#type.is_none() && n.is_integer() => n.to_integer(),
+            _ => return Err(invalid()),
+        };
+        let n = match n.to_u64() {
+            Some(n) => n,
+            None if n.is_negative() => return Err(invalid()),
+            None => {
+                return Err(ConstEvalError::Overflow(format!(
+                    "{} {} {}",
+                    self.value, operator, n
+                )))
+            }
+        };
+        let v = match &self.value {
+            ConstValue::Int(v) | ConstValue::Rune(v) => v.to_owned(),
+            ConstValue::Float(v) if self.r#type.is_none() && v.is_integer() => v.to_integer(),
+            _ => return Err(invalid()),
+        };
+
+        // Right shifts round towards negative infinity, as Go does.
+        let v = if operator == BinaryOperator::ShiftLeft {
+            if v.is_zero() {
+                v
+            } else if n > MAX_INT_BITS {
+                return Err(ConstEvalError::Overflow(format!("{} << {}", v, n)));
+            } else {
+                v << n
+            }
+        } else if n > v.bits() {
+            if v.is_negative() {
+                -BigInt::one()
+            } else {
+                BigInt::zero()
+            }
+        } else {
+            v >> n
+        };
+
+        let value = match self.value {
+            ConstValue::Rune(_) => ConstValue::Rune(v),
+            _ => ConstValue::Int(v),
+        };
+        self.with_value(value)
+    }
+
+    // Keeps the type, and checks the value is representable by it.
+    fn with_value(self, value: ConstValue) -> Result<Self, ConstEvalError> {
+        let constant = Self {
+            value,
+            r#type: None,
+        };
+        match &self.r#type {
+            Some(r#type) => constant.convert(r#type),
+            None => {
+                if let ConstValue::Int(v) | ConstValue::Rune(v) = &constant.value {
+                    if v.bits() > MAX_INT_BITS {
+                        return Err(ConstEvalError::Overflow(format!("{} bits", v.bits())));
+                    }
+                }
+                Ok(constant)
+            }
+        }
+    }
+}
+
+fn binary_values(
+    left: &ConstValue,
+    operator: BinaryOperator,
+    right: &ConstValue,
+) -> Result<ConstValue, ConstEvalError> {
+    use BinaryOperator::*;
+
+    let invalid = || ConstEvalError::InvalidOperation(format!("{} {} {}", left, operator, right));
+
+    let value = match (left, right) {
+        (ConstValue::Bool(l), ConstValue::Bool(r)) => match operator {
+            LogicalOr => ConstValue::Bool(*l || *r),
+            LogicalAnd => ConstValue::Bool(*l && *r),
+            Equal => ConstValue::Bool(l == r),
+            NotEqual => ConstValue::Bool(l != r),
+            _ => return Err(invalid()),
+        },
+        (ConstValue::String(l), ConstValue::String(r)) => match operator {
+            Add => ConstValue::String([l.as_slice(), r.as_slice()].concat()),
+            Equal => ConstValue::Bool(l == r),
+            NotEqual => ConstValue::Bool(l != r),
+            Less => ConstValue::Bool(l < r),
+            LessOrEqual => ConstValue::Bool(l <= r),
+            Greater => ConstValue::Bool(l > r),
+            GreaterOrEqual => ConstValue::Bool(l >= r),
+            _ => return Err(invalid()),
+        },
+        (ConstValue::Float(_), _) | (_, ConstValue::Float(_)) => {
+            let (l, r) = match (left.to_rational(), right.to_rational()) {
+                (Some(l), Some(r)) => (l, r),
+                _ => return Err(invalid()),
+            };
+            match operator {
+                Add => ConstValue::Float(l + r),
+                Sub => ConstValue::Float(l - r),
+                Mul => ConstValue::Float(l * r),
+                Div if r.is_zero() => return Err(ConstEvalError::DivisionByZero),
+                Div => ConstValue::Float(l / r),
+                Equal => ConstValue::Bool(l == r),
+                NotEqual => ConstValue::Bool(l != r),
+                Less => ConstValue::Bool(l < r),
+                LessOrEqual => ConstValue::Bool(l <= r),
+                Greater => ConstValue::Bool(l > r),
+                GreaterOrEqual => ConstValue::Bool(l >= r),
+                _ => return Err(invalid()),
+            }
+        }
+        (ConstValue::Int(l), ConstValue::Int(r))
+        | (ConstValue::Int(l), ConstValue::Rune(r))
+        | (ConstValue::Rune(l), ConstValue::Int(r))
+        | (ConstValue::Rune(l), ConstValue::Rune(r)) => {
+            let v = match operator {
+                Add => l + r,
+                Sub => l - r,
+                Mul => l * r,
+                // Integer division truncates towards zero, as BigInt does.
+                Div | Rem if r.is_zero() => return Err(ConstEvalError::DivisionByZero),
+                Div => l / r,
+                Rem => l % r,
+                Or => l | r,
+                Xor => l ^ r,
+                And => l & r,
+                AndNot => l & !r,
+                Equal => return Ok(ConstValue::Bool(l == r)),
+                NotEqual => return Ok(ConstValue::Bool(l != r)),
+                Less => return Ok(ConstValue::Bool(l < r)),
+                LessOrEqual => return Ok(ConstValue::Bool(l <= r)),
+                Greater => return Ok(ConstValue::Bool(l > r)),
+                GreaterOrEqual => return Ok(ConstValue::Bool(l >= r)),
+                _ => return Err(invalid()),
+            };
+            // An operation involving an untyped rune yields an untyped rune.
+            match (left, right) {
+                (ConstValue::Rune(_), _) | (_, ConstValue::Rune(_)) => ConstValue::Rune(v),
+                _ => ConstValue::Int(v),
+            }
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok(value)
+}
+
+// https://golang.org/ref/spec#Numeric_types
+// `int`, `uint` and `uintptr` are assumed to be 64 bits.
+fn int_type_range(type_name: &TypeName) -> Option<(i128, i128)> {
+    let range = match type_name {
+        TypeName::Int8 => (i8::MIN as i128, i8::MAX as i128),
+        TypeName::Int16 => (i16::MIN as i128, i16::MAX as i128),
+        TypeName::Int32 | TypeName::Rune => (i32::MIN as i128, i32::MAX as i128),
+        TypeName::Int64 | TypeName::Int => (i64::MIN as i128, i64::MAX as i128),
+        TypeName::Uint8 | TypeName::Byte => (0, u8::MAX as i128),
+        TypeName::Uint16 => (0, u16::MAX as i128),
+        TypeName::Uint32 => (0, u32::MAX as i128),
+        TypeName::Uint64 | TypeName::Uint | TypeName::Uintptr => (0, u64::MAX as i128),
+        _ => return None,
+    };
+    Some(range)
+}
+
+fn unsigned_type_bits(r#type: &Type) -> Option<usize> {
+    match r#type {
+        Type::TypeName(TypeName::Uint8) | Type::TypeName(TypeName::Byte) => Some(8),
+        Type::TypeName(TypeName::Uint16) => Some(16),
+        Type::TypeName(TypeName::Uint32) => Some(32),
+        Type::TypeName(TypeName::Uint64)
+        | Type::TypeName(TypeName::Uint)
+        | Type::TypeName(TypeName::Uintptr) => Some(64),
+        _ => None,
+    }
+}
+
+impl ConstValue {
+    pub fn from_basic_lit(basic_lit: &BasicLit) -> Result<Self, ConstEvalError> {
+        match basic_lit {
+            BasicLit::Int(s) => parse_int_lit(s).map(Self::Int),
+            BasicLit::Float(s) => parse_float_lit(s).map(Self::Float),
+            BasicLit::Imaginary(s) => Err(ConstEvalError::Unsupported(s.to_owned())),
+            BasicLit::Rune(s) => parse_rune_lit(s).map(Self::Rune),
+            BasicLit::String(s) => parse_string_lit(s).map(Self::String),
+        }
+    }
+
+    // https://golang.org/ref/spec#Constants
+    pub fn default_type(&self) -> TypeName {
+        match self {
+            Self::Bool(_) => TypeName::Bool,
+            Self::Rune(_) => TypeName::Rune,
+            Self::Int(_) => TypeName::Int,
+            Self::Float(_) => TypeName::Float64,
+            Self::String(_) => TypeName::String,
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        self.to_int().and_then(|v| v.to_i64())
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_int().and_then(|v| v.to_u64())
+    }
+
+    pub fn to_usize(&self) -> Option<usize> {
+        self.to_int().and_then(|v| v.to_usize())
+    }
+
+    // The nearest float.
+    pub fn to_f64(&self) -> Option<f64> {
+        self.to_rational()
+            .and_then(|v| v.to_f64())
+            .filter(|v| v.is_finite())
+    }
+
+    // Integers, and floats without fractional part, e.g. `1e3`.
+    pub fn to_int(&self) -> Option<BigInt> {
+        match self {
+            Self::Int(v) | Self::Rune(v) => Some(v.to_owned()),
+            Self::Float(v) if v.is_integer() => Some(v.to_integer()),
+            _ => None,
+        }
+    }
+
+    pub fn to_rational(&self) -> Option<BigRational> {
+        match self {
+            Self::Int(v) | Self::Rune(v) => Some(BigRational::from_integer(v.to_owned())),
+            Self::Float(v) => Some(v.to_owned()),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    // None if not UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_bytes().and_then(|v| str::from_utf8(v).ok())
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+// https://golang.org/ref/spec#Integer_literals
+fn parse_int_lit(s: &str) -> Result<BigInt, ConstEvalError> {
+    let digits = s.replace('_', "");
+    let (digits, radix) = match digits.get(..2) {
+        Some("0x") | Some("0X") => (&digits[2..], 16),
+        Some("0b") | Some("0B") => (&digits[2..], 2),
+        Some("0o") | Some("0O") => (&digits[2..], 8),
+        _ if digits.len() > 1 && digits.starts_with('0') => (&digits[1..], 8),
+        _ => (&digits[..], 10),
+    };
+    BigInt::parse_bytes(digits.as_bytes(), radix)
+        .ok_or_else(|| ConstEvalError::LiteralInvalid(s.to_owned()))
+}
+
+// https://golang.org/ref/spec#Floating-point_literals
+// The mantissa digits scaled by a power of 10, or of 2 for hexadecimal literals.
+fn parse_float_lit(s: &str) -> Result<BigRational, ConstEvalError> {
+    let invalid = || ConstEvalError::LiteralInvalid(s.to_owned());
+
+    let digits = s.replace('_', "");
+    let (mantissa, exponent, radix, base, digit_bits) = match digits.get(..2) {
+        Some("0x") | Some("0X") => {
+            let (mantissa, exponent) = digits[2..].split_once(['p', 'P']).ok_or_else(invalid)?;
+            (mantissa, exponent, 16, 2_u32, 4)
+        }
+        _ => {
+            let (mantissa, exponent) = digits.split_once(['e', 'E']).unwrap_or((&digits, "0"));
+            (mantissa, exponent, 10, 10_u32, 1)
+        }
+    };
+    let exponent: i64 = exponent.parse().map_err(|_| invalid())?;
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let mantissa_digits = format!("{}{}", int_part, frac_part);
+    let mantissa = if mantissa_digits.is_empty() {
+        return Err(invalid());
+    } else {
+        BigInt::parse_bytes(mantissa_digits.as_bytes(), radix).ok_or_else(invalid)?
+    };
+    let exponent = exponent - digit_bits * frac_part.len() as i64;
+    if exponent.unsigned_abs() > MAX_INT_BITS * 4 {
+        return Err(ConstEvalError::Overflow(s.to_owned()));
+    }
+
+    let scale = num_traits::pow(BigInt::from(base), exponent.unsigned_abs() as usize);
+    if exponent >= 0 {
+        Ok(BigRational::from_integer(mantissa * scale))
+    } else {
+        Ok(BigRational::new(mantissa, scale))
+    }
+}
+
+// https://golang.org/ref/spec#Rune_literals
+fn parse_rune_lit(s: &str) -> Result<BigInt, ConstEvalError> {
+    let invalid = || ConstEvalError::LiteralInvalid(s.to_owned());
+
+    let inner = s
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .ok_or_else(invalid)?;
+    match unescape(inner).map_err(|_| invalid())?.as_slice() {
+        [Unescaped::Byte(b)] => Ok(BigInt::from(*b)),
+        [Unescaped::Char(c)] => Ok(BigInt::from(*c as u32)),
+        _ => Err(invalid()),
+    }
+}
+
+// https://golang.org/ref/spec#String_literals
+fn parse_string_lit(s: &str) -> Result<Vec<u8>, ConstEvalError> {
+    let invalid = || ConstEvalError::LiteralInvalid(s.to_owned());
+
+    if let Some(inner) = s.strip_prefix('`').and_then(|s| s.strip_suffix('`')) {
+        // Carriage returns are discarded from raw strings.
+        return Ok(inner.replace('\r', "").into_bytes());
+    }
+
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(invalid)?;
+    let mut bytes = vec![];
+    for unescaped in unescape(inner).map_err(|_| invalid())? {
+        match unescaped {
+            Unescaped::Byte(b) => bytes.push(b),
+            Unescaped::Char(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+    Ok(bytes)
+}
+
+enum Unescaped {
+    Byte(u8),
+    Char(char),
+}
+
+fn unescape(s: &str) -> Result<Vec<Unescaped>, ()> {
+    let mut chars = s.chars();
+    let mut unescaped = vec![];
+
+    let take_digits = |chars: &mut std::str::Chars, n: usize, radix: u32| -> Result<u32, ()> {
+        let mut v = 0_u32;
+        for _ in 0..n {
+            v = v * radix + chars.next().and_then(|c| c.to_digit(radix)).ok_or(())?;
+        }
+        Ok(v)
+    };
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(Unescaped::Char(c));
+            continue;
+        }
+        let c = match chars.next().ok_or(())? {
+            'a' => '\x07',
+            'b' => '\x08',
+            'f' => '\x0c',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\x0b',
+            '\\' => '\\',
+            '\'' => '\'',
+            '"' => '"',
+            'x' => {
+                unescaped.push(Unescaped::Byte(take_digits(&mut chars, 2, 16)? as u8));
+                continue;
+            }
+            'u' => char::from_u32(take_digits(&mut chars, 4, 16)?).ok_or(())?,
+            'U' => char::from_u32(take_digits(&mut chars, 8, 16)?).ok_or(())?,
+            c @ '0'..='7' => {
+                let v = c.to_digit(8).unwrap() * 64 + take_digits(&mut chars, 2, 8)?;
+                unescaped.push(Unescaped::Byte(u8::try_from(v).map_err(|_| ())?));
+                continue;
+            }
+            _ => return Err(()),
+        };
+        unescaped.push(Unescaped::Char(c));
+    }
+
+    Ok(unescaped)
+}
+
+impl fmt::Display for ConstValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(v) => write!(f, "{}", v),
+            Self::Rune(v) | Self::Int(v) => write!(f, "{}", v),
+            // Exact if it is an integer, else the nearest float.
+            Self::Float(v) if v.is_integer() => write!(f, "{}", v.to_integer()),
+            Self::Float(v) => match v.to_f64().filter(|v| v.is_finite()) {
+                Some(v) => write!(f, "{}", v),
+                None => write!(f, "{}", v),
+            },
+            Self::String(v) => {
+                write!(f, "\"")?;
+                // The bytes not of UTF-8 are escaped, e.g. `\xff`.
+                let mut rest = v.as_slice();
+                loop {
+                    let (valid, invalid) = match str::from_utf8(rest) {
+                        Ok(valid) => (valid, &[][..]),
+                        Err(err) => {
+                            let (valid, invalid) = rest.split_at(err.valid_up_to());
+                            let len = err.error_len().unwrap_or(invalid.len());
+                            rest = &invalid[len..];
+                            (str::from_utf8(valid).unwrap_or_default(), &invalid[..len])
+                        }
+                    };
+                    for c in valid.chars() {
+                        match c {
+                            '"' => write!(f, "\\\"")?,
+                            '\\' => write!(f, "\\\\")?,
+                            '\n' => write!(f, "\\n")?,
+                            '\r' => write!(f, "\\r")?,
+                            '\t' => write!(f, "\\t")?,
+                            c if c.is_control() && (c as u32) < 0x80 => {
+                                write!(f, "\\x{:02x}", c as u32)?
+                            }
+                            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                            c => write!(f, "{}", c)?,
+                        }
+                    }
+                    if invalid.is_empty() {
+                        break;
+                    }
+                    for b in invalid {
+                        write!(f, "\\x{:02x}", b)?;
+                    }
+                }
+                write!(f, "\"")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.r#type {
+            Some(r#type) => write!(f, "{}({})", r#type, self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
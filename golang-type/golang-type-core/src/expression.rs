@@ -0,0 +1,513 @@
+use std::{
+    fmt,
+    str::{self, FromStr},
+};
+
+use golang_parser::{tree_sitter::Node, Parser, Span, NODE_KIND_COMMENT};
+
+use crate::{ArrayLength, ArrayType, Type, TypeParseError};
+
+// https://golang.org/ref/spec#Expressions
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Expression {
+    // Including the predeclared `true`, `false`, `nil` and `iota`.
+    Identifier(String),
+    BasicLit(BasicLit),
+    // e.g. `time.Second`
+    Selector(Box<Expression>, String),
+    // Function calls, and conversions whose type is an identifier, e.g. `Kind(1)`.
+    Call(CallExpression),
+    // e.g. `[]byte("a")`
+    Conversion(Type, Box<Expression>),
+    Unary(UnaryOperator, Box<Expression>),
+    Binary(BinaryExpression),
+    Parenthesized(Box<Expression>),
+    // e.g. `Config{Port: 8080}`
+    CompositeLit(CompositeLit),
+}
+
+// https://golang.org/ref/spec#BasicLit
+// The source text, e.g. `0x_FF`, `'\n'`, "`raw`".
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum BasicLit {
+    Int(String),
+    Float(String),
+    Imaginary(String),
+    Rune(String),
+    String(String),
+}
+
+// https://golang.org/ref/spec#Composite_literals
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CompositeLit {
+    // None if elided, e.g. the elements of `[]Point{{1, 2}}`.
+    // The length of `[...]T` is `ArrayLength::Implicit`.
+    pub r#type: Option<Type>,
+    pub elements: Vec<KeyedElement>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct KeyedElement {
+    // A field name, map key or array index.
+    pub key: Option<Expression>,
+    pub value: Expression,
+}
+
+// https://golang.org/ref/spec#Calls
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CallExpression {
+    pub function: Box<Expression>,
+    pub arguments: Vec<Expression>,
+    // `f(a, b...)`
+    pub is_variadic: bool,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BinaryExpression {
+    pub left: Box<Expression>,
+    pub operator: BinaryOperator,
+    pub right: Box<Expression>,
+}
+
+// https://golang.org/ref/spec#Operators
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum UnaryOperator {
+    Plus,
+    Minus,
+    Not,
+    Xor,
+    Deref,
+    Address,
+    Receive,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum BinaryOperator {
+    LogicalOr,
+    LogicalAnd,
+    //
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    //
+    Add,
+    Sub,
+    Or,
+    Xor,
+    //
+    Mul,
+    Div,
+    Rem,
+    ShiftLeft,
+    ShiftRight,
+    And,
+    AndNot,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExpressionParseError {
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(str::Utf8Error),
+    #[error("OperatorUnknown {0} at {1}")]
+    OperatorUnknown(String, Span),
+}
+
+impl FromStr for Expression {
+    type Err = TypeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new_strict(format!("var _ = {}", s))?;
+        let source = parser.get_source();
+        let root_node = parser.get_root_node();
+
+        let node_var_spec = root_node
+            .named_child(0)
+            .filter(|node| node.kind() == "var_declaration")
+            .and_then(|node| node.named_child(0))
+            .ok_or_else(|| {
+                TypeParseError::NodeMissing("var_spec".to_string(), Span::from(root_node))
+            })?;
+        let node_expression_list = node_var_spec.child_by_field_name("value").ok_or_else(|| {
+            TypeParseError::NodeMissing("var_spec value".to_string(), Span::from(node_var_spec))
+        })?;
+
+        let mut cursor = node_expression_list.walk();
+        let mut node_expressions = node_expression_list
+            .named_children(&mut cursor)
+            .filter(|x| x.kind() != NODE_KIND_COMMENT);
+        let node_expression = node_expressions.next().ok_or_else(|| {
+            TypeParseError::NodeMissing("expression".to_string(), Span::from(node_expression_list))
+        })?;
+        if let Some(node) = node_expressions.next() {
+            return Err(TypeParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        Self::from_node(node_expression, source)
+    }
+}
+
+impl Expression {
+    pub fn from_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
+        let text = || {
+            node.utf8_text(source)
+                .map(ToOwned::to_owned)
+                .map_err(ExpressionParseError::Utf8Error)
+        };
+        let field = |name: &str| {
+            node.child_by_field_name(name).ok_or_else(|| {
+                ExpressionParseError::NodeMissing(
+                    format!("{} {}", node.kind(), name),
+                    Span::from(node),
+                )
+            })
+        };
+
+        let expression = match node.kind() {
+            "identifier" | "true" | "false" | "nil" | "iota" => Self::Identifier(text()?),
+            //
+            "int_literal" => Self::BasicLit(BasicLit::Int(text()?)),
+            "float_literal" => Self::BasicLit(BasicLit::Float(text()?)),
+            "imaginary_literal" => Self::BasicLit(BasicLit::Imaginary(text()?)),
+            "rune_literal" => Self::BasicLit(BasicLit::Rune(text()?)),
+            "interpreted_string_literal" | "raw_string_literal" => {
+                Self::BasicLit(BasicLit::String(text()?))
+            }
+            //
+            "selector_expression" => {
+                let operand = Self::from_node(field("operand")?, source)?;
+                let name = field("field")?
+                    .utf8_text(source)
+                    .map_err(ExpressionParseError::Utf8Error)?;
+                Self::Selector(operand.into(), name.to_owned())
+            }
+            "call_expression" => {
+                let function = Self::from_node(field("function")?, source)?;
+
+                let node_arguments = field("arguments")?;
+                let mut arguments = vec![];
+                let mut is_variadic = false;
+                for node_argument in node_arguments
+                    .named_children(&mut node_arguments.walk())
+                    .filter(|x| x.kind() != NODE_KIND_COMMENT)
+                {
+                    if node_argument.kind() == "variadic_argument" {
+                        is_variadic = true;
+                        let node_argument = node_argument.named_child(0).ok_or_else(|| {
+                            ExpressionParseError::NodeMissing(
+                                "variadic_argument expression".to_string(),
+                                Span::from(node_argument),
+                            )
+                        })?;
+                        arguments.push(Self::from_node(node_argument, source)?);
+                    } else {
+                        arguments.push(Self::from_node(node_argument, source)?);
+                    }
+                }
+
+                Self::Call(CallExpression {
+                    function: function.into(),
+                    arguments,
+                    is_variadic,
+                })
+            }
+            "type_conversion_expression" => {
+                let r#type = Type::from_node(field("type")?, source)?;
+                let operand = Self::from_node(field("operand")?, source)?;
+                Self::Conversion(r#type, operand.into())
+            }
+            "unary_expression" => {
+                let node_operator = field("operator")?;
+                let operator = match node_operator.kind() {
+                    "+" => UnaryOperator::Plus,
+                    "-" => UnaryOperator::Minus,
+                    "!" => UnaryOperator::Not,
+                    "^" => UnaryOperator::Xor,
+                    "*" => UnaryOperator::Deref,
+                    "&" => UnaryOperator::Address,
+                    "<-" => UnaryOperator::Receive,
+                    s => {
+                        return Err(ExpressionParseError::OperatorUnknown(
+                            s.to_owned(),
+                            Span::from(node_operator),
+                        )
+                        .into())
+                    }
+                };
+                let operand = Self::from_node(field("operand")?, source)?;
+                Self::Unary(operator, operand.into())
+            }
+            "binary_expression" => {
+                let node_operator = field("operator")?;
+                let operator = match node_operator.kind() {
+                    "||" => BinaryOperator::LogicalOr,
+                    "&&" => BinaryOperator::LogicalAnd,
+                    "==" => BinaryOperator::Equal,
+                    "!=" => BinaryOperator::NotEqual,
+                    "<" => BinaryOperator::Less,
+                    "<=" => BinaryOperator::LessOrEqual,
+                    ">" => BinaryOperator::Greater,
+                    ">=" => BinaryOperator::GreaterOrEqual,
+                    "+" => BinaryOperator::Add,
+                    "-" => BinaryOperator::Sub,
+                    "|" => BinaryOperator::Or,
+                    "^" => BinaryOperator::Xor,
+                    "*" => BinaryOperator::Mul,
+                    "/" => BinaryOperator::Div,
+                    "%" => BinaryOperator::Rem,
+                    "<<" => BinaryOperator::ShiftLeft,
+                    ">>" => BinaryOperator::ShiftRight,
+                    "&" => BinaryOperator::And,
+                    "&^" => BinaryOperator::AndNot,
+                    s => {
+                        return Err(ExpressionParseError::OperatorUnknown(
+                            s.to_owned(),
+                            Span::from(node_operator),
+                        )
+                        .into())
+                    }
+                };
+                let left = Self::from_node(field("left")?, source)?;
+                let right = Self::from_node(field("right")?, source)?;
+                Self::Binary(BinaryExpression {
+                    left: left.into(),
+                    operator,
+                    right: right.into(),
+                })
+            }
+            "parenthesized_expression" => {
+                let node_element = node
+                    .named_children(&mut node.walk())
+                    .find(|x| x.kind() != NODE_KIND_COMMENT)
+                    .ok_or_else(|| {
+                        ExpressionParseError::NodeMissing(
+                            "parenthesized_expression element".to_string(),
+                            Span::from(node),
+                        )
+                    })?;
+                Self::Parenthesized(Self::from_node(node_element, source)?.into())
+            }
+            "composite_literal" => {
+                let node_type = field("type")?;
+                let r#type = if node_type.kind() == "implicit_length_array_type" {
+                    let node_element =
+                        node_type.child_by_field_name("element").ok_or_else(|| {
+                            ExpressionParseError::NodeMissing(
+                                "implicit_length_array_type element".to_string(),
+                                Span::from(node_type),
+                            )
+                        })?;
+                    Type::ArrayType(ArrayType {
+                        length: ArrayLength::Implicit,
+                        element: Type::from_node(node_element, source)?.into(),
+                    })
+                } else {
+                    Type::from_node(node_type, source)?
+                };
+                let elements =
+                    CompositeLit::elements_from_literal_value_node(field("body")?, source)?;
+                Self::CompositeLit(CompositeLit {
+                    r#type: Some(r#type),
+                    elements,
+                })
+            }
+            "literal_value" => Self::CompositeLit(CompositeLit {
+                r#type: None,
+                elements: CompositeLit::elements_from_literal_value_node(node, source)?,
+            }),
+            _ => {
+                return Err(ExpressionParseError::NodeKindUnknown(
+                    node.kind().to_owned(),
+                    Span::from(node),
+                )
+                .into())
+            }
+        };
+
+        Ok(expression)
+    }
+}
+
+impl CompositeLit {
+    fn elements_from_literal_value_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Vec<KeyedElement>, TypeParseError> {
+        // literal_element wraps an expression or an elided literal_value.
+        let from_literal_element_node = |node: Node| -> Result<Expression, TypeParseError> {
+            let node_element = node
+                .named_children(&mut node.walk())
+                .find(|x| x.kind() != NODE_KIND_COMMENT)
+                .ok_or_else(|| {
+                    ExpressionParseError::NodeMissing(
+                        "literal_element expression".to_string(),
+                        Span::from(node),
+                    )
+                })?;
+            Expression::from_node(node_element, source)
+        };
+
+        let mut elements = vec![];
+        for node_element in node
+            .named_children(&mut node.walk())
+            .filter(|x| x.kind() != NODE_KIND_COMMENT)
+        {
+            match node_element.kind() {
+                "literal_element" => elements.push(KeyedElement {
+                    key: None,
+                    value: from_literal_element_node(node_element)?,
+                }),
+                "keyed_element" => {
+                    let mut cursor = node_element.walk();
+                    let mut node_literal_elements = node_element
+                        .named_children(&mut cursor)
+                        .filter(|x| x.kind() == "literal_element");
+                    let (node_key, node_value) =
+                        match (node_literal_elements.next(), node_literal_elements.next()) {
+                            (Some(node_key), Some(node_value)) => (node_key, node_value),
+                            _ => {
+                                return Err(ExpressionParseError::NodeMissing(
+                                    "keyed_element key or value".to_string(),
+                                    Span::from(node_element),
+                                )
+                                .into())
+                            }
+                        };
+                    elements.push(KeyedElement {
+                        key: Some(from_literal_element_node(node_key)?),
+                        value: from_literal_element_node(node_value)?,
+                    });
+                }
+                _ => {
+                    return Err(ExpressionParseError::NodeKindUnknown(
+                        node_element.kind().to_owned(),
+                        Span::from(node_element),
+                    )
+                    .into())
+                }
+            }
+        }
+
+        Ok(elements)
+    }
+}
+
+// Printed with a space around every binary operator.
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(name) => write!(f, "{}", name),
+            Self::BasicLit(basic_lit) => write!(f, "{}", basic_lit),
+            Self::Selector(operand, name) => write!(f, "{}.{}", operand, name),
+            Self::Call(call_expression) => write!(f, "{}", call_expression),
+            Self::Conversion(r#type, operand) => write!(f, "{}({})", r#type, operand),
+            Self::Unary(operator, operand) => write!(f, "{}{}", operator, operand),
+            Self::Binary(binary_expression) => write!(f, "{}", binary_expression),
+            Self::Parenthesized(element) => write!(f, "({})", element),
+            Self::CompositeLit(composite_lit) => write!(f, "{}", composite_lit),
+        }
+    }
+}
+
+impl fmt::Display for CompositeLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(r#type) = &self.r#type {
+            write!(f, "{}", r#type)?;
+        }
+        let elements: Vec<_> = self.elements.iter().map(ToString::to_string).collect();
+        write!(f, "{{{}}}", elements.join(", "))
+    }
+}
+
+impl fmt::Display for KeyedElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.key {
+            Some(key) => write!(f, "{}: {}", key, self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for BasicLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(s)
+            | Self::Float(s)
+            | Self::Imaginary(s)
+            | Self::Rune(s)
+            | Self::String(s) => {
+                write!(f, "{}", s)
+            }
+        }
+    }
+}
+
+impl fmt::Display for CallExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arguments: Vec<_> = self.arguments.iter().map(ToString::to_string).collect();
+        write!(
+            f,
+            "{}({}{})",
+            self.function,
+            arguments.join(", "),
+            if self.is_variadic { "..." } else { "" }
+        )
+    }
+}
+
+impl fmt::Display for BinaryExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.left, self.operator, self.right)
+    }
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Not => "!",
+            Self::Xor => "^",
+            Self::Deref => "*",
+            Self::Address => "&",
+            Self::Receive => "<-",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::LogicalOr => "||",
+            Self::LogicalAnd => "&&",
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::Less => "<",
+            Self::LessOrEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterOrEqual => ">=",
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Or => "|",
+            Self::Xor => "^",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+            Self::ShiftLeft => "<<",
+            Self::ShiftRight => ">>",
+            Self::And => "&",
+            Self::AndNot => "&^",
+        };
+        write!(f, "{}", s)
+    }
+}
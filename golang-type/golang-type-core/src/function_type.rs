@@ -1,18 +1,166 @@
-use golang_parser::tree_sitter::Node;
+use std::{fmt, str};
 
-use crate::TypeParseError;
+use golang_parser::{tree_sitter::Node, Span, NODE_KIND_COMMENT};
+
+use crate::{Type, TypeParseError};
+
+// https://golang.org/ref/spec#Function_types
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FunctionType {
+    pub parameters: Vec<ParameterDecl>,
+    pub result: Option<FunctionResult>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParameterDecl {
+    pub names: Vec<String>,
+    pub r#type: Box<Type>,
+    pub is_variadic: bool,
+}
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub struct FunctionType {}
+pub enum FunctionResult {
+    Parameters(Vec<ParameterDecl>),
+    Type(Box<Type>),
+}
 
 #[derive(thiserror::Error, Debug)]
-pub enum FunctionTypeParseError {}
+pub enum FunctionTypeParseError {
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(str::Utf8Error),
+}
 impl FunctionType {
     pub(crate) fn from_function_type_node(
-        _node: Node,
-        _source: &[u8],
+        node: Node,
+        source: &[u8],
     ) -> Result<Self, TypeParseError> {
-        // TODO
-        Ok(Self {})
+        Self::from_signature_node(node, source)
+    }
+
+    // function_type, method_spec, function_declaration and method_declaration have the same `parameters` and `result` fields.
+    pub fn from_signature_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
+        let node_parameters = node.child_by_field_name("parameters").ok_or_else(|| {
+            FunctionTypeParseError::NodeMissing("parameters".to_string(), Span::from(node))
+        })?;
+
+        let parameters = ParameterDecl::from_parameter_list_node(node_parameters, source)?;
+
+        let result = if let Some(node_result) = node.child_by_field_name("result") {
+            match node_result.kind() {
+                "parameter_list" => Some(FunctionResult::Parameters(
+                    ParameterDecl::from_parameter_list_node(node_result, source)?,
+                )),
+                _ => Some(FunctionResult::Type(
+                    Type::from_node(node_result, source)?.into(),
+                )),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self { parameters, result })
+    }
+}
+
+impl ParameterDecl {
+    pub fn from_parameter_list_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Vec<Self>, TypeParseError> {
+        let mut tree_cursor = node.walk();
+
+        let mut parameter_decls = vec![];
+
+        for node_parameter_declaration in node.named_children(&mut tree_cursor) {
+            let is_variadic = match node_parameter_declaration.kind() {
+                "parameter_declaration" => false,
+                "variadic_parameter_declaration" => true,
+                NODE_KIND_COMMENT => continue,
+                _ => {
+                    return Err(FunctionTypeParseError::NodeKindUnknown(
+                        node_parameter_declaration.kind().to_owned(),
+                        Span::from(node_parameter_declaration),
+                    )
+                    .into())
+                }
+            };
+
+            let node_parameter_declaration_type = node_parameter_declaration
+                .child_by_field_name("type")
+                .ok_or_else(|| {
+                    FunctionTypeParseError::NodeMissing(
+                        "parameter_declaration type".to_string(),
+                        Span::from(node_parameter_declaration),
+                    )
+                })?;
+
+            let mut names = vec![];
+            for node_parameter_declaration_name in node_parameter_declaration
+                .named_children(&mut node_parameter_declaration.walk())
+                .filter(|node| node.kind() == "identifier")
+            {
+                let name = node_parameter_declaration_name
+                    .utf8_text(source)
+                    .map_err(FunctionTypeParseError::Utf8Error)?;
+                names.push(name.to_owned());
+            }
+
+            let r#type = Type::from_node(node_parameter_declaration_type, source)?;
+
+            parameter_decls.push(Self {
+                names,
+                r#type: r#type.into(),
+                is_variadic,
+            });
+        }
+
+        Ok(parameter_decls)
+    }
+}
+
+impl fmt::Display for FunctionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "func{}", self.signature())
+    }
+}
+
+impl FunctionType {
+    // e.g. `(a, b int) (int, error)`
+    pub fn signature(&self) -> String {
+        let mut s = format!("({})", ParameterDecl::list(&self.parameters));
+        match &self.result {
+            Some(FunctionResult::Parameters(parameters)) => {
+                s.push_str(&format!(" ({})", ParameterDecl::list(parameters)))
+            }
+            Some(FunctionResult::Type(r#type)) => s.push_str(&format!(" {}", r#type)),
+            None => {}
+        }
+        s
+    }
+}
+
+impl fmt::Display for ParameterDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.names.is_empty() {
+            write!(f, "{} ", self.names.join(", "))?;
+        }
+        if self.is_variadic {
+            write!(f, "...")?;
+        }
+        write!(f, "{}", self.r#type)
+    }
+}
+
+impl ParameterDecl {
+    fn list(parameter_decls: &[Self]) -> String {
+        parameter_decls
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 }
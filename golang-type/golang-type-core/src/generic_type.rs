@@ -0,0 +1,103 @@
+use std::fmt;
+
+use golang_parser::{tree_sitter::Node, Span, NODE_KIND_COMMENT};
+
+use crate::{Type, TypeName, TypeParseError};
+
+// https://go.dev/ref/spec#Instantiations
+// e.g. `List[int]`, `pkg.Pair[K, V]`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct GenericType {
+    pub type_name: TypeName,
+    pub type_args: Vec<Type>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GenericTypeParseError {
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+}
+impl GenericType {
+    pub(crate) fn from_generic_type_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Self, TypeParseError> {
+        let node_generic_type_type = node.child_by_field_name("type").ok_or_else(|| {
+            GenericTypeParseError::NodeMissing("generic_type type".to_string(), Span::from(node))
+        })?;
+        let node_generic_type_type_arguments =
+            node.child_by_field_name("type_arguments").ok_or_else(|| {
+                GenericTypeParseError::NodeMissing(
+                    "generic_type type_arguments".to_string(),
+                    Span::from(node),
+                )
+            })?;
+
+        let type_name = match node_generic_type_type.kind() {
+            "qualified_type" => TypeName::from_qualified_type_node(node_generic_type_type, source)?,
+            "type_identifier" => {
+                TypeName::from_type_identifier_node(node_generic_type_type, source)?
+            }
+            _ => {
+                return Err(GenericTypeParseError::NodeKindUnknown(
+                    node_generic_type_type.kind().to_owned(),
+                    Span::from(node_generic_type_type),
+                )
+                .into())
+            }
+        };
+
+        let mut tree_cursor = node_generic_type_type_arguments.walk();
+        let type_args = node_generic_type_type_arguments
+            .named_children(&mut tree_cursor)
+            .filter(|node| node.kind() != NODE_KIND_COMMENT)
+            .map(|node| Type::from_node(node, source))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            type_name,
+            type_args,
+        })
+    }
+}
+
+impl fmt::Display for GenericType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_args: Vec<_> = self.type_args.iter().map(ToString::to_string).collect();
+        write!(f, "{}[{}]", self.type_name, type_args.join(", "))
+    }
+}
+
+#[cfg(feature = "enable-quote-to_tokens")]
+mod enable_quote_to_tokens {
+    use super::GenericType;
+    use crate::TypeMappings;
+
+    use proc_macro2::{Punct, Spacing, TokenStream};
+    use quote::{quote, ToTokens, TokenStreamExt as _};
+
+    impl GenericType {
+        pub(crate) fn to_token_stream_with(&self, type_mappings: &TypeMappings) -> TokenStream {
+            let mut tokens = TokenStream::new();
+            let type_name = &self.type_name;
+            tokens.append_all(quote!(#type_name));
+            tokens.append(Punct::new('<', Spacing::Alone));
+            let type_args: Vec<_> = self
+                .type_args
+                .iter()
+                .map(|type_arg| type_arg.to_token_stream_with(type_mappings))
+                .collect();
+            tokens.append_all(quote!(#(#type_args),*));
+            tokens.append(Punct::new('>', Spacing::Alone));
+            tokens
+        }
+    }
+
+    impl ToTokens for GenericType {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.to_token_stream_with(&TypeMappings::default()));
+        }
+    }
+}
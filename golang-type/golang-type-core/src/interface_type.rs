@@ -1,18 +1,250 @@
-use golang_parser::tree_sitter::Node;
+use std::{fmt, str};
 
-use crate::TypeParseError;
+use golang_parser::{tree_sitter::Node, Span, NODE_KIND_COMMENT};
+
+use crate::{printer, FunctionType, PointerType, StructType, Type, TypeName, TypeParseError};
+
+// https://golang.org/ref/spec#Interface_types
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct InterfaceType {
+    pub method_specs: Vec<MethodSpec>,
+    pub embedded_interfaces: Vec<TypeName>,
+    pub type_elems: Vec<TypeElem>,
+}
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub struct InterfaceType {}
+pub struct MethodSpec {
+    pub name: String,
+    pub signature: FunctionType,
+    pub span: Span,
+}
+
+// https://go.dev/ref/spec#General_interfaces
+// e.g. `~int | ~string`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TypeElem(pub Vec<TypeTerm>);
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TypeTerm {
+    pub r#type: Box<Type>,
+    // ~T
+    pub is_underlying: bool,
+}
 
 #[derive(thiserror::Error, Debug)]
-pub enum InterfaceTypeParseError {}
+pub enum InterfaceTypeParseError {
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(str::Utf8Error),
+}
+
 impl InterfaceType {
     pub(crate) fn from_interface_type_node(
-        _node: Node,
-        _source: &[u8],
+        node: Node,
+        source: &[u8],
     ) -> Result<Self, TypeParseError> {
-        // TODO
-        Ok(Self {})
+        let mut tree_cursor = node.walk();
+
+        let mut method_specs = vec![];
+        let mut embedded_interfaces = vec![];
+        let mut type_elems = vec![];
+
+        for node_interface_elem in node.named_children(&mut tree_cursor) {
+            match node_interface_elem.kind() {
+                "method_spec" => {
+                    let node_method_spec_name = node_interface_elem
+                        .child_by_field_name("name")
+                        .ok_or_else(|| {
+                            InterfaceTypeParseError::NodeMissing(
+                                "method_spec name".to_string(),
+                                Span::from(node_interface_elem),
+                            )
+                        })?;
+                    let name = node_method_spec_name
+                        .utf8_text(source)
+                        .map_err(InterfaceTypeParseError::Utf8Error)?;
+
+                    let signature = FunctionType::from_signature_node(node_interface_elem, source)?;
+
+                    method_specs.push(MethodSpec {
+                        name: name.to_owned(),
+                        signature,
+                        span: Span::from(node_interface_elem),
+                    });
+                }
+                "constraint_elem" => {
+                    let node_constraint_elem_type =
+                        node_interface_elem.named_child(0).ok_or_else(|| {
+                            InterfaceTypeParseError::NodeMissing(
+                                "constraint_elem type".to_string(),
+                                Span::from(node_interface_elem),
+                            )
+                        })?;
+
+                    let type_elem = TypeElem::from_node(node_constraint_elem_type, source)?;
+
+                    // A single TypeName is an embedded interface,
+                    // except the predeclared types which can only be constraints.
+                    match type_elem.0.as_slice() {
+                        [TypeTerm {
+                            r#type,
+                            is_underlying: false,
+                        }] => match r#type.as_ref() {
+                            Type::TypeName(
+                                type_name @ (TypeName::QualifiedIdent(_, _)
+                                | TypeName::Identifier(_)),
+                            ) => embedded_interfaces.push(type_name.to_owned()),
+                            _ => type_elems.push(type_elem),
+                        },
+                        _ => type_elems.push(type_elem),
+                    }
+                }
+                "struct_elem" => {
+                    let mut terms = vec![];
+                    for node_struct_term in node_interface_elem
+                        .named_children(&mut node_interface_elem.walk())
+                        .filter(|node| node.kind() == "struct_term")
+                    {
+                        let node_struct_type =
+                            node_struct_term.named_child(0).ok_or_else(|| {
+                                InterfaceTypeParseError::NodeMissing(
+                                    "struct_term type".to_string(),
+                                    Span::from(node_struct_term),
+                                )
+                            })?;
+                        let struct_type = Type::StructType(StructType::from_struct_type_node(
+                            node_struct_type,
+                            source,
+                        )?);
+
+                        let term = match node_struct_term.child(0).map(|node| node.kind()) {
+                            Some("~") => TypeTerm {
+                                r#type: struct_type.into(),
+                                is_underlying: true,
+                            },
+                            Some("*") => TypeTerm {
+                                r#type: Type::PointerType(PointerType(struct_type.into())).into(),
+                                is_underlying: false,
+                            },
+                            _ => TypeTerm {
+                                r#type: struct_type.into(),
+                                is_underlying: false,
+                            },
+                        };
+                        terms.push(term);
+                    }
+
+                    type_elems.push(TypeElem(terms));
+                }
+                NODE_KIND_COMMENT => continue,
+                _ => {
+                    return Err(InterfaceTypeParseError::NodeKindUnknown(
+                        node_interface_elem.kind().to_owned(),
+                        Span::from(node_interface_elem),
+                    )
+                    .into())
+                }
+            }
+        }
+
+        Ok(Self {
+            method_specs,
+            embedded_interfaces,
+            type_elems,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.method_specs.is_empty()
+            && self.embedded_interfaces.is_empty()
+            && self.type_elems.is_empty()
+    }
+}
+
+impl TypeElem {
+    pub(crate) fn from_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
+        let mut terms = vec![];
+        Self::push_terms(node, source, &mut terms)?;
+        Ok(Self(terms))
+    }
+
+    fn push_terms(
+        node: Node,
+        source: &[u8],
+        terms: &mut Vec<TypeTerm>,
+    ) -> Result<(), TypeParseError> {
+        match node.kind() {
+            "union_type" => {
+                for node_union_type_term in node
+                    .named_children(&mut node.walk())
+                    .filter(|node| node.kind() != NODE_KIND_COMMENT)
+                {
+                    Self::push_terms(node_union_type_term, source, terms)?;
+                }
+            }
+            "negated_type" => {
+                let node_negated_type_element = node.named_child(0).ok_or_else(|| {
+                    InterfaceTypeParseError::NodeMissing(
+                        "negated_type element".to_string(),
+                        Span::from(node),
+                    )
+                })?;
+
+                terms.push(TypeTerm {
+                    r#type: Type::from_node(node_negated_type_element, source)?.into(),
+                    is_underlying: true,
+                });
+            }
+            _ => terms.push(TypeTerm {
+                r#type: Type::from_node(node, source)?.into(),
+                is_underlying: false,
+            }),
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for InterfaceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "interface{{}}");
+        }
+
+        let rows: Vec<_> = self
+            .embedded_interfaces
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.type_elems.iter().map(ToString::to_string))
+            .chain(self.method_specs.iter().map(ToString::to_string))
+            .map(|row| vec![row])
+            .collect();
+
+        write!(f, "{}", printer::block("interface {", &rows, "}"))
+    }
+}
+
+impl fmt::Display for MethodSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.name, self.signature.signature())
+    }
+}
+
+impl fmt::Display for TypeElem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<_> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", terms.join(" | "))
+    }
+}
+
+impl fmt::Display for TypeTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_underlying {
+            write!(f, "~")?;
+        }
+        write!(f, "{}", self.r#type)
     }
 }
@@ -1,35 +1,64 @@
+pub use golang_parser::{Position, Span};
 pub use golang_struct_tag;
 pub use golang_type_name_core::{self, TypeName, TypeNameParseError};
+pub use num_bigint;
+pub use num_rational;
+pub use num_traits;
 
-use std::str::{self, FromStr};
+use std::{
+    fmt,
+    str::{self, FromStr},
+};
 
 use golang_parser::{tree_sitter::Node, Parser};
 
 pub mod array_type;
 pub mod channel_type;
+pub mod constant;
+pub mod expression;
 pub mod function_type;
+pub mod generic_type;
 pub mod interface_type;
 pub mod map_type;
 pub mod parenthesized_type;
 pub mod pointer_type;
+pub mod printer;
 pub mod slice_type;
 pub mod struct_type;
+pub mod type_mapping;
+pub mod type_parameters;
 
 pub use self::array_type::{ArrayLength, ArrayType, ArrayTypeParseError};
-pub use self::channel_type::{ChannelType, ChannelTypeParseError};
-pub use self::function_type::{FunctionType, FunctionTypeParseError};
-pub use self::interface_type::{InterfaceType, InterfaceTypeParseError};
+pub use self::channel_type::{ChannelDirection, ChannelType, ChannelTypeParseError};
+pub use self::constant::{ConstEvalError, ConstEvaluator, ConstValue, Constant};
+pub use self::expression::{
+    BasicLit, BinaryExpression, BinaryOperator, CallExpression, CompositeLit, Expression,
+    ExpressionParseError, KeyedElement, UnaryOperator,
+};
+pub use self::function_type::{
+    FunctionResult, FunctionType, FunctionTypeParseError, ParameterDecl,
+};
+pub use self::generic_type::{GenericType, GenericTypeParseError};
+pub use self::interface_type::{
+    InterfaceType, InterfaceTypeParseError, MethodSpec, TypeElem, TypeTerm,
+};
 pub use self::map_type::{MapType, MapTypeParseError};
 pub use self::parenthesized_type::{ParenthesizedType, ParenthesizedTypeParseError};
 pub use self::pointer_type::{PointerType, PointerTypeParseError};
 pub use self::slice_type::{SliceType, SliceTypeParseError};
 pub use self::struct_type::{
-    EmbeddedField, FieldDecl, StructField, StructType, StructTypeParseError,
+    is_exported, is_recursive, EmbeddedField, FieldDecl, StructField, StructType,
+    StructTypeParseError,
 };
+pub use self::type_mapping::{TypeMapping, TypeMappings};
+#[cfg(feature = "enable-config")]
+pub use self::type_mapping::{TypeMappingsParseError, CONFIG_FILE_NAME};
+pub use self::type_parameters::{TypeParamDecl, TypeParamDeclParseError};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Type {
     TypeName(TypeName),
+    GenericType(GenericType),
     //
     ArrayType(ArrayType),
     StructType(StructType),
@@ -47,14 +76,16 @@ pub enum Type {
 pub enum TypeParseError {
     #[error("GolangParserError {0:?}")]
     GolangParserError(#[from] golang_parser::Error),
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
-    #[error("NodeKindUnknown {0}")]
-    NodeKindUnknown(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
     //
     //
     #[error("TypeNameParseError {0:?}")]
     TypeNameParseError(#[from] TypeNameParseError),
+    #[error("GenericTypeParseError {0:?}")]
+    GenericTypeParseError(#[from] GenericTypeParseError),
     //
     #[error("ArrayTypeParseError {0:?}")]
     ArrayTypeParseError(#[from] ArrayTypeParseError),
@@ -75,13 +106,19 @@ pub enum TypeParseError {
     //
     #[error("ParenthesizedTypeParseError {0:?}")]
     ParenthesizedTypeParseError(#[from] ParenthesizedTypeParseError),
+    //
+    #[error("TypeParamDeclParseError {0:?}")]
+    TypeParamDeclParseError(#[from] TypeParamDeclParseError),
+    //
+    #[error("ExpressionParseError {0:?}")]
+    ExpressionParseError(#[from] ExpressionParseError),
 }
 
 impl FromStr for Type {
     type Err = TypeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parser = Parser::new(format!("var _ {}", s))?;
+        let parser = Parser::new_strict(format!("var _ {}", s))?;
         let source = parser.get_source();
         let root_node = parser.get_root_node();
 
@@ -89,18 +126,25 @@ impl FromStr for Type {
         let node_var_declaration = root_node
             .named_children(&mut cursor)
             .find(|node| node.kind() == "var_declaration")
-            .ok_or_else(|| TypeParseError::NodeMissing("var_declaration".to_string()))?;
+            .ok_or_else(|| {
+                TypeParseError::NodeMissing("var_declaration".to_string(), Span::from(root_node))
+            })?;
         let node_var_spec = node_var_declaration
             .named_children(&mut cursor)
             .find(|node| node.kind() == "var_spec")
-            .ok_or_else(|| TypeParseError::NodeMissing("var_spec".to_string()))?;
+            .ok_or_else(|| {
+                TypeParseError::NodeMissing(
+                    "var_spec".to_string(),
+                    Span::from(node_var_declaration),
+                )
+            })?;
 
-        let _ = node_var_spec
-            .named_child(0)
-            .ok_or_else(|| TypeParseError::NodeMissing("var_spec name".to_string()))?;
-        let node_var_spec_type = node_var_spec
-            .named_child(1)
-            .ok_or_else(|| TypeParseError::NodeMissing("var_spec type".to_string()))?;
+        let _ = node_var_spec.named_child(0).ok_or_else(|| {
+            TypeParseError::NodeMissing("var_spec name".to_string(), Span::from(node_var_spec))
+        })?;
+        let node_var_spec_type = node_var_spec.named_child(1).ok_or_else(|| {
+            TypeParseError::NodeMissing("var_spec type".to_string(), Span::from(node_var_spec))
+        })?;
 
         Self::from_node(node_var_spec_type, source)
     }
@@ -116,6 +160,9 @@ impl Type {
             "type_identifier" => TypeName::from_type_identifier_node(node, source)
                 .map(Self::TypeName)
                 .map_err(Into::into),
+            "generic_type" => {
+                GenericType::from_generic_type_node(node, source).map(Self::GenericType)
+            }
             //
             "array_type" => ArrayType::from_array_type_node(node, source).map(Self::ArrayType),
             "struct_type" => StructType::from_struct_type_node(node, source).map(Self::StructType),
@@ -136,49 +183,180 @@ impl Type {
             //
             "parenthesized_type" => ParenthesizedType::from_parenthesized_type_node(node, source)
                 .map(Self::ParenthesizedType),
-            _ => Err(TypeParseError::NodeKindUnknown(node.kind().to_owned())),
+            _ => Err(TypeParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            )),
+        }
+    }
+
+    // `interface{}` or `any`
+    pub fn is_empty_interface(&self) -> bool {
+        self.is_empty_interface_with(&|_| false)
+    }
+
+    // The predeclared `any` only if not declared, e.g. `type any struct{}`.
+    pub fn is_empty_interface_with(&self, is_declared: &dyn Fn(&str) -> bool) -> bool {
+        match self {
+            Self::InterfaceType(interface_type) => interface_type.is_empty(),
+            Self::TypeName(TypeName::Identifier(identifier_str)) => {
+                identifier_str == "any" && !is_declared(identifier_str)
+            }
+            Self::ParenthesizedType(ParenthesizedType(element)) => {
+                element.is_empty_interface_with(is_declared)
+            }
+            _ => false,
+        }
+    }
+
+    // e.g. `[16]byte` to `[]byte`, also the nested ones, i.e. Vec instead of the fixed size arrays.
+    pub fn array_to_slice(&self) -> Self {
+        match self {
+            Self::ArrayType(ArrayType { element, .. }) | Self::SliceType(SliceType { element }) => {
+                Self::SliceType(SliceType {
+                    element: element.array_to_slice().into(),
+                })
+            }
+            Self::PointerType(PointerType(element)) => {
+                Self::PointerType(PointerType(element.array_to_slice().into()))
+            }
+            Self::MapType(MapType { key, value }) => Self::MapType(MapType {
+                key: key.array_to_slice().into(),
+                value: value.array_to_slice().into(),
+            }),
+            Self::ParenthesizedType(ParenthesizedType(element)) => {
+                Self::ParenthesizedType(ParenthesizedType(element.array_to_slice().into()))
+            }
+            _ => self.to_owned(),
+        }
+    }
+
+    // Resolves the array lengths of the nested types, e.g. `[N]byte` with `const N = 4`.
+    pub fn resolve_array_lengths(&mut self, evaluator: &ConstEvaluator) {
+        match self {
+            Self::TypeName(_) | Self::FunctionType(_) | Self::InterfaceType(_) => {}
+            Self::GenericType(generic_type) => {
+                for type_arg in generic_type.type_args.iter_mut() {
+                    type_arg.resolve_array_lengths(evaluator);
+                }
+            }
+            Self::ArrayType(array_type) => {
+                array_type.length = array_type.length.to_owned().resolved(evaluator);
+                array_type.element.resolve_array_lengths(evaluator);
+            }
+            Self::StructType(struct_type) => {
+                for field_decl in struct_type.field_decls.iter_mut() {
+                    if let StructField::IdentifierListType(_, r#type) = &mut field_decl.struct_field
+                    {
+                        r#type.resolve_array_lengths(evaluator);
+                    }
+                }
+            }
+            Self::PointerType(PointerType(element))
+            | Self::SliceType(SliceType { element })
+            | Self::ChannelType(ChannelType { element, .. })
+            | Self::ParenthesizedType(ParenthesizedType(element)) => {
+                element.resolve_array_lengths(evaluator)
+            }
+            Self::MapType(MapType { key, value }) => {
+                key.resolve_array_lengths(evaluator);
+                value.resolve_array_lengths(evaluator);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeName(type_name) => write!(f, "{}", type_name),
+            Self::GenericType(generic_type) => write!(f, "{}", generic_type),
+            Self::ArrayType(array_type) => write!(f, "{}", array_type),
+            Self::StructType(struct_type) => write!(f, "{}", struct_type),
+            Self::PointerType(pointer_type) => write!(f, "{}", pointer_type),
+            Self::FunctionType(function_type) => write!(f, "{}", function_type),
+            Self::InterfaceType(interface_type) => write!(f, "{}", interface_type),
+            Self::SliceType(slice_type) => write!(f, "{}", slice_type),
+            Self::MapType(map_type) => write!(f, "{}", map_type),
+            Self::ChannelType(channel_type) => write!(f, "{}", channel_type),
+            Self::ParenthesizedType(parenthesized_type) => write!(f, "{}", parenthesized_type),
         }
     }
 }
 
 #[cfg(feature = "enable-quote-to_tokens")]
 mod enable_quote_to_tokens {
-    use super::Type;
+    use super::{Type, TypeMappings};
 
     use proc_macro2::TokenStream;
     use quote::{quote, ToTokens, TokenStreamExt as _};
 
-    impl ToTokens for Type {
-        fn to_tokens(&self, tokens: &mut TokenStream) {
+    impl Type {
+        // With the Rust types of the types of other packages, e.g. `time.Time`.
+        pub fn to_token_stream_with(&self, type_mappings: &TypeMappings) -> TokenStream {
+            // The way encoding/json decodes into `interface{}`.
+            if self.is_empty_interface_with(&|name| type_mappings.declared_types.contains(name)) {
+                let reexports = reexports(type_mappings);
+                return quote!(#reexports::serde_json::Value);
+            }
+
             match self {
                 //
-                Self::TypeName(type_name) => tokens.append_all(quote!(#type_name)),
+                Self::TypeName(type_name) => match type_mappings.get_by_type_name(type_name) {
+                    Some(type_mapping) => type_mapping
+                        .rust_type
+                        .parse::<TokenStream>()
+                        .unwrap_or_else(|_| {
+                            let err = format!("invalid Rust type {}", type_mapping.rust_type);
+                            quote!(compile_error!(#err))
+                        }),
+                    None => match type_mappings.unmapped_error(type_name) {
+                        Some(err) => quote!(compile_error!(#err)),
+                        None => quote!(#type_name),
+                    },
+                },
+                Self::GenericType(generic_type) => generic_type.to_token_stream_with(type_mappings),
                 //
-                Self::ArrayType(array_type) => tokens.append_all(quote!(#array_type)),
+                Self::ArrayType(array_type) => array_type.to_token_stream_with(type_mappings),
                 Self::StructType(_) => {
                     let err = "impl ToTokens for StructType is unsupported";
-                    tokens.append_all(quote!(compile_error!(#err)))
+                    quote!(compile_error!(#err))
                 }
-                Self::PointerType(pointer_type) => tokens.append_all(quote!(#pointer_type)),
+                Self::PointerType(pointer_type) => pointer_type.to_token_stream_with(type_mappings),
                 Self::FunctionType(_) => {
                     let err = "impl ToTokens for FunctionType is unsupported";
-                    tokens.append_all(quote!(compile_error!(#err)))
+                    quote!(compile_error!(#err))
                 }
                 Self::InterfaceType(_) => {
                     let err = "impl ToTokens for InterfaceType is unsupported";
-                    tokens.append_all(quote!(compile_error!(#err)))
+                    quote!(compile_error!(#err))
                 }
-                Self::SliceType(slice_type) => tokens.append_all(quote!(#slice_type)),
-                Self::MapType(map_type) => tokens.append_all(quote!(#map_type)),
+                Self::SliceType(slice_type) => slice_type.to_token_stream_with(type_mappings),
+                Self::MapType(map_type) => map_type.to_token_stream_with(type_mappings),
                 Self::ChannelType(_) => {
                     let err = "impl ToTokens for ChannelType is unsupported";
-                    tokens.append_all(quote!(compile_error!(#err)))
+                    quote!(compile_error!(#err))
                 }
                 //
                 Self::ParenthesizedType(parenthesized_type) => {
-                    tokens.append_all(quote!(#parenthesized_type))
+                    parenthesized_type.to_token_stream_with(type_mappings)
                 }
             }
         }
     }
+
+    impl ToTokens for Type {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.to_token_stream_with(&TypeMappings::default()))
+        }
+    }
+
+    // e.g. `::golang_type` of `::golang_type::serde_json::Value`, see `TypeMappings::reexports`.
+    pub(crate) fn reexports(type_mappings: &TypeMappings) -> TokenStream {
+        type_mappings
+            .reexports
+            .as_ref()
+            .and_then(|reexports| reexports.parse().ok())
+            .unwrap_or_default()
+    }
 }
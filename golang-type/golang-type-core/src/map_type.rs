@@ -1,4 +1,6 @@
-use golang_parser::tree_sitter::Node;
+use std::fmt;
+
+use golang_parser::{tree_sitter::Node, Span};
 
 use crate::{Type, TypeParseError};
 
@@ -10,17 +12,17 @@ pub struct MapType {
 
 #[derive(thiserror::Error, Debug)]
 pub enum MapTypeParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
 }
 impl MapType {
     pub(crate) fn from_map_type_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
-        let node_map_type_key = node
-            .named_child(0)
-            .ok_or_else(|| MapTypeParseError::NodeMissing("map_type key".to_string()))?;
-        let node_map_type_value = node
-            .named_child(1)
-            .ok_or_else(|| MapTypeParseError::NodeMissing("map_type value".to_string()))?;
+        let node_map_type_key = node.named_child(0).ok_or_else(|| {
+            MapTypeParseError::NodeMissing("map_type key".to_string(), Span::from(node))
+        })?;
+        let node_map_type_value = node.named_child(1).ok_or_else(|| {
+            MapTypeParseError::NodeMissing("map_type value".to_string(), Span::from(node))
+        })?;
 
         let key = Type::from_node(node_map_type_key, source)?;
         let value = Type::from_node(node_map_type_value, source)?;
@@ -32,23 +34,48 @@ impl MapType {
     }
 }
 
+impl fmt::Display for MapType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "map[{}]{}", self.key, self.value)
+    }
+}
+
 #[cfg(feature = "enable-quote-to_tokens")]
 mod enable_quote_to_tokens {
     use super::MapType;
+    use crate::{enable_quote_to_tokens::reexports, Type, TypeMappings, TypeName};
 
     use proc_macro2::{Punct, Spacing, TokenStream};
     use quote::{quote, ToTokens, TokenStreamExt as _};
 
-    impl ToTokens for MapType {
-        fn to_tokens(&self, tokens: &mut TokenStream) {
+    impl MapType {
+        pub(crate) fn to_token_stream_with(&self, type_mappings: &TypeMappings) -> TokenStream {
+            // The way encoding/json decodes JSON objects into `interface{}`.
+            if self.key.as_ref() == &Type::TypeName(TypeName::String)
+                && self
+                    .value
+                    .is_empty_interface_with(&|name| type_mappings.declared_types.contains(name))
+            {
+                let reexports = reexports(type_mappings);
+                return quote!(
+                    #reexports::serde_json::Map<::std::string::String, #reexports::serde_json::Value>
+                );
+            }
+
+            let mut tokens = TokenStream::new();
             tokens.append_all(quote!(::std::collections::HashMap));
             tokens.append(Punct::new('<', Spacing::Alone));
-            let key = &self.key;
-            tokens.append_all(quote!(#key));
+            tokens.append_all(self.key.to_token_stream_with(type_mappings));
             tokens.append(Punct::new(',', Spacing::Alone));
-            let value = &self.value;
-            tokens.append_all(quote!(#value));
+            tokens.append_all(self.value.to_token_stream_with(type_mappings));
             tokens.append(Punct::new('>', Spacing::Alone));
+            tokens
+        }
+    }
+
+    impl ToTokens for MapType {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.to_token_stream_with(&TypeMappings::default()));
         }
     }
 }
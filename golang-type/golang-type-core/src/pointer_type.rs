@@ -1,4 +1,6 @@
-use golang_parser::tree_sitter::Node;
+use std::fmt;
+
+use golang_parser::{tree_sitter::Node, Span};
 
 use crate::{Type, TypeParseError};
 
@@ -7,8 +9,8 @@ pub struct PointerType(pub Box<Type>);
 
 #[derive(thiserror::Error, Debug)]
 pub enum PointerTypeParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
 }
 impl PointerType {
     pub(crate) fn from_pointer_type_node(
@@ -16,7 +18,7 @@ impl PointerType {
         source: &[u8],
     ) -> Result<Self, TypeParseError> {
         let node_pointer_type_element = node.named_child(0).ok_or_else(|| {
-            PointerTypeParseError::NodeMissing("pointer_type element".to_string())
+            PointerTypeParseError::NodeMissing("pointer_type element".to_string(), Span::from(node))
         })?;
 
         let element = Type::from_node(node_pointer_type_element, source)?;
@@ -25,17 +27,29 @@ impl PointerType {
     }
 }
 
+impl fmt::Display for PointerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "*{}", self.0)
+    }
+}
+
 #[cfg(feature = "enable-quote-to_tokens")]
 mod enable_quote_to_tokens {
     use super::PointerType;
+    use crate::TypeMappings;
 
     use proc_macro2::TokenStream;
-    use quote::{quote, ToTokens, TokenStreamExt as _};
+    use quote::{ToTokens, TokenStreamExt as _};
+
+    impl PointerType {
+        pub(crate) fn to_token_stream_with(&self, type_mappings: &TypeMappings) -> TokenStream {
+            self.0.to_token_stream_with(type_mappings)
+        }
+    }
 
     impl ToTokens for PointerType {
         fn to_tokens(&self, tokens: &mut TokenStream) {
-            let element = &self.0;
-            tokens.append_all(quote!(#element));
+            tokens.append_all(self.to_token_stream_with(&TypeMappings::default()));
         }
     }
 }
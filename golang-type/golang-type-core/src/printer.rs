@@ -0,0 +1,74 @@
+// Helpers shared by the `Display` impls, which print gofmt-style Go source.
+
+// Mirrors the elastic tabstops of https://pkg.go.dev/text/tabwriter as configured by gofmt
+// (padding 1, padchar ' '). Every cell except the last one of a row is aligned with the same
+// column of the adjacent rows, a row containing a multi-line cell ends the alignment section.
+pub fn align_rows(rows: &[Vec<String>]) -> String {
+    let mut lines = vec![];
+
+    let mut section_start = 0;
+    for (i, row) in rows.iter().enumerate() {
+        if i + 1 == rows.len() || row.iter().any(|cell| cell.contains('\n')) {
+            align_section(&rows[section_start..=i], &mut lines);
+            section_start = i + 1;
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn align_section(rows: &[Vec<String>], lines: &mut Vec<String>) {
+    // Cells after the first multi-line cell are not aligned.
+    let aligned_len = |row: &Vec<String>| -> usize {
+        match row.iter().position(|cell| cell.contains('\n')) {
+            Some(i) => i,
+            None => row.len().saturating_sub(1),
+        }
+    };
+
+    let mut widths: Vec<Vec<usize>> = rows.iter().map(|row| vec![0; aligned_len(row)]).collect();
+    let column_count = widths.iter().map(Vec::len).max().unwrap_or(0);
+    for column in 0..column_count {
+        // A column block is a run of adjacent rows which all have the column.
+        let mut block_start = 0;
+        while block_start < rows.len() {
+            let mut block_end = block_start;
+            while block_end < rows.len() && widths[block_end].len() > column {
+                block_end += 1;
+            }
+            if block_end == block_start {
+                block_start += 1;
+                continue;
+            }
+
+            let width = rows[block_start..block_end]
+                .iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0);
+            for row_widths in &mut widths[block_start..block_end] {
+                row_widths[column] = width;
+            }
+
+            block_start = block_end;
+        }
+    }
+
+    for (row, row_widths) in rows.iter().zip(widths) {
+        let mut line = String::new();
+        for (cell, width) in row.iter().zip(row_widths.iter()) {
+            line.push_str(&format!("{:width$} ", cell, width = width));
+        }
+        line.push_str(&row[row_widths.len()..].join(" "));
+        lines.push(line);
+    }
+}
+
+// e.g. `struct {`, rows, `}`
+pub fn block(open: &str, rows: &[Vec<String>], close: &str) -> String {
+    format!("{}\n{}\n{}", open, indent(&align_rows(rows)), close)
+}
+
+pub fn indent(s: &str) -> String {
+    format!("\t{}", s.replace('\n', "\n\t"))
+}
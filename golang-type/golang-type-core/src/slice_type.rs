@@ -1,4 +1,6 @@
-use golang_parser::tree_sitter::Node;
+use std::fmt;
+
+use golang_parser::{tree_sitter::Node, Span};
 
 use crate::{Type, TypeParseError};
 
@@ -9,14 +11,14 @@ pub struct SliceType {
 
 #[derive(thiserror::Error, Debug)]
 pub enum SliceTypeParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
 }
 impl SliceType {
     pub(crate) fn from_slice_type_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
-        let node_slice_type_element = node
-            .named_child(0)
-            .ok_or_else(|| SliceTypeParseError::NodeMissing("slice_type element".to_string()))?;
+        let node_slice_type_element = node.named_child(0).ok_or_else(|| {
+            SliceTypeParseError::NodeMissing("slice_type element".to_string(), Span::from(node))
+        })?;
 
         let element = Type::from_node(node_slice_type_element, source)?;
 
@@ -26,21 +28,35 @@ impl SliceType {
     }
 }
 
+impl fmt::Display for SliceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[]{}", self.element)
+    }
+}
+
 #[cfg(feature = "enable-quote-to_tokens")]
 mod enable_quote_to_tokens {
     use super::SliceType;
+    use crate::TypeMappings;
 
     use proc_macro2::{Punct, Spacing, TokenStream};
     use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
 
-    impl ToTokens for SliceType {
-        fn to_tokens(&self, tokens: &mut TokenStream) {
+    impl SliceType {
+        pub(crate) fn to_token_stream_with(&self, type_mappings: &TypeMappings) -> TokenStream {
+            let mut tokens = TokenStream::new();
             let vec_ident = format_ident!("{}", "Vec");
             tokens.append_all(quote!(#vec_ident));
             tokens.append(Punct::new('<', Spacing::Alone));
-            let element = &self.element;
-            tokens.append_all(quote!(#element));
+            tokens.append_all(self.element.to_token_stream_with(type_mappings));
             tokens.append(Punct::new('>', Spacing::Alone));
+            tokens
+        }
+    }
+
+    impl ToTokens for SliceType {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.to_token_stream_with(&TypeMappings::default()));
         }
     }
 }
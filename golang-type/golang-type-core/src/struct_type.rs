@@ -1,9 +1,12 @@
-use std::str;
+use std::{collections::HashMap, fmt, str};
 
-use golang_parser::tree_sitter::Node;
+use golang_parser::{tree_sitter::Node, Span};
 use golang_struct_tag::{StructTag, StructTagParseError};
 
-use crate::{golang_type_name_core::TypeName, PointerType, Type, TypeParseError};
+use crate::{
+    golang_type_name_core::TypeName, printer, ArrayType, ParenthesizedType, PointerType, Type,
+    TypeParseError,
+};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct StructType {
@@ -14,6 +17,8 @@ pub struct StructType {
 pub struct FieldDecl {
     pub struct_field: StructField,
     pub tag: Option<StructTag>,
+    pub span: Span,
+    pub tag_span: Option<Span>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -44,18 +49,64 @@ impl EmbeddedField {
     }
 }
 
+// https://golang.org/ref/spec#Exported_identifiers
+pub fn is_exported(name: &str) -> bool {
+    name.chars().next().is_some_and(char::is_uppercase)
+}
+
+// Contains the named type `name` by value, e.g. `*TreeNode` of `TreeNode`, which has to be boxed
+// in Rust. `types` are the declared types by name.
+pub fn is_recursive(name: &str, r#type: &Type, types: &HashMap<String, Type>) -> bool {
+    let mut visited = vec![];
+    let mut next = vec![r#type];
+    while let Some(r#type) = next.pop() {
+        match r#type {
+            Type::TypeName(TypeName::Identifier(type_name)) => {
+                if type_name == name {
+                    return true;
+                }
+                if visited.contains(&type_name) {
+                    continue;
+                }
+                visited.push(type_name);
+                if let Some(r#type) = types.get(type_name) {
+                    next.push(r#type);
+                }
+            }
+            Type::PointerType(PointerType(element))
+            | Type::ArrayType(ArrayType { element, .. })
+            | Type::ParenthesizedType(ParenthesizedType(element)) => next.push(element),
+            Type::StructType(struct_type) => {
+                for field_decl in &struct_type.field_decls {
+                    match &field_decl.struct_field {
+                        StructField::IdentifierListType(_, r#type) => next.push(r#type),
+                        StructField::EmbeddedField(embedded_field) => {
+                            if let Some(r#type) = types.get(&embedded_field.name()) {
+                                next.push(r#type);
+                            }
+                        }
+                    }
+                }
+            }
+            // On the heap, e.g. `Vec<T>`.
+            _ => {}
+        }
+    }
+    false
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum StructTypeParseError {
-    #[error("NodeMissing {0}")]
-    NodeMissing(String),
-    #[error("NodeKindUnknown {0}")]
-    NodeKindUnknown(String),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
     #[error("Utf8Error {0:?}")]
     Utf8Error(str::Utf8Error),
-    #[error("UnexpectedType {0}")]
-    UnexpectedType(String),
-    #[error("DuplicateField {0}")]
-    DuplicateField(String),
+    #[error("UnexpectedType {0} at {1}")]
+    UnexpectedType(String, Span),
+    #[error("DuplicateField {0} at {1}")]
+    DuplicateField(String, Span),
 
     #[error("StructTagParseError {0:?}")]
     StructTagParseError(#[from] StructTagParseError),
@@ -63,12 +114,17 @@ pub enum StructTypeParseError {
 impl StructType {
     pub(crate) fn from_struct_type_node(node: Node, source: &[u8]) -> Result<Self, TypeParseError> {
         let node_field_declaration_list = node.named_child(0).ok_or_else(|| {
-            StructTypeParseError::NodeMissing("field_declaration_list".to_string())
+            StructTypeParseError::NodeMissing(
+                "field_declaration_list".to_string(),
+                Span::from(node),
+            )
         })?;
         if node_field_declaration_list.kind() != "field_declaration_list" {
-            return Err(
-                StructTypeParseError::NodeMissing("field_declaration_list".to_string()).into(),
-            );
+            return Err(StructTypeParseError::NodeMissing(
+                "field_declaration_list".to_string(),
+                Span::from(node),
+            )
+            .into());
         }
         let mut tree_cursor = node_field_declaration_list.walk();
 
@@ -82,6 +138,7 @@ impl StructType {
                 _ => {
                     return Err(StructTypeParseError::NodeKindUnknown(
                         node_field_declaration.kind().to_owned(),
+                        Span::from(node_field_declaration),
                     )
                     .into())
                 }
@@ -99,6 +156,7 @@ impl StructType {
                     node_field_declaration.named_child(i).ok_or_else(|| {
                         StructTypeParseError::NodeMissing(
                             "field_declaration name or type".to_string(),
+                            Span::from(node_field_declaration),
                         )
                     })?;
                 i += 1;
@@ -113,6 +171,7 @@ impl StructType {
 
             let r#type = Type::from_node(node_field_declaration_type, source)?;
 
+            let tag_span = node_field_declaration.named_child(i).map(Span::from);
             let tag = if let Some(node_field_declaration_tag) =
                 node_field_declaration.named_child(i)
             {
@@ -131,6 +190,7 @@ impl StructType {
                     _ => {
                         return Err(StructTypeParseError::NodeKindUnknown(
                             node_field_declaration_tag.kind().to_owned(),
+                            Span::from(node_field_declaration_tag),
                         )
                         .into())
                     }
@@ -140,7 +200,15 @@ impl StructType {
             };
 
             if node_field_declaration_names.is_empty() {
+                // tree-sitter-go keeps the `*` of `*T` as an anonymous child of field_declaration.
+                let is_pointer = node_field_declaration
+                    .children(&mut node_field_declaration.walk())
+                    .any(|node| node.kind() == "*");
+
                 let embedded_field = match &r#type {
+                    Type::TypeName(type_name) if is_pointer => {
+                        EmbeddedField::PointerType(type_name.to_owned())
+                    }
                     Type::TypeName(type_name) => EmbeddedField::TypeName(type_name.to_owned()),
                     Type::PointerType(PointerType(pointer_type_element)) => {
                         match **pointer_type_element {
@@ -148,28 +216,28 @@ impl StructType {
                                 EmbeddedField::PointerType(type_name.to_owned())
                             }
                             _ => {
-                                return Err(StructTypeParseError::UnexpectedType(format!(
-                                    "unexpected type {:?}",
-                                    pointer_type_element
-                                ))
+                                return Err(StructTypeParseError::UnexpectedType(
+                                    format!("unexpected type {:?}", pointer_type_element),
+                                    Span::from(node_field_declaration),
+                                )
                                 .into())
                             }
                         }
                     }
                     _ => {
-                        return Err(StructTypeParseError::UnexpectedType(format!(
-                            "unexpected type {:?}",
-                            &r#type
-                        ))
+                        return Err(StructTypeParseError::UnexpectedType(
+                            format!("unexpected type {:?}", &r#type),
+                            Span::from(node_field_declaration),
+                        )
                         .into())
                     }
                 };
 
                 if non_blank_field_names.contains(&embedded_field.name()) {
-                    return Err(StructTypeParseError::DuplicateField(format!(
-                        "duplicate field {}",
-                        &embedded_field.name()
-                    ))
+                    return Err(StructTypeParseError::DuplicateField(
+                        format!("duplicate field {}", &embedded_field.name()),
+                        Span::from(node_field_declaration),
+                    )
                     .into());
                 }
                 non_blank_field_names.push(embedded_field.name().to_owned());
@@ -177,6 +245,8 @@ impl StructType {
                 let field_decl = FieldDecl {
                     struct_field: StructField::EmbeddedField(embedded_field),
                     tag,
+                    span: Span::from(node_field_declaration),
+                    tag_span,
                 };
                 field_decls.push(field_decl);
             } else {
@@ -189,10 +259,10 @@ impl StructType {
 
                     if name != "_" {
                         if non_blank_field_names.contains(&name) {
-                            return Err(StructTypeParseError::DuplicateField(format!(
-                                "duplicate field {}",
-                                name
-                            ))
+                            return Err(StructTypeParseError::DuplicateField(
+                                format!("duplicate field {}", name),
+                                Span::from(node_field_declaration),
+                            )
                             .into());
                         }
                         non_blank_field_names.push(name.to_owned());
@@ -204,6 +274,8 @@ impl StructType {
                 let field_decl = FieldDecl {
                     struct_field: StructField::IdentifierListType(names, r#type.into()),
                     tag: tag.to_owned(),
+                    span: Span::from(node_field_declaration),
+                    tag_span,
                 };
                 field_decls.push(field_decl);
             }
@@ -213,6 +285,42 @@ impl StructType {
     }
 }
 
+impl fmt::Display for StructType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field_decls.is_empty() {
+            return write!(f, "struct{{}}");
+        }
+
+        let rows: Vec<_> = self
+            .field_decls
+            .iter()
+            .map(|field_decl| {
+                let mut row = match &field_decl.struct_field {
+                    StructField::IdentifierListType(names, r#type) => {
+                        vec![names.join(", "), r#type.to_string()]
+                    }
+                    StructField::EmbeddedField(embedded_field) => vec![embedded_field.to_string()],
+                };
+                if let Some(tag) = &field_decl.tag {
+                    row.push(tag.to_string());
+                }
+                row
+            })
+            .collect();
+
+        write!(f, "{}", printer::block("struct {", &rows, "}"))
+    }
+}
+
+impl fmt::Display for EmbeddedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeName(type_name) => write!(f, "{}", type_name),
+            Self::PointerType(type_name) => write!(f, "*{}", type_name),
+        }
+    }
+}
+
 //
 //
 //
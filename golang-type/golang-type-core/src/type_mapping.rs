@@ -0,0 +1,255 @@
+use std::collections::{HashMap, HashSet};
+
+use golang_type_name_core::TypeName;
+
+// The Rust type of a Go type, e.g. `time.Time`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TypeMapping {
+    // e.g. `::chrono::DateTime<::chrono::FixedOffset>`
+    pub rust_type: String,
+    // The module of `serialize` and `deserialize`, for the fields of JSON structs.
+    pub serde_with: Option<String>,
+    // The `Option` one for the pointer fields, e.g. `big_int_option`.
+    pub serde_with_option: Option<String>,
+}
+
+// The custom ones take precedence over the well-known ones.
+#[derive(Default, Debug, Clone)]
+pub struct TypeMappings {
+    // Keyed by the type name, the qualified type name or the import path and the type name,
+    // e.g. `UserID`, `decimal.Decimal` or `github.com/shopspring/decimal.Decimal`.
+    pub custom: HashMap<String, TypeMapping>,
+    // The import paths by package name, e.g. `decimal` to `github.com/shopspring/decimal`.
+    pub import_paths: HashMap<String, String>,
+    // The module of the well-known serde helpers, set by the macro crate,
+    // e.g. `::golang_type_decl::serde_helpers`. Without it they have no `serde_with`.
+    pub serde_helpers: Option<String>,
+    // The crate re-exporting the crates of the well-known Rust types, set by the macro crate,
+    // e.g. `::golang_type` for `::golang_type::serde_json::Value`. Without it the paths are
+    // the absolute ones, e.g. `::serde_json::Value`.
+    pub reexports: Option<String>,
+    // The types declared in the source file, which shadow the predeclared ones, e.g. `any`.
+    pub declared_types: HashSet<String>,
+}
+
+// (qualified type name, Rust type, serde helper)
+const WELL_KNOWN: &[(&str, &str, Option<&str>)] = &[
+    // https://pkg.go.dev/time#Time.MarshalJSON
+    (
+        "time.Time",
+        "::chrono::DateTime<::chrono::FixedOffset>",
+        Some("rfc3339_nano"),
+    ),
+    // The integer nanoseconds, which may be negative.
+    ("time.Duration", "i64", None),
+    (
+        "json.RawMessage",
+        "::std::boxed::Box<::serde_json::value::RawValue>",
+        None,
+    ),
+    ("json.Number", "::serde_json::Number", None),
+    // https://pkg.go.dev/math/big#Int.MarshalJSON
+    ("big.Int", "::num_bigint::BigInt", Some("big_int")),
+    // https://pkg.go.dev/net#IP.MarshalText
+    ("net.IP", "::std::net::IpAddr", None),
+];
+
+// The crates of the well-known Rust types, see `TypeMappings::reexports`.
+const REEXPORTED_CRATES: &[&str] = &["chrono", "serde_json", "num_bigint"];
+
+// (qualified type name, error), the ones to be mapped in the config, since no Rust type
+// is (de)serialized the same way.
+const UNMAPPED: &[(&str, &str)] = &[
+    // No MarshalJSON nor MarshalText, encoding/json encodes the fields, unlike the string of
+    // `url::Url`.
+    (
+        "url.URL",
+        "url.URL is encoded as an object of its fields, please map it in golang-rs.toml, e.g. to ::url::Url for the string of URL.String()",
+    ),
+];
+
+impl TypeMappings {
+    pub fn get(&self, qualified_name: &str) -> Option<TypeMapping> {
+        if let Some(type_mapping) = self.custom.get(qualified_name) {
+            return Some(type_mapping.to_owned());
+        }
+
+        WELL_KNOWN
+            .iter()
+            .find(|(name, _, _)| *name == qualified_name)
+            .map(|(_, rust_type, serde_with)| {
+                let serde_with = self
+                    .serde_helpers
+                    .as_ref()
+                    .zip(*serde_with)
+                    .map(|(serde_helpers, name)| format!("{}::{}", serde_helpers, name));
+                TypeMapping {
+                    rust_type: self.reexported(rust_type),
+                    serde_with_option: serde_with
+                        .as_ref()
+                        .map(|serde_with| format!("{}_option", serde_with)),
+                    serde_with,
+                }
+            })
+    }
+
+    // The import path is preferred, since the package name may be an alias.
+    pub fn get_by_type_name(&self, type_name: &TypeName) -> Option<TypeMapping> {
+        match type_name {
+            TypeName::QualifiedIdent(package_name, name) => self
+                .import_paths
+                .get(package_name)
+                .and_then(|import_path| self.custom.get(&format!("{}.{}", import_path, name)))
+                .cloned()
+                .or_else(|| self.get(&format!("{}.{}", package_name, name))),
+            TypeName::Identifier(name) => self.custom.get(name).cloned(),
+            _ => None,
+        }
+    }
+
+    // The paths of the re-exported crates, e.g. `::chrono::FixedOffset` to
+    // `::golang_type::chrono::FixedOffset`.
+    pub fn reexported(&self, rust_type: &str) -> String {
+        match &self.reexports {
+            Some(reexports) => REEXPORTED_CRATES
+                .iter()
+                .fold(rust_type.to_owned(), |s, name| {
+                    s.replace(
+                        &format!("::{}::", name),
+                        &format!("{}::{}::", reexports, name),
+                    )
+                }),
+            None => rust_type.to_owned(),
+        }
+    }
+
+    // The error of the type without a mapping, e.g. `url.URL`.
+    pub fn unmapped_error(&self, type_name: &TypeName) -> Option<&'static str> {
+        match type_name {
+            TypeName::QualifiedIdent(package_name, name) => UNMAPPED
+                .iter()
+                .find(|(qualified_name, _)| *qualified_name == format!("{}.{}", package_name, name))
+                .map(|(_, err)| *err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "enable-config")]
+mod enable_config {
+    use std::{env, fs, path::PathBuf, str::FromStr};
+
+    use super::{TypeMapping, TypeMappings};
+
+    // The project-level config next to `Cargo.toml`, e.g. the `[types]` mappings.
+    pub const CONFIG_FILE_NAME: &str = "golang-rs.toml";
+
+    impl TypeMappings {
+        // The config of the crate being built, for the macros, the default one if missing.
+        pub fn from_manifest_dir() -> Result<Self, String> {
+            let cargo_manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+                "CARGO_MANIFEST_DIR is not set; please use Cargo to build".to_owned()
+            })?;
+
+            let path = PathBuf::from(cargo_manifest_dir).join(CONFIG_FILE_NAME);
+
+            if !path.exists() {
+                return Ok(Self::default());
+            }
+
+            let content = fs::read_to_string(&path)
+                .map_err(|err| format!("failed to read file at {:?}: {}", path, err))?;
+
+            content.parse().map_err(|err: TypeMappingsParseError| {
+                format!("file invalid at {:?}: {}", path, err)
+            })
+        }
+    }
+
+    #[derive(thiserror::Error, Debug)]
+    pub enum TypeMappingsParseError {
+        #[error("TomlInvalid {0}")]
+        TomlInvalid(String),
+        #[error("TypeMappingInvalid {0}")]
+        TypeMappingInvalid(String),
+    }
+
+    // The `[types]` table of `golang-rs.toml`, e.g.
+    //
+    // [types]
+    // "UserID" = "u64"
+    // "decimal.Decimal" = "::rust_decimal::Decimal"
+    // "github.com/google/uuid.UUID" = { rust_type = "::uuid::Uuid", serde_with = "my_crate::uuid" }
+    //
+    // The pointer fields use `serde_with_option`, e.g. `"my_crate::uuid_option"`, for
+    // `Option<T>`. Without it they have no `with`, i.e. the serde impls of the Rust type.
+    impl FromStr for TypeMappings {
+        type Err = TypeMappingsParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let value: toml::Value = s.parse().map_err(|err: toml::de::Error| {
+                TypeMappingsParseError::TomlInvalid(err.to_string())
+            })?;
+
+            let mut type_mappings = Self::default();
+
+            let types = match value.get("types") {
+                Some(toml::Value::Table(types)) => types,
+                Some(_) => return Err(TypeMappingsParseError::TomlInvalid("types".to_owned())),
+                None => return Ok(type_mappings),
+            };
+
+            for (name, value) in types {
+                let type_mapping = match value {
+                    toml::Value::String(rust_type) => TypeMapping {
+                        rust_type: rust_type.to_owned(),
+                        serde_with: None,
+                        serde_with_option: None,
+                    },
+                    toml::Value::Table(table) => {
+                        let rust_type = match table.get("rust_type") {
+                            Some(toml::Value::String(rust_type)) => rust_type.to_owned(),
+                            _ => {
+                                return Err(TypeMappingsParseError::TypeMappingInvalid(
+                                    name.to_owned(),
+                                ))
+                            }
+                        };
+                        let serde_with = match table.get("serde_with") {
+                            Some(toml::Value::String(serde_with)) => Some(serde_with.to_owned()),
+                            None => None,
+                            Some(_) => {
+                                return Err(TypeMappingsParseError::TypeMappingInvalid(
+                                    name.to_owned(),
+                                ))
+                            }
+                        };
+                        let serde_with_option = match table.get("serde_with_option") {
+                            Some(toml::Value::String(serde_with_option)) => {
+                                Some(serde_with_option.to_owned())
+                            }
+                            None => None,
+                            Some(_) => {
+                                return Err(TypeMappingsParseError::TypeMappingInvalid(
+                                    name.to_owned(),
+                                ))
+                            }
+                        };
+                        TypeMapping {
+                            rust_type,
+                            serde_with,
+                            serde_with_option,
+                        }
+                    }
+                    _ => return Err(TypeMappingsParseError::TypeMappingInvalid(name.to_owned())),
+                };
+                type_mappings.custom.insert(name.to_owned(), type_mapping);
+            }
+
+            Ok(type_mappings)
+        }
+    }
+}
+
+#[cfg(feature = "enable-config")]
+pub use self::enable_config::{TypeMappingsParseError, CONFIG_FILE_NAME};
@@ -0,0 +1,138 @@
+use std::{fmt, str};
+
+use golang_parser::{tree_sitter::Node, Span, NODE_KIND_COMMENT};
+
+use crate::{Type, TypeElem, TypeName, TypeParseError, TypeTerm};
+
+// https://go.dev/ref/spec#Type_parameter_declarations
+// e.g. `K comparable`, `T1, T2 any`, `N ~int | ~float64`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TypeParamDecl {
+    pub names: Vec<String>,
+    pub constraint: TypeElem,
+    pub span: Span,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TypeParamDeclParseError {
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(str::Utf8Error),
+}
+impl TypeParamDecl {
+    pub fn from_type_parameter_list_node(
+        node: Node,
+        source: &[u8],
+    ) -> Result<Vec<Self>, TypeParseError> {
+        let mut tree_cursor = node.walk();
+
+        let mut type_param_decls = vec![];
+
+        for node_parameter_declaration in node.named_children(&mut tree_cursor) {
+            match node_parameter_declaration.kind() {
+                "parameter_declaration" => {}
+                NODE_KIND_COMMENT => continue,
+                _ => {
+                    return Err(TypeParamDeclParseError::NodeKindUnknown(
+                        node_parameter_declaration.kind().to_owned(),
+                        Span::from(node_parameter_declaration),
+                    )
+                    .into())
+                }
+            }
+
+            let node_parameter_declaration_type = node_parameter_declaration
+                .child_by_field_name("type")
+                .ok_or_else(|| {
+                    TypeParamDeclParseError::NodeMissing(
+                        "parameter_declaration type".to_string(),
+                        Span::from(node_parameter_declaration),
+                    )
+                })?;
+
+            let mut names = vec![];
+            for node_parameter_declaration_name in node_parameter_declaration
+                .named_children(&mut node_parameter_declaration.walk())
+                .filter(|node| node.kind() == "identifier")
+            {
+                let name = node_parameter_declaration_name
+                    .utf8_text(source)
+                    .map_err(TypeParamDeclParseError::Utf8Error)?;
+                names.push(name.to_owned());
+            }
+            if names.is_empty() {
+                return Err(TypeParamDeclParseError::NodeMissing(
+                    "parameter_declaration name".to_string(),
+                    Span::from(node_parameter_declaration),
+                )
+                .into());
+            }
+
+            let constraint = TypeElem::from_node(node_parameter_declaration_type, source)?;
+
+            type_param_decls.push(Self {
+                names,
+                constraint,
+                span: Span::from(node_parameter_declaration),
+            });
+        }
+
+        Ok(type_param_decls)
+    }
+
+    // https://go.dev/ref/spec#Comparable_types
+    pub fn is_comparable(&self) -> bool {
+        matches!(
+            self.constraint.0.as_slice(),
+            [TypeTerm {
+                r#type,
+                is_underlying: false,
+            }] if **r#type == Type::TypeName(TypeName::Identifier("comparable".to_owned()))
+        )
+    }
+}
+
+impl fmt::Display for TypeParamDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.names.join(", "), self.constraint)
+    }
+}
+
+impl TypeParamDecl {
+    // e.g. `[K comparable, V any]`, empty if there are no type parameters.
+    pub fn list(type_param_decls: &[Self]) -> String {
+        if type_param_decls.is_empty() {
+            return "".to_owned();
+        }
+        let type_param_decls: Vec<_> = type_param_decls.iter().map(ToString::to_string).collect();
+        format!("[{}]", type_param_decls.join(", "))
+    }
+}
+
+#[cfg(feature = "enable-quote-to_tokens")]
+mod enable_quote_to_tokens {
+    use super::TypeParamDecl;
+
+    use proc_macro2::TokenStream;
+    use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
+
+    // `K comparable, V any` => `K: Eq + Hash, V`
+    impl ToTokens for TypeParamDecl {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            let names: Vec<_> = self
+                .names
+                .iter()
+                .map(|name| format_ident!("{}", name))
+                .collect();
+
+            if self.is_comparable() {
+                tokens.append_all(quote!(#(#names: ::core::cmp::Eq + ::core::hash::Hash),*));
+            } else {
+                tokens.append_all(quote!(#(#names),*));
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use std::{error, fs, path::PathBuf};
 
 use golang_type_core::{
-    golang_type_name_core::TypeName, ArrayLength, ArrayType, PointerType, Type,
+    golang_type_name_core::TypeName, ArrayLength, ArrayType, ConstEvaluator, ConstValue, Constant,
+    PointerType, Type,
 };
 
 #[test]
@@ -16,7 +17,13 @@ fn test_parse() -> Result<(), Box<dyn error::Error>> {
                 }),
                 str.parse()?
             ),
-            2 => {}
+            2 => match str.parse()? {
+                Type::ArrayType(ArrayType {
+                    length: ArrayLength::Expression(length),
+                    ..
+                }) => assert_eq!(length.to_string(), "2 * N"),
+                r#type => panic!("unexpected {:?}", r#type),
+            },
             3 => assert_eq!(
                 Type::ArrayType(ArrayType {
                     length: ArrayLength::IntLiteral(1000),
@@ -59,3 +66,34 @@ fn test_parse() -> Result<(), Box<dyn error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_resolve_array_lengths() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(
+        "[2 * 8]int".parse::<Type>()?,
+        Type::ArrayType(ArrayType {
+            length: ArrayLength::IntLiteral(16),
+            element: Type::TypeName(TypeName::Int).into()
+        })
+    );
+    assert_eq!(
+        "[len(\"abc\") + 0x1]int".parse::<Type>()?.to_string(),
+        "[4]int"
+    );
+
+    let mut evaluator = ConstEvaluator::default();
+    evaluator
+        .constants
+        .insert("N".to_owned(), Constant::untyped(ConstValue::Int(4.into())));
+
+    let mut r#type: Type = "map[string][2 * N][N]byte".parse()?;
+    assert_eq!(r#type.to_string(), "map[string][2 * N][N]byte");
+    r#type.resolve_array_lengths(&evaluator);
+    assert_eq!(r#type.to_string(), "map[string][8][4]byte");
+
+    let mut r#type: Type = "[M]byte".parse()?;
+    r#type.resolve_array_lengths(&evaluator);
+    assert_eq!(r#type.to_string(), "[M]byte");
+
+    Ok(())
+}
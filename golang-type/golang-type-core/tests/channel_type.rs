@@ -0,0 +1,85 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_type_core::{
+    golang_type_name_core::TypeName, ChannelDirection, ChannelType, ParenthesizedType, Type,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/channel_type.txt"))?;
+    for (i, str) in content.lines().enumerate() {
+        match i + 1 {
+            1 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Bidirectional,
+                    element: Type::TypeName(TypeName::Identifier("T".to_owned())).into()
+                }),
+                str.parse()?
+            ),
+            2 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Send,
+                    element: Type::TypeName(TypeName::Float64).into()
+                }),
+                str.parse()?
+            ),
+            3 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Receive,
+                    element: Type::TypeName(TypeName::Int).into()
+                }),
+                str.parse()?
+            ),
+            4 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Send,
+                    element: Type::ChannelType(ChannelType {
+                        direction: ChannelDirection::Bidirectional,
+                        element: Type::TypeName(TypeName::Int).into()
+                    })
+                    .into()
+                }),
+                str.parse()?
+            ),
+            5 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Send,
+                    element: Type::ChannelType(ChannelType {
+                        direction: ChannelDirection::Receive,
+                        element: Type::TypeName(TypeName::Int).into()
+                    })
+                    .into()
+                }),
+                str.parse()?
+            ),
+            6 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Receive,
+                    element: Type::ChannelType(ChannelType {
+                        direction: ChannelDirection::Receive,
+                        element: Type::TypeName(TypeName::Int).into()
+                    })
+                    .into()
+                }),
+                str.parse()?
+            ),
+            7 => assert_eq!(
+                Type::ChannelType(ChannelType {
+                    direction: ChannelDirection::Bidirectional,
+                    element: Type::ParenthesizedType(ParenthesizedType(
+                        Type::ChannelType(ChannelType {
+                            direction: ChannelDirection::Receive,
+                            element: Type::TypeName(TypeName::Int).into()
+                        })
+                        .into()
+                    ))
+                    .into()
+                }),
+                str.parse()?
+            ),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use std::error;
+
+use golang_type_core::{
+    ConstEvalError, ConstEvaluator, ConstValue, Constant, Expression, Type, TypeName,
+};
+
+fn eval(s: &str) -> Result<Constant, Box<dyn error::Error>> {
+    let evaluator = ConstEvaluator {
+        iota: Some(2),
+        ..Default::default()
+    };
+    Ok(evaluator.eval(&s.parse::<Expression>()?)?)
+}
+
+fn eval_value(s: &str) -> Result<ConstValue, Box<dyn error::Error>> {
+    eval(s).map(|constant| constant.value)
+}
+
+#[test]
+fn test_literal() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(eval_value("1_000")?, ConstValue::Int(1000.into()));
+    assert_eq!(eval_value("0x_FF")?, ConstValue::Int(255.into()));
+    assert_eq!(eval_value("0o17")?, ConstValue::Int(15.into()));
+    assert_eq!(eval_value("017")?, ConstValue::Int(15.into()));
+    assert_eq!(eval_value("0b101")?, ConstValue::Int(5.into()));
+    assert_eq!(eval_value("0")?, ConstValue::Int(0.into()));
+
+    assert_eq!(eval_value("1.5e3")?.to_f64(), Some(1500.0));
+    assert_eq!(eval_value(".25")?.to_f64(), Some(0.25));
+    assert_eq!(eval_value("0x1.8p1")?.to_f64(), Some(3.0));
+
+    assert_eq!(eval_value("'a'")?, ConstValue::Rune(97.into()));
+    assert_eq!(eval_value(r"'\n'")?, ConstValue::Rune(10.into()));
+    assert_eq!(eval_value(r"'\x41'")?, ConstValue::Rune(65.into()));
+    assert_eq!(eval_value(r"'\101'")?, ConstValue::Rune(65.into()));
+    assert_eq!(eval_value(r"'é'")?, ConstValue::Rune(0xe9.into()));
+    assert_eq!(eval_value("'世'")?, ConstValue::Rune(0x4e16.into()));
+
+    assert_eq!(
+        eval_value(r#""a\tb\"世""#)?,
+        ConstValue::String("a\tb\"世".into())
+    );
+    assert_eq!(eval_value(r"`a\tb`")?, ConstValue::String(r"a\tb".into()));
+
+    assert_eq!(eval_value("true")?, ConstValue::Bool(true));
+    assert_eq!(eval_value("iota")?, ConstValue::Int(2.into()));
+
+    Ok(())
+}
+
+#[test]
+fn test_arithmetic() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(
+        eval_value("1 << (10 * (iota + 1))")?,
+        ConstValue::Int((1 << 30).into())
+    );
+    assert_eq!(eval_value("7 / 2")?, ConstValue::Int(3.into()));
+    assert_eq!(eval_value("-7 % 2")?, ConstValue::Int((-1).into()));
+    assert_eq!(eval_value("7 / 2.0")?.to_f64(), Some(3.5));
+    assert_eq!(
+        eval_value("0xF0 &^ 0x30 | 1")?,
+        ConstValue::Int(0xC1.into())
+    );
+    assert_eq!(eval_value("^0")?, ConstValue::Int((-1).into()));
+    assert_eq!(eval_value("'a' + 1")?, ConstValue::Rune(98.into()));
+    assert_eq!(eval_value("1.0 << 3")?, ConstValue::Int(8.into()));
+    assert_eq!(eval_value("-8 >> 1")?, ConstValue::Int((-4).into()));
+    assert_eq!(
+        eval_value(r#""foo" + `bar`"#)?,
+        ConstValue::String("foobar".into())
+    );
+    assert_eq!(
+        eval_value(r#""a" < "b" && !false"#)?,
+        ConstValue::Bool(true)
+    );
+    assert_eq!(eval_value("min(3, 1, 2)")?, ConstValue::Int(1.into()));
+    assert_eq!(eval_value("max(3, 1, 2.5)")?.to_f64(), Some(3.0));
+
+    assert!(matches!(
+        eval("1 / 0").unwrap_err().downcast_ref::<ConstEvalError>(),
+        Some(ConstEvalError::DivisionByZero)
+    ));
+    assert!(eval(r#"1 + "a""#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_precision() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(eval_value("1 << 100 >> 98")?, ConstValue::Int(4.into()));
+    assert_eq!(
+        eval_value("1 << 100")?.to_string(),
+        "1267650600228229401496703205376"
+    );
+    assert_eq!(eval_value("0.1 + 0.2 == 0.3")?, ConstValue::Bool(true));
+    assert_eq!(eval_value("1e100 / 1e99")?.to_i64(), Some(10));
+    assert_eq!(
+        eval("int64(1e18) * 9")?.value.to_i64(),
+        Some(9_000_000_000_000_000_000)
+    );
+    assert_eq!(
+        eval_value("(1 << 200) / (1 << 199)")?,
+        ConstValue::Int(2.into())
+    );
+
+    assert!(matches!(
+        eval("1 << 600")
+            .unwrap_err()
+            .downcast_ref::<ConstEvalError>(),
+        Some(ConstEvalError::Overflow(_))
+    ));
+    assert!(eval("int64(1 << 63)").is_err());
+    assert!(eval("1.0 / 0").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_typed() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(
+        eval("^uint8(0)")?,
+        Constant {
+            value: ConstValue::Int(255.into()),
+            r#type: Some(Type::TypeName(TypeName::Uint8)),
+        }
+    );
+    assert_eq!(
+        eval("^uint(0) >> 1")?.value,
+        ConstValue::Int(i64::MAX.into())
+    );
+    assert_eq!(
+        eval(r#"len("héllo")"#)?,
+        Constant {
+            value: ConstValue::Int(6.into()),
+            r#type: Some(Type::TypeName(TypeName::Int)),
+        }
+    );
+    assert_eq!(
+        eval(r#"len("\xff" + "\377")"#)?.value,
+        ConstValue::Int(2.into())
+    );
+    assert_eq!(
+        eval(r#""\xffa""#)?.value,
+        ConstValue::String(vec![0xff, b'a'])
+    );
+    assert_eq!(eval(r#""\xffa""#)?.value.as_str(), None);
+    assert_eq!(eval(r#""\xffa\n""#)?.value.to_string(), r#""\xffa\n""#);
+    assert_eq!(
+        eval("Kind(1) + 2")?,
+        Constant {
+            value: ConstValue::Int(3.into()),
+            r#type: Some(Type::TypeName(TypeName::Identifier("Kind".to_owned()))),
+        }
+    );
+    assert_eq!(eval("int(5.0) / 2")?.value, ConstValue::Int(2.into()));
+    assert_eq!(eval("float32(0.1)")?.value.to_f64(), Some(0.1_f32 as f64));
+    assert_eq!(eval("string(65)")?.value, ConstValue::String("A".into()));
+    assert_eq!(eval("Kind(1) == 1")?.r#type, None);
+
+    assert!(eval("uint8(256)").is_err());
+    assert!(eval("uint8(255) + 1").is_err());
+    assert!(eval("-uint(1)").is_err());
+    assert!(eval("int(2.5)").is_err());
+    assert!(eval("int8(1) + int16(1)").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_scope() -> Result<(), Box<dyn error::Error>> {
+    let mut evaluator = ConstEvaluator::default();
+    evaluator.constants.insert(
+        "time.Second".to_owned(),
+        Constant {
+            value: ConstValue::Int(1_000_000_000.into()),
+            r#type: Some(Type::TypeName(TypeName::QualifiedIdent(
+                "time".to_owned(),
+                "Duration".to_owned(),
+            ))),
+        },
+    );
+    evaluator.constants.insert(
+        "N".to_owned(),
+        Constant::untyped(ConstValue::Int(30.into())),
+    );
+
+    let constant = evaluator.eval(&"N * time.Second".parse()?)?;
+    assert_eq!(constant.value, ConstValue::Int(30_000_000_000_i64.into()));
+    assert_eq!(constant.to_string(), "time.Duration(30000000000)");
+
+    assert!(matches!(
+        evaluator.eval(&"M".parse()?),
+        Err(ConstEvalError::IdentifierUnknown(name)) if name == "M"
+    ));
+    assert!(matches!(
+        evaluator.eval(&"iota".parse()?),
+        Err(ConstEvalError::IotaOutsideConstDecl)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_type_core::Type;
+
+#[test]
+fn test_display_gofmt() -> Result<(), Box<dyn error::Error>> {
+    for path in &[
+        "tests/files/struct_type/gofmt.txt",
+        "tests/files/struct_type/tag.txt",
+    ] {
+        let content = fs::read_to_string(PathBuf::new().join(path))?;
+
+        let r#type: Type = content.parse()?;
+        assert_eq!(r#type.to_string(), content.trim_end(), "{}", path);
+    }
+
+    let r#type: Type = "struct { Name string; Server struct { Host string `json:\"host\"` } `json:\"server\"`; Timeout int }".parse()?;
+    assert_eq!(
+        r#type.to_string(),
+        "struct {\n\tName   string\n\tServer struct {\n\t\tHost string `json:\"host\"`\n\t} `json:\"server\"`\n\tTimeout int\n}"
+    );
+
+    let r#type: Type = "interface{ ~int | ~string; fmt.Stringer }".parse()?;
+    assert_eq!(
+        r#type.to_string(),
+        "interface {\n\tfmt.Stringer\n\t~int | ~string\n}"
+    );
+
+    let r#type: Type = "map[string]struct{}".parse()?;
+    assert_eq!(r#type.to_string(), "map[string]struct{}");
+
+    Ok(())
+}
+
+#[test]
+fn test_display_roundtrip() -> Result<(), Box<dyn error::Error>> {
+    for path in &[
+        "tests/files/array_type.txt",
+        "tests/files/channel_type.txt",
+        "tests/files/function_type.txt",
+        "tests/files/generic_type.txt",
+        "tests/files/interface_type.txt",
+        "tests/files/map_type.txt",
+        "tests/files/slice_type.txt",
+    ] {
+        let content = fs::read_to_string(PathBuf::new().join(path))?;
+        for str in content.lines() {
+            let r#type: Type = str.parse()?;
+            assert_eq!(r#type, r#type.to_string().parse()?, "{}", str);
+        }
+    }
+
+    let content =
+        fs::read_to_string(PathBuf::new().join("tests/files/struct_type/embedded_field.txt"))?;
+    let r#type: Type = content.parse()?;
+    assert_eq!(r#type, r#type.to_string().parse()?);
+
+    Ok(())
+}
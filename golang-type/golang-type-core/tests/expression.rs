@@ -0,0 +1,92 @@
+use std::error;
+
+use golang_type_core::{
+    BasicLit, BinaryExpression, BinaryOperator, CallExpression, Expression, SliceType, Type,
+    TypeName, UnaryOperator,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(
+        "1 << (10 * (iota + 1))".parse::<Expression>()?,
+        Expression::Binary(BinaryExpression {
+            left: Expression::BasicLit(BasicLit::Int("1".to_owned())).into(),
+            operator: BinaryOperator::ShiftLeft,
+            right: Expression::Parenthesized(
+                Expression::Binary(BinaryExpression {
+                    left: Expression::BasicLit(BasicLit::Int("10".to_owned())).into(),
+                    operator: BinaryOperator::Mul,
+                    right: Expression::Parenthesized(
+                        Expression::Binary(BinaryExpression {
+                            left: Expression::Identifier("iota".to_owned()).into(),
+                            operator: BinaryOperator::Add,
+                            right: Expression::BasicLit(BasicLit::Int("1".to_owned())).into(),
+                        })
+                        .into()
+                    )
+                    .into(),
+                })
+                .into()
+            )
+            .into(),
+        })
+    );
+
+    assert_eq!(
+        "-time.Second".parse::<Expression>()?,
+        Expression::Unary(
+            UnaryOperator::Minus,
+            Expression::Selector(
+                Expression::Identifier("time".to_owned()).into(),
+                "Second".to_owned()
+            )
+            .into()
+        )
+    );
+
+    assert_eq!(
+        "Kind(len(`a`))".parse::<Expression>()?,
+        Expression::Call(CallExpression {
+            function: Expression::Identifier("Kind".to_owned()).into(),
+            arguments: vec![Expression::Call(CallExpression {
+                function: Expression::Identifier("len".to_owned()).into(),
+                arguments: vec![Expression::BasicLit(BasicLit::String("`a`".to_owned()))],
+                is_variadic: false,
+            })],
+            is_variadic: false,
+        })
+    );
+
+    assert_eq!(
+        r#"[]byte("a")"#.parse::<Expression>()?,
+        Expression::Conversion(
+            Type::SliceType(SliceType {
+                element: Type::TypeName(TypeName::Byte).into()
+            }),
+            Expression::BasicLit(BasicLit::String(r#""a""#.to_owned())).into()
+        )
+    );
+
+    assert!("1 +".parse::<Expression>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_display() -> Result<(), Box<dyn error::Error>> {
+    for s in &[
+        "1 << (10 * (iota + 1))",
+        "^uint(0) >> 1",
+        "!ok && x.y != nil",
+        r#"len("abc") + 'a'"#,
+        "f(a, b...)",
+        "[]byte(`x`)",
+        r#"[...]Point{{1, 2}, 3: {X: 4}}"#,
+        r#"map[string][]int{"a": {1}}"#,
+        "&Config{}",
+    ] {
+        assert_eq!(s.parse::<Expression>()?.to_string(), *s);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+package main
+
+type T int
+
+func main() {
+	// https://golang.org/ref/spec#Channel_types
+	var _ chan T
+	var _ chan<- float64
+	var _ <-chan int
+	var _ chan<- chan int
+	var _ chan<- <-chan int
+	var _ <-chan <-chan int
+	var _ chan (<-chan int)
+}
@@ -0,0 +1,18 @@
+package main
+
+type T int
+
+func main() {
+	// https://golang.org/ref/spec#Function_types
+	var _ func()
+	var _ func(x int) int
+	var _ func(a, _ int, z float32) bool
+	var _ func(a, b int, z float32) (bool)
+	var _ func(prefix string, values ...int)
+	var _ func(a, b int, z float64, opt ...interface{}) (success bool)
+	var _ func(int, int, float64) (float64, *[]int)
+	var _ func(n int) func(p *T)
+
+	//
+	var _ func(...string) (n int, err error)
+}
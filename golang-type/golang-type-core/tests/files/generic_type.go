@@ -0,0 +1,20 @@
+package main
+
+import (
+	"sync/atomic"
+)
+
+type List[T any] []T
+
+type Pair[K comparable, V any] struct {
+	Key   K
+	Value V
+}
+
+func main() {
+	// https://go.dev/ref/spec#Instantiations
+	var _ List[int]
+	var _ atomic.Pointer[int]
+	var _ Pair[string, []int]
+	var _ map[string]List[Pair[string, int]]
+}
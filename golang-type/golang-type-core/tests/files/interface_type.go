@@ -0,0 +1,18 @@
+package main
+
+import (
+	"io"
+)
+
+type Locker interface {
+	Lock()
+	Unlock()
+}
+
+func main() {
+	// https://golang.org/ref/spec#Interface_types
+	var _ interface{}
+	var _ interface{ Read(p []byte) (n int, err error); io.Closer }
+	var _ interface{ Locker; Close() error }
+	var _ any
+}
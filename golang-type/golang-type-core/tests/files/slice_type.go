@@ -3,4 +3,7 @@ package main
 func main() {
 	// https://golang.org/ref/spec#Slice_types
 	var _ []int
+
+	//
+	var _ [][]uint
 }
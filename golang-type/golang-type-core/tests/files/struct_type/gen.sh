@@ -7,7 +7,7 @@ set -ex
 script_path=$(cd $(dirname $0) ; pwd -P)
 script_path_root="${script_path}/"
 
-categories=('embedded_field' 'normal' 'tag')
+categories=('embedded_field' 'gofmt' 'normal' 'tag')
 
 for category in "${categories[@]}"
 do
@@ -0,0 +1,18 @@
+package main
+
+import "io"
+
+type Node struct{}
+
+func main() {
+	// gofmt output
+	var _ struct {
+		io.Reader
+		*Node      `json:"node"`
+		ID, Parent int64  `json:"id,string"`
+		Name       string `json:"name,omitempty" xml:"name"`
+		Labels     map[string]string
+		Handler    func(w io.Writer, args ...string) error
+		Done       <-chan struct{}
+	}
+}
@@ -0,0 +1,234 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_type_core::{
+    golang_type_name_core::TypeName, FunctionResult, FunctionType, InterfaceType, ParameterDecl,
+    PointerType, SliceType, Type,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/function_type.txt"))?;
+    for (i, str) in content.lines().enumerate() {
+        match i + 1 {
+            1 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![],
+                    result: None,
+                }),
+                str.parse()?
+            ),
+            2 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![ParameterDecl {
+                        names: vec!["x".to_owned()],
+                        r#type: Type::TypeName(TypeName::Int).into(),
+                        is_variadic: false,
+                    }],
+                    result: Some(FunctionResult::Type(Type::TypeName(TypeName::Int).into())),
+                }),
+                str.parse()?
+            ),
+            3 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![
+                        ParameterDecl {
+                            names: vec!["a".to_owned(), "_".to_owned()],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec!["z".to_owned()],
+                            r#type: Type::TypeName(TypeName::Float32).into(),
+                            is_variadic: false,
+                        }
+                    ],
+                    result: Some(FunctionResult::Type(Type::TypeName(TypeName::Bool).into())),
+                }),
+                str.parse()?
+            ),
+            4 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![
+                        ParameterDecl {
+                            names: vec!["a".to_owned(), "b".to_owned()],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec!["z".to_owned()],
+                            r#type: Type::TypeName(TypeName::Float32).into(),
+                            is_variadic: false,
+                        }
+                    ],
+                    result: Some(FunctionResult::Parameters(vec![ParameterDecl {
+                        names: vec![],
+                        r#type: Type::TypeName(TypeName::Bool).into(),
+                        is_variadic: false,
+                    }])),
+                }),
+                str.parse()?
+            ),
+            5 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![
+                        ParameterDecl {
+                            names: vec!["prefix".to_owned()],
+                            r#type: Type::TypeName(TypeName::String).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec!["values".to_owned()],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: true,
+                        }
+                    ],
+                    result: None,
+                }),
+                str.parse()?
+            ),
+            6 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![
+                        ParameterDecl {
+                            names: vec!["a".to_owned(), "b".to_owned()],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec!["z".to_owned()],
+                            r#type: Type::TypeName(TypeName::Float64).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec!["opt".to_owned()],
+                            r#type: Type::InterfaceType(InterfaceType {
+                                method_specs: vec![],
+                                embedded_interfaces: vec![],
+                                type_elems: vec![],
+                            })
+                            .into(),
+                            is_variadic: true,
+                        }
+                    ],
+                    result: Some(FunctionResult::Parameters(vec![ParameterDecl {
+                        names: vec!["success".to_owned()],
+                        r#type: Type::TypeName(TypeName::Bool).into(),
+                        is_variadic: false,
+                    }])),
+                }),
+                str.parse()?
+            ),
+            7 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![
+                        ParameterDecl {
+                            names: vec![],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec![],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec![],
+                            r#type: Type::TypeName(TypeName::Float64).into(),
+                            is_variadic: false,
+                        }
+                    ],
+                    result: Some(FunctionResult::Parameters(vec![
+                        ParameterDecl {
+                            names: vec![],
+                            r#type: Type::TypeName(TypeName::Float64).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec![],
+                            r#type: Type::PointerType(PointerType(
+                                Type::SliceType(SliceType {
+                                    element: Type::TypeName(TypeName::Int).into()
+                                })
+                                .into()
+                            ))
+                            .into(),
+                            is_variadic: false,
+                        }
+                    ])),
+                }),
+                str.parse()?
+            ),
+            8 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![ParameterDecl {
+                        names: vec!["n".to_owned()],
+                        r#type: Type::TypeName(TypeName::Int).into(),
+                        is_variadic: false,
+                    }],
+                    result: Some(FunctionResult::Type(
+                        Type::FunctionType(FunctionType {
+                            parameters: vec![ParameterDecl {
+                                names: vec!["p".to_owned()],
+                                r#type: Type::PointerType(PointerType(
+                                    Type::TypeName(TypeName::Identifier("T".to_owned())).into()
+                                ))
+                                .into(),
+                                is_variadic: false,
+                            }],
+                            result: None,
+                        })
+                        .into()
+                    )),
+                }),
+                str.parse()?
+            ),
+            9 => assert_eq!(
+                Type::FunctionType(FunctionType {
+                    parameters: vec![ParameterDecl {
+                        names: vec![],
+                        r#type: Type::TypeName(TypeName::String).into(),
+                        is_variadic: true,
+                    }],
+                    result: Some(FunctionResult::Parameters(vec![
+                        ParameterDecl {
+                            names: vec!["n".to_owned()],
+                            r#type: Type::TypeName(TypeName::Int).into(),
+                            is_variadic: false,
+                        },
+                        ParameterDecl {
+                            names: vec!["err".to_owned()],
+                            r#type: Type::TypeName(TypeName::Identifier("error".to_owned())).into(),
+                            is_variadic: false,
+                        }
+                    ])),
+                }),
+                str.parse()?
+            ),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_compare() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(
+        "func(a, b int)".parse::<Type>()?,
+        "func(a, b int)".parse::<Type>()?
+    );
+    assert_ne!(
+        "func(a, b int)".parse::<Type>()?,
+        "func(a int, b int)".parse::<Type>()?
+    );
+    assert_ne!(
+        "func(int) error".parse::<Type>()?,
+        "func(int) (error)".parse::<Type>()?
+    );
+    assert_ne!(
+        "func(...int)".parse::<Type>()?,
+        "func([]int)".parse::<Type>()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_type_core::{golang_type_name_core::TypeName, GenericType, MapType, SliceType, Type};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/generic_type.txt"))?;
+    for (i, str) in content.lines().enumerate() {
+        match i + 1 {
+            1 => assert_eq!(
+                Type::GenericType(GenericType {
+                    type_name: TypeName::Identifier("List".to_owned()),
+                    type_args: vec![Type::TypeName(TypeName::Int)]
+                }),
+                str.parse()?
+            ),
+            2 => assert_eq!(
+                Type::GenericType(GenericType {
+                    type_name: TypeName::QualifiedIdent("atomic".to_owned(), "Pointer".to_owned()),
+                    type_args: vec![Type::TypeName(TypeName::Int)]
+                }),
+                str.parse()?
+            ),
+            3 => assert_eq!(
+                Type::GenericType(GenericType {
+                    type_name: TypeName::Identifier("Pair".to_owned()),
+                    type_args: vec![
+                        Type::TypeName(TypeName::String),
+                        Type::SliceType(SliceType {
+                            element: Type::TypeName(TypeName::Int).into()
+                        })
+                    ]
+                }),
+                str.parse()?
+            ),
+            4 => assert_eq!(
+                Type::MapType(MapType {
+                    key: Type::TypeName(TypeName::String).into(),
+                    value: Type::GenericType(GenericType {
+                        type_name: TypeName::Identifier("List".to_owned()),
+                        type_args: vec![Type::GenericType(GenericType {
+                            type_name: TypeName::Identifier("Pair".to_owned()),
+                            type_args: vec![
+                                Type::TypeName(TypeName::String),
+                                Type::TypeName(TypeName::Int)
+                            ]
+                        })]
+                    })
+                    .into()
+                }),
+                str.parse()?
+            ),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use std::{error, fs, path::PathBuf};
+
+use golang_type_core::{
+    golang_type_name_core::TypeName, FunctionResult, FunctionType, InterfaceType, MethodSpec,
+    ParameterDecl, SliceType, Type, TypeElem, TypeTerm,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let content = fs::read_to_string(PathBuf::new().join("tests/files/interface_type.txt"))?;
+    for (i, str) in content.lines().enumerate() {
+        match i + 1 {
+            1 => {
+                let r#type: Type = str.parse()?;
+                assert_eq!(
+                    Type::InterfaceType(InterfaceType {
+                        method_specs: vec![],
+                        embedded_interfaces: vec![],
+                        type_elems: vec![],
+                    }),
+                    r#type
+                );
+                assert!(r#type.is_empty_interface());
+            }
+            2 => assert_eq!(
+                Type::InterfaceType(InterfaceType {
+                    method_specs: vec![MethodSpec {
+                        name: "Read".to_owned(),
+                        signature: FunctionType {
+                            parameters: vec![ParameterDecl {
+                                names: vec!["p".to_owned()],
+                                r#type: Type::SliceType(SliceType {
+                                    element: Type::TypeName(TypeName::Byte).into()
+                                })
+                                .into(),
+                                is_variadic: false,
+                            }],
+                            result: Some(FunctionResult::Parameters(vec![
+                                ParameterDecl {
+                                    names: vec!["n".to_owned()],
+                                    r#type: Type::TypeName(TypeName::Int).into(),
+                                    is_variadic: false,
+                                },
+                                ParameterDecl {
+                                    names: vec!["err".to_owned()],
+                                    r#type: Type::TypeName(TypeName::Identifier(
+                                        "error".to_owned()
+                                    ))
+                                    .into(),
+                                    is_variadic: false,
+                                }
+                            ])),
+                        },
+                        span: Default::default(),
+                    }],
+                    embedded_interfaces: vec![TypeName::QualifiedIdent(
+                        "io".to_owned(),
+                        "Closer".to_owned()
+                    )],
+                    type_elems: vec![],
+                }),
+                str.parse()?
+            ),
+            3 => {
+                let r#type: Type = str.parse()?;
+                assert_eq!(
+                    Type::InterfaceType(InterfaceType {
+                        method_specs: vec![MethodSpec {
+                            name: "Close".to_owned(),
+                            signature: FunctionType {
+                                parameters: vec![],
+                                result: Some(FunctionResult::Type(
+                                    Type::TypeName(TypeName::Identifier("error".to_owned())).into()
+                                )),
+                            },
+                            span: Default::default(),
+                        }],
+                        embedded_interfaces: vec![TypeName::Identifier("Locker".to_owned())],
+                        type_elems: vec![],
+                    }),
+                    r#type
+                );
+                assert!(!r#type.is_empty_interface());
+            }
+            4 => {
+                let r#type: Type = str.parse()?;
+                assert_eq!(
+                    Type::TypeName(TypeName::Identifier("any".to_owned())),
+                    r#type
+                );
+                assert!(r#type.is_empty_interface());
+                assert!(!r#type.is_empty_interface_with(&|name| name == "any"));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_type_elem() -> Result<(), Box<dyn error::Error>> {
+    assert_eq!(
+        Type::InterfaceType(InterfaceType {
+            method_specs: vec![],
+            embedded_interfaces: vec![],
+            type_elems: vec![TypeElem(vec![
+                TypeTerm {
+                    r#type: Type::TypeName(TypeName::Int).into(),
+                    is_underlying: true,
+                },
+                TypeTerm {
+                    r#type: Type::TypeName(TypeName::String).into(),
+                    is_underlying: true,
+                },
+                TypeTerm {
+                    r#type: Type::TypeName(TypeName::Float64).into(),
+                    is_underlying: false,
+                },
+            ])],
+        }),
+        "interface{ ~int | ~string | float64 }".parse()?
+    );
+
+    assert_eq!(
+        Type::InterfaceType(InterfaceType {
+            method_specs: vec![],
+            embedded_interfaces: vec![TypeName::Identifier("comparable".to_owned())],
+            type_elems: vec![
+                TypeElem(vec![TypeTerm {
+                    r#type: Type::SliceType(SliceType {
+                        element: Type::TypeName(TypeName::Byte).into()
+                    })
+                    .into(),
+                    is_underlying: true,
+                }]),
+                TypeElem(vec![TypeTerm {
+                    r#type: Type::TypeName(TypeName::Int).into(),
+                    is_underlying: false,
+                }]),
+            ],
+        }),
+        "interface{ comparable; ~[]byte; int }".parse()?
+    );
+
+    Ok(())
+}
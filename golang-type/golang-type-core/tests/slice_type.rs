@@ -13,7 +13,15 @@ fn test_parse() -> Result<(), Box<dyn error::Error>> {
                 }),
                 str.parse()?
             ),
-
+            2 => assert_eq!(
+                Type::SliceType(SliceType {
+                    element: Type::SliceType(SliceType {
+                        element: Type::TypeName(TypeName::Uint).into()
+                    })
+                    .into()
+                }),
+                str.parse()?
+            ),
             _ => {}
         }
     }
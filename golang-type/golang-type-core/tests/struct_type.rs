@@ -23,24 +23,32 @@ fn test_parse_embedded_field() -> Result<(), Box<dyn error::Error>> {
                         TypeName::Identifier("T1".to_owned())
                     )),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
-                    struct_field: StructField::EmbeddedField(EmbeddedField::TypeName(
+                    struct_field: StructField::EmbeddedField(EmbeddedField::PointerType(
                         TypeName::Identifier("T2".to_owned())
                     )),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
                     struct_field: StructField::EmbeddedField(EmbeddedField::TypeName(
                         TypeName::QualifiedIdent("P".to_owned(), "Duration".to_owned())
                     )),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
-                    struct_field: StructField::EmbeddedField(EmbeddedField::TypeName(
+                    struct_field: StructField::EmbeddedField(EmbeddedField::PointerType(
                         TypeName::QualifiedIdent("P".to_owned(), "Month".to_owned())
                     )),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
                     struct_field: StructField::IdentifierListType(
@@ -48,6 +56,8 @@ fn test_parse_embedded_field() -> Result<(), Box<dyn error::Error>> {
                         Type::TypeName(TypeName::Int).into()
                     ),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
             ]
         })
@@ -72,6 +82,8 @@ fn test_parse_normal() -> Result<(), Box<dyn error::Error>> {
                         Type::TypeName(TypeName::Int).into()
                     ),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
                     struct_field: StructField::IdentifierListType(
@@ -79,6 +91,8 @@ fn test_parse_normal() -> Result<(), Box<dyn error::Error>> {
                         Type::TypeName(TypeName::Float32).into()
                     ),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
                     struct_field: StructField::IdentifierListType(
@@ -86,29 +100,36 @@ fn test_parse_normal() -> Result<(), Box<dyn error::Error>> {
                         Type::TypeName(TypeName::Float32).into()
                     ),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
                     struct_field: StructField::IdentifierListType(
                         vec!["A".to_owned()],
-                        Type::PointerType(
-                            PointerType(
-                                Type::SliceType(SliceType {
-                                    element: Type::TypeName(TypeName::Int).into()
-                                })
-                                .into()
-                            )
+                        Type::PointerType(PointerType(
+                            Type::SliceType(SliceType {
+                                element: Type::TypeName(TypeName::Int).into()
+                            })
                             .into()
-                        )
+                        ))
                         .into(),
                     ),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
                 FieldDecl {
                     struct_field: StructField::IdentifierListType(
                         vec!["F".to_owned()],
-                        Type::FunctionType(FunctionType {}).into(),
+                        Type::FunctionType(FunctionType {
+                            parameters: vec![],
+                            result: None,
+                        })
+                        .into(),
                     ),
                     tag: None,
+                    span: Default::default(),
+                    tag_span: None,
                 },
             ]
         })
@@ -140,6 +161,8 @@ fn test_parse_tag() -> Result<(), Box<dyn error::Error>> {
                         .into_iter()
                         .collect()
                     )),
+                    span: Default::default(),
+                    tag_span: Some(Default::default()),
                 },
                 FieldDecl {
                     struct_field: StructField::IdentifierListType(
@@ -154,6 +177,8 @@ fn test_parse_tag() -> Result<(), Box<dyn error::Error>> {
                         .into_iter()
                         .collect()
                     )),
+                    span: Default::default(),
+                    tag_span: Some(Default::default()),
                 },
             ]
         })
@@ -175,6 +200,7 @@ fn test_parse_tag() -> Result<(), Box<dyn error::Error>> {
 //         Ok(_) => assert!(false),
 //         Err(TypeParseError::StructTypeParseError(StructTypeParseError::UnexpectedType(
 //             ref err,
+//             _,
 //         ))) if err.starts_with("unexpected type ") => {}
 //         Err(err) => assert!(false, "{:?}", err),
 //     }
@@ -192,11 +218,12 @@ fn test_parse_with_duplicate_field() -> Result<(), Box<dyn error::Error>> {
     "#
     .parse::<Type>()
     {
-        Ok(_) => assert!(false),
+        Ok(_) => panic!(),
         Err(TypeParseError::StructTypeParseError(StructTypeParseError::DuplicateField(
             ref err,
+            _,
         ))) if err == "duplicate field int" => {}
-        Err(err) => assert!(false, "{:?}", err),
+        Err(err) => panic!("{:?}", err),
     }
 
     match r#"
@@ -207,11 +234,15 @@ fn test_parse_with_duplicate_field() -> Result<(), Box<dyn error::Error>> {
     "#
     .parse::<Type>()
     {
-        Ok(_) => assert!(false),
+        Ok(_) => panic!(),
         Err(TypeParseError::StructTypeParseError(StructTypeParseError::DuplicateField(
             ref err,
-        ))) if err == "duplicate field a" => {}
-        Err(err) => assert!(false, "{}", err),
+            span,
+        ))) if err == "duplicate field a" => {
+            assert_eq!(span.start.line, 4);
+            assert_eq!(span.start.column, 3);
+        }
+        Err(err) => panic!("{}", err),
     }
 
     Ok(())
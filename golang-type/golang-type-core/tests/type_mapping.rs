@@ -0,0 +1,189 @@
+use golang_type_core::{TypeMapping, TypeMappings, TypeName};
+
+#[test]
+fn test_get() {
+    let mut type_mappings = TypeMappings::default();
+    assert_eq!(
+        type_mappings.get("big.Int"),
+        Some(TypeMapping {
+            rust_type: "::num_bigint::BigInt".to_owned(),
+            serde_with: None,
+            serde_with_option: None,
+        })
+    );
+    assert_eq!(type_mappings.get("sql.NullString"), None);
+
+    type_mappings.serde_helpers = Some("::golang_type_decl::serde_helpers".to_owned());
+    assert_eq!(
+        type_mappings.get("big.Int"),
+        Some(TypeMapping {
+            rust_type: "::num_bigint::BigInt".to_owned(),
+            serde_with: Some("::golang_type_decl::serde_helpers::big_int".to_owned()),
+            serde_with_option: Some("::golang_type_decl::serde_helpers::big_int_option".to_owned()),
+        })
+    );
+
+    type_mappings.custom.insert(
+        "time.Duration".to_owned(),
+        TypeMapping {
+            rust_type: "::core::time::Duration".to_owned(),
+            serde_with: None,
+            serde_with_option: None,
+        },
+    );
+    assert_eq!(
+        type_mappings.get("time.Duration").map(|x| x.rust_type),
+        Some("::core::time::Duration".to_owned())
+    );
+}
+
+#[test]
+fn test_get_by_type_name() {
+    let mut type_mappings = TypeMappings::default();
+    type_mappings.custom.insert(
+        "UserID".to_owned(),
+        TypeMapping {
+            rust_type: "u64".to_owned(),
+            serde_with: None,
+            serde_with_option: None,
+        },
+    );
+    type_mappings.custom.insert(
+        "github.com/google/uuid.UUID".to_owned(),
+        TypeMapping {
+            rust_type: "::uuid::Uuid".to_owned(),
+            serde_with: None,
+            serde_with_option: None,
+        },
+    );
+    type_mappings
+        .import_paths
+        .insert("id".to_owned(), "github.com/google/uuid".to_owned());
+    type_mappings
+        .import_paths
+        .insert("stdtime".to_owned(), "time".to_owned());
+
+    let rust_type = |type_name: TypeName| {
+        type_mappings
+            .get_by_type_name(&type_name)
+            .map(|x| x.rust_type)
+    };
+    assert_eq!(
+        rust_type(TypeName::Identifier("UserID".to_owned())),
+        Some("u64".to_owned())
+    );
+    assert_eq!(
+        rust_type(TypeName::QualifiedIdent("id".to_owned(), "UUID".to_owned())),
+        Some("::uuid::Uuid".to_owned())
+    );
+    assert_eq!(
+        rust_type(TypeName::QualifiedIdent(
+            "uuid".to_owned(),
+            "UUID".to_owned()
+        )),
+        None
+    );
+    assert_eq!(
+        rust_type(TypeName::QualifiedIdent(
+            "time".to_owned(),
+            "Duration".to_owned()
+        )),
+        Some("i64".to_owned())
+    );
+    assert_eq!(rust_type(TypeName::Identifier("Duration".to_owned())), None);
+}
+
+#[test]
+fn test_unmapped_error() {
+    let mut type_mappings = TypeMappings::default();
+    let type_name = TypeName::QualifiedIdent("url".to_owned(), "URL".to_owned());
+    assert!(type_mappings.get_by_type_name(&type_name).is_none());
+    assert!(type_mappings.unmapped_error(&type_name).is_some());
+    assert!(type_mappings
+        .unmapped_error(&TypeName::QualifiedIdent(
+            "url".to_owned(),
+            "Values".to_owned()
+        ))
+        .is_none());
+
+    type_mappings.custom.insert(
+        "url.URL".to_owned(),
+        TypeMapping {
+            rust_type: "::url::Url".to_owned(),
+            serde_with: None,
+            serde_with_option: None,
+        },
+    );
+    assert_eq!(
+        type_mappings
+            .get_by_type_name(&type_name)
+            .map(|x| x.rust_type),
+        Some("::url::Url".to_owned())
+    );
+}
+
+#[test]
+fn test_reexported() {
+    let type_mappings = TypeMappings {
+        reexports: Some("::golang_type".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(
+        type_mappings.get("time.Time").map(|x| x.rust_type),
+        Some("::golang_type::chrono::DateTime<::golang_type::chrono::FixedOffset>".to_owned())
+    );
+    assert_eq!(
+        type_mappings.get("net.IP").map(|x| x.rust_type),
+        Some("::std::net::IpAddr".to_owned())
+    );
+    assert_eq!(
+        TypeMappings::default().reexported("::serde_json::Number"),
+        "::serde_json::Number"
+    );
+}
+
+#[cfg(feature = "enable-config")]
+#[test]
+fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+    let type_mappings: TypeMappings = r#"
+[types]
+"UserID" = "u64"
+"decimal.Decimal" = { rust_type = "::rust_decimal::Decimal", serde_with = "::rust_decimal::serde::str" }
+"uuid.UUID" = { rust_type = "::uuid::Uuid", serde_with_option = "my_crate::uuid_option" }
+"#
+    .parse()?;
+    assert_eq!(
+        type_mappings.get("UserID"),
+        Some(TypeMapping {
+            rust_type: "u64".to_owned(),
+            serde_with: None,
+            serde_with_option: None,
+        })
+    );
+    assert_eq!(
+        type_mappings.get("decimal.Decimal"),
+        Some(TypeMapping {
+            rust_type: "::rust_decimal::Decimal".to_owned(),
+            serde_with: Some("::rust_decimal::serde::str".to_owned()),
+            serde_with_option: None,
+        })
+    );
+
+    assert_eq!(
+        type_mappings.get("uuid.UUID"),
+        Some(TypeMapping {
+            rust_type: "::uuid::Uuid".to_owned(),
+            serde_with: None,
+            serde_with_option: Some("my_crate::uuid_option".to_owned()),
+        })
+    );
+
+    assert!("".parse::<TypeMappings>()?.custom.is_empty());
+    assert!("[types]\nUserID = 1".parse::<TypeMappings>().is_err());
+    assert!("[types]\nUserID = { serde_with = \"x\" }"
+        .parse::<TypeMappings>()
+        .is_err());
+    assert!("types = [".parse::<TypeMappings>().is_err());
+
+    Ok(())
+}
@@ -1,14 +1,15 @@
 extern crate proc_macro;
 
-use golang_type_core::{Type, TypeParseError};
+use golang_type_core::{Type, TypeMappings, TypeParseError};
 use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
-    parse_macro_input, Error as SynError, LitStr,
+    parse_macro_input, Error as SynError, Ident, LitBool, LitStr, Token,
 };
 
 struct GenTypeInput {
     r#type: Type,
+    disable_fixed_size_array: bool,
 }
 impl Parse for GenTypeInput {
     fn parse(input: ParseStream) -> Result<Self, SynError> {
@@ -19,16 +20,53 @@ impl Parse for GenTypeInput {
             .parse()
             .map_err(|err: TypeParseError| SynError::new_spanned(r#type, err.to_string()))?;
 
-        Ok(Self { r#type })
+        let mut disable_fixed_size_array = false;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            if key == "disable_fixed_size_array" {
+                disable_fixed_size_array = input.parse::<LitBool>()?.value();
+            } else {
+                let err = format!("unexpected input key: {}", key);
+                return Err(SynError::new_spanned(key, err));
+            }
+        }
+
+        Ok(Self {
+            r#type,
+            disable_fixed_size_array,
+        })
     }
 }
 
+// The crates of the well-known types, e.g. `serde_json` of `interface{}`, re-exported by this.
+const REEXPORTS: &str = "::golang_type";
+
 #[proc_macro]
 pub fn gen_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as GenTypeInput);
 
-    let r#type = input.r#type;
+    let type_mappings = match TypeMappings::from_manifest_dir() {
+        Ok(type_mappings) => TypeMappings {
+            reexports: Some(REEXPORTS.to_owned()),
+            ..type_mappings
+        },
+        Err(err) => return quote!(compile_error!(#err)).into(),
+    };
+
+    let r#type = if input.disable_fixed_size_array {
+        input.r#type.array_to_slice()
+    } else {
+        input.r#type
+    };
 
-    let output = quote!(#r#type);
+    let output = r#type.to_token_stream_with(&type_mappings);
     output.into()
 }
@@ -1,9 +1,22 @@
 pub use golang_type_core::*;
 pub use golang_type_macro;
 
+// The crates of the well-known Rust types, e.g. `::golang_type::serde_json::Value`.
+#[cfg(feature = "enable-chrono")]
+pub use chrono;
+#[cfg(feature = "enable-num-bigint")]
+pub use num_bigint;
+pub use serde_json;
+
 #[macro_export]
 macro_rules! gen_type {
     ($type_lit:literal) => {
         golang_type_macro::gen_type!($type_lit)
     };
+    (
+        $type_lit:literal,
+        $( $opt_k:ident = $opt_v:literal ),+ $(,)?
+    ) => {
+        golang_type_macro::gen_type!($type_lit, $( $opt_k = $opt_v ,)*)
+    };
 }
@@ -1,6 +1,7 @@
 use std::{
     any::{Any as _, TypeId},
     collections::HashMap,
+    net::IpAddr,
 };
 
 macro_rules! assert_gen_type {
@@ -13,14 +14,20 @@ macro_rules! assert_gen_type {
 
 #[test]
 fn test_array_type() {
-    assert_gen_type!("[32]byte", vec![8_u8], Vec<u8>);
-    assert_gen_type!("[1000]*float64", vec![0.0_f64], Vec<f64>);
-    assert_gen_type!("[3][5]int", vec![vec![-1_isize]], Vec<Vec<isize>>);
+    assert_gen_type!("[32]byte", [8_u8; 32], [u8; 32]);
+    assert_gen_type!("[1000]*float64", [0.0_f64; 1000], [f64; 1000]);
+    assert_gen_type!("[3][5]int", [[-1_isize; 5]; 3], [[isize; 5]; 3]);
     assert_gen_type!(
         "[2][2][2]float64",
-        vec![vec![vec![0.0_f64]]],
-        Vec<Vec<Vec<f64>>>
+        [[[0.0_f64; 2]; 2]; 2],
+        [[[f64; 2]; 2]; 2]
     );
+    assert_gen_type!("[2 * 4]byte", [0_u8; 8], [u8; 8]);
+    assert_gen_type!("[N]byte", vec![8_u8], Vec<u8>);
+
+    let v: golang_type::gen_type!("[3][5]int", disable_fixed_size_array = true);
+    v = vec![vec![-1_isize; 5]; 3];
+    assert_eq!(v.type_id(), TypeId::of::<Vec<Vec<isize>>>());
 }
 
 #[test]
@@ -41,7 +48,57 @@ fn test_map_type() {
     );
 }
 
+#[test]
+fn test_generic_type() {
+    assert_gen_type!("Vec[int]", vec![-1_isize], Vec<isize>);
+    assert_gen_type!(
+        "Vec[Vec[string]]",
+        vec![vec!["".to_owned()]],
+        Vec<Vec<String>>
+    );
+}
+
 #[test]
 fn test_parenthesized_type() {
     assert_gen_type!("(int)", -1_isize, isize);
 }
+
+#[test]
+fn test_qualified_type() {
+    assert_gen_type!("time.Duration", -1_000_000_000_i64, i64);
+    assert_gen_type!("[]net.IP", vec![IpAddr::from([127, 0, 0, 1])], Vec<IpAddr>);
+    assert_gen_type!(
+        "map[string]*time.Duration",
+        HashMap::new(),
+        HashMap<String, i64>
+    );
+}
+
+#[test]
+fn test_reexported_types() {
+    use golang_type::{chrono, num_bigint, serde_json};
+
+    assert_gen_type!("interface{}", serde_json::Value::Null, serde_json::Value);
+    assert_gen_type!(
+        "map[string]any",
+        serde_json::Map::new(),
+        serde_json::Map<String, serde_json::Value>
+    );
+    assert_gen_type!(
+        "time.Time",
+        chrono::DateTime::parse_from_rfc3339("2006-01-02T15:04:05Z").unwrap(),
+        chrono::DateTime<chrono::FixedOffset>
+    );
+    assert_gen_type!("*big.Int", num_bigint::BigInt::from(1), num_bigint::BigInt);
+}
+
+#[test]
+fn test_type_mappings() {
+    // See golang-rs.toml
+    assert_gen_type!("[]UserID", vec![1_u64], Vec<u64>);
+    assert_gen_type!(
+        "map[UserID]decimal.Decimal",
+        HashMap::new(),
+        HashMap<u64, String>
+    );
+}
@@ -1 +1,236 @@
+pub use golang_type_core;
 
+use std::str::{self, FromStr};
+
+use golang_parser::{tree_sitter::Node, Parser, Span, NODE_KIND_COMMENT};
+use golang_type_core::{Expression, Type, TypeParseError};
+
+#[cfg(feature = "enable-quote-to_tokens")]
+pub mod static_item;
+pub mod value;
+
+pub use self::value::{Value, ValueEvalError, VarEvaluator};
+
+// https://golang.org/ref/spec#Variable_declarations
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct VarDecl {
+    pub var_specs: Vec<VarSpec>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct VarSpec {
+    pub names: Vec<String>,
+    pub r#type: Option<Type>,
+    // Empty if only the type is given.
+    pub values: Vec<Expression>,
+    pub span: Span,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum VarDeclParseError {
+    #[error("GolangParserError {0:?}")]
+    GolangParserError(#[from] golang_parser::Error),
+    #[error("NodeMissing {0} at {1}")]
+    NodeMissing(String, Span),
+    #[error("NodeKindUnknown {0} at {1}")]
+    NodeKindUnknown(String, Span),
+    #[error("Utf8Error {0:?}")]
+    Utf8Error(#[from] str::Utf8Error),
+    //
+    #[error("TypeParseError {0:?}")]
+    TypeParseError(#[from] TypeParseError),
+}
+
+// An evaluated variable of a VarSpec.
+#[derive(PartialEq, Debug, Clone)]
+pub struct NamedVar {
+    pub name: String,
+    pub value: Value,
+    pub span: Span,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum VarDeclEvalError {
+    #[error("ExpressionCountMismatch {0} at {1}")]
+    ExpressionCountMismatch(String, Span),
+    #[error("ValueEvalError {1} of {0} at {2}")]
+    ValueEvalError(String, Box<ValueEvalError>, Span),
+}
+
+impl FromStr for VarDecl {
+    type Err = VarDeclParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new_strict(s)?;
+        let source = parser.get_source();
+        let root_node = parser.get_root_node();
+
+        let mut cursor = root_node.walk();
+        let mut node_source_file_named_children_iter = root_node
+            .named_children(&mut cursor)
+            .filter(|x| x.kind() != NODE_KIND_COMMENT);
+
+        let node_var_declaration =
+            node_source_file_named_children_iter.next().ok_or_else(|| {
+                VarDeclParseError::NodeMissing("var_declaration".to_string(), Span::from(root_node))
+            })?;
+        let var_decl = Self::from_var_declaration_node(node_var_declaration, source)?;
+
+        if let Some(node) = node_source_file_named_children_iter.next() {
+            return Err(VarDeclParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        Ok(var_decl)
+    }
+}
+
+impl VarDecl {
+    pub fn from_var_declaration_node(node: Node, source: &[u8]) -> Result<Self, VarDeclParseError> {
+        if node.kind() != "var_declaration" {
+            return Err(VarDeclParseError::NodeKindUnknown(
+                node.kind().to_owned(),
+                Span::from(node),
+            ));
+        }
+
+        let mut var_specs = vec![];
+        for node_var_spec in node
+            .named_children(&mut node.walk())
+            .filter(|x| x.kind() != NODE_KIND_COMMENT)
+        {
+            match node_var_spec.kind() {
+                "var_spec" => var_specs.push(VarSpec::from_var_spec_node(node_var_spec, source)?),
+                _ => {
+                    return Err(VarDeclParseError::NodeKindUnknown(
+                        node_var_spec.kind().to_owned(),
+                        Span::from(node_var_spec),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { var_specs })
+    }
+}
+
+impl VarDecl {
+    pub fn eval(&self) -> Result<Vec<NamedVar>, VarDeclEvalError> {
+        self.eval_with(&mut VarEvaluator::default())
+    }
+
+    // The variables are added to the evaluator, so that later declarations can refer to them.
+    pub fn eval_with(
+        &self,
+        evaluator: &mut VarEvaluator,
+    ) -> Result<Vec<NamedVar>, VarDeclEvalError> {
+        let mut named_vars = vec![];
+
+        for var_spec in &self.var_specs {
+            let values = if var_spec.values.is_empty() {
+                None
+            } else if var_spec.values.len() == var_spec.names.len() {
+                Some(&var_spec.values)
+            } else {
+                // e.g. `var a, b = f()`
+                return Err(VarDeclEvalError::ExpressionCountMismatch(
+                    var_spec.names.join(", "),
+                    var_spec.span,
+                ));
+            };
+
+            for (i, name) in var_spec.names.iter().enumerate() {
+                let value = match (values, &var_spec.r#type) {
+                    (Some(values), r#type) => {
+                        let value = evaluator.eval_as(&values[i], r#type.as_ref());
+                        // Untyped constants are converted to their default type, e.g. `int`.
+                        let value = value.and_then(|value| match value {
+                            Value::Constant(constant) if constant.r#type.is_none() => {
+                                let r#type = constant.type_or_default();
+                                Ok(Value::Constant(constant.convert(&r#type)?))
+                            }
+                            value => Ok(value),
+                        });
+                        value.map_err(|err| {
+                            VarDeclEvalError::ValueEvalError(
+                                name.to_owned(),
+                                err.into(),
+                                var_spec.span,
+                            )
+                        })?
+                    }
+                    (None, Some(r#type)) => Value::Zero(r#type.to_owned()),
+                    // Unreachable, a VarSpec has either a type or values.
+                    (None, None) => {
+                        return Err(VarDeclEvalError::ExpressionCountMismatch(
+                            name.to_owned(),
+                            var_spec.span,
+                        ))
+                    }
+                };
+
+                if name != "_" {
+                    if let Value::Constant(constant) = &value {
+                        // Allows simple arithmetic on the variables, e.g. `b = a * 2`.
+                        evaluator
+                            .constants
+                            .constants
+                            .insert(name.to_owned(), constant.to_owned());
+                    }
+                    evaluator.values.insert(name.to_owned(), value.to_owned());
+                }
+                named_vars.push(NamedVar {
+                    name: name.to_owned(),
+                    value,
+                    span: var_spec.span,
+                });
+            }
+        }
+
+        Ok(named_vars)
+    }
+}
+
+impl VarSpec {
+    fn from_var_spec_node(node: Node, source: &[u8]) -> Result<Self, VarDeclParseError> {
+        let mut names = vec![];
+        // tree-sitter 0.20 also yields the `,` between the names.
+        for node_name in node
+            .children_by_field_name("name", &mut node.walk())
+            .filter(|x| x.is_named())
+        {
+            names.push(node_name.utf8_text(source)?.to_owned());
+        }
+        if names.is_empty() {
+            return Err(VarDeclParseError::NodeMissing(
+                "var_spec name".to_string(),
+                Span::from(node),
+            ));
+        }
+
+        let r#type = if let Some(node_type) = node.child_by_field_name("type") {
+            Some(Type::from_node(node_type, source)?)
+        } else {
+            None
+        };
+
+        let mut values = vec![];
+        if let Some(node_value) = node.child_by_field_name("value") {
+            for node_expression in node_value
+                .named_children(&mut node_value.walk())
+                .filter(|x| x.kind() != NODE_KIND_COMMENT)
+            {
+                values.push(Expression::from_node(node_expression, source)?);
+            }
+        }
+
+        Ok(Self {
+            names,
+            r#type,
+            values,
+            span: Span::from(node),
+        })
+    }
+}
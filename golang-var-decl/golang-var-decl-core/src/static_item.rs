@@ -0,0 +1,489 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use convert_case::{Case, Casing as _};
+use golang_type_core::{
+    golang_type_name_core::TypeName, num_traits::ToPrimitive as _, ArrayLength, ArrayType,
+    ConstValue, Constant, MapType, ParenthesizedType, PointerType, SliceType, Type, TypeMappings,
+};
+use golang_type_decl_core::type_def::json_struct::{
+    JsonStruct, JsonStructFieldKind, JsonStructOption,
+};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt as _};
+
+use crate::{value::underlying_type, Value};
+
+// A Rust item holding the value of a Go package-level variable, e.g.
+// `var Names = []string{"a", "b"}` to `pub static NAMES: &[&str] = &["a", "b"];`.
+// Basic values are `pub const`, arrays, slices and maps (sorted by key) are borrowed `pub static`,
+// struct values are `once_cell::sync::Lazy` matching the types generated by `gen_json_struct!`.
+pub struct StaticItem {
+    // The Go identifier.
+    pub name: String,
+    pub value: Value,
+    // The declared types, e.g. the struct types of the composite literals.
+    pub types: HashMap<String, Type>,
+    pub opt: StaticItemOption,
+}
+
+#[derive(Default, Debug)]
+pub struct StaticItemOption {
+    pub alias_name: Option<String>,
+    // Maps are `Lazy<HashMap<K, V>>` instead of `&[(K, V)]`.
+    pub enable_lazy_map: bool,
+    // The options of `gen_json_struct!` the struct types are laid out by.
+    pub disable_fixed_size_array: bool,
+    pub enable_unexported_fields: bool,
+    // The Rust types of the types of other packages, e.g. `time.Time`.
+    pub type_mappings: TypeMappings,
+}
+
+impl ToTokens for StaticItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let item_name = format_ident!(
+            "{}",
+            self.opt
+                .alias_name
+                .to_owned()
+                .unwrap_or_else(|| self.name.to_case(Case::UpperSnake))
+        );
+
+        let token = self.item_tokens(&item_name).unwrap_or_else(|err| {
+            let err = format!("{} of {}", err, self.name);
+            quote!(compile_error!(#err);)
+        });
+        tokens.append_all(token);
+    }
+}
+
+impl StaticItem {
+    fn item_tokens(&self, item_name: &Ident) -> Result<TokenStream, String> {
+        let r#type = self.value.r#type();
+
+        if self.is_owned(&self.value) {
+            let r#type = self.array_type(r#type);
+            let expr = self.owned_expr(&self.value, &r#type, &[])?;
+            let r#type = r#type.to_token_stream_with(&self.opt.type_mappings);
+            return Ok(quote! {
+                pub static #item_name: ::once_cell::sync::Lazy<#r#type> =
+                    ::once_cell::sync::Lazy::new(|| #expr);
+            });
+        }
+
+        let item_type = self.borrowed_type(&r#type)?;
+        let expr = self.borrowed_expr(&self.value)?;
+        match self.underlying_type(&r#type) {
+            Type::TypeName(_) => Ok(quote! {
+                pub const #item_name: #item_type = #expr;
+            }),
+            _ => Ok(quote! {
+                pub static #item_name: #item_type = #expr;
+            }),
+        }
+    }
+
+    // Struct values require allocations, e.g. `String` fields.
+    fn is_owned(&self, value: &Value) -> bool {
+        match value {
+            Value::Constant(_) => false,
+            Value::Zero(r#type) => match self.underlying_type(r#type) {
+                Type::StructType(_) => true,
+                Type::MapType(_) => self.opt.enable_lazy_map,
+                _ => false,
+            },
+            Value::List(_, values) => values.iter().any(|value| self.is_owned(value)),
+            Value::Map(_, entries) => {
+                self.opt.enable_lazy_map
+                    || entries
+                        .iter()
+                        .any(|(key, value)| self.is_owned(key) || self.is_owned(value))
+            }
+            Value::Struct(_, _) => true,
+            Value::Address(element) => self.is_owned(element),
+        }
+    }
+
+    fn borrowed_type(&self, r#type: &Type) -> Result<TokenStream, String> {
+        let token = match self.underlying_type(r#type) {
+            Type::TypeName(TypeName::String) => quote!(&'static str),
+            underlying_type if is_duration(underlying_type) => quote!(::core::time::Duration),
+            Type::TypeName(TypeName::QualifiedIdent(_, _))
+            | Type::TypeName(TypeName::Identifier(_)) => {
+                return Err(format!("type {} unsupported", r#type))
+            }
+            Type::TypeName(type_name) => quote!(#type_name),
+            Type::SliceType(SliceType { element }) => {
+                let element = self.borrowed_type(element)?;
+                quote!(&'static [#element])
+            }
+            Type::ArrayType(ArrayType {
+                length: ArrayLength::IntLiteral(length),
+                element,
+            }) => {
+                let element = self.borrowed_type(element)?;
+                quote!([#element; #length])
+            }
+            Type::MapType(MapType { key, value }) => {
+                let key = self.borrowed_type(key)?;
+                let value = self.borrowed_type(value)?;
+                quote!(&'static [(#key, #value)])
+            }
+            Type::PointerType(PointerType(element)) => self.borrowed_type(element)?,
+            _ => return Err(format!("type {} unsupported", r#type)),
+        };
+
+        Ok(token)
+    }
+
+    fn borrowed_expr(&self, value: &Value) -> Result<TokenStream, String> {
+        let token = match value {
+            Value::Constant(constant) => self.constant_expr(constant, false)?,
+            Value::Zero(r#type) => self.borrowed_zero_expr(r#type)?,
+            Value::List(r#type, values) => {
+                let values = values
+                    .iter()
+                    .map(|value| self.borrowed_expr(value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match self.underlying_type(r#type) {
+                    Type::ArrayType(_) => quote!([#(#values),*]),
+                    _ => quote!(&[#(#values),*]),
+                }
+            }
+            Value::Map(_, entries) => {
+                // Sorted by key, so that it can be looked up by `binary_search_by_key`.
+                let mut entries: Vec<_> = entries.iter().collect();
+                entries.sort_by(|(a, _), (b, _)| {
+                    match (a.as_constant_value(), b.as_constant_value()) {
+                        (Some(a), Some(b)) => compare_const_values(a, b),
+                        _ => Ordering::Equal,
+                    }
+                });
+                let entries = entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key = self.borrowed_expr(key)?;
+                        let value = self.borrowed_expr(value)?;
+                        Ok(quote!((#key, #value)))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                quote!(&[#(#entries),*])
+            }
+            Value::Struct(r#type, _) => return Err(format!("struct {} unsupported", r#type)),
+            Value::Address(element) => self.borrowed_expr(element)?,
+        };
+
+        Ok(token)
+    }
+
+    fn borrowed_zero_expr(&self, r#type: &Type) -> Result<TokenStream, String> {
+        let token = match self.underlying_type(r#type) {
+            Type::TypeName(TypeName::String) => quote!(""),
+            Type::TypeName(TypeName::Bool) => quote!(false),
+            Type::TypeName(TypeName::Float32) | Type::TypeName(TypeName::Float64) => quote!(0.0),
+            underlying_type if is_duration(underlying_type) => {
+                quote!(::core::time::Duration::from_nanos(0))
+            }
+            Type::TypeName(TypeName::QualifiedIdent(_, _))
+            | Type::TypeName(TypeName::Identifier(_)) => {
+                return Err(format!("type {} unsupported", r#type))
+            }
+            Type::TypeName(_) => quote!(0),
+            Type::SliceType(_) | Type::MapType(_) => quote!(&[]),
+            Type::ArrayType(ArrayType {
+                length: ArrayLength::IntLiteral(length),
+                element,
+            }) => {
+                let element = self.borrowed_zero_expr(element)?;
+                quote!([#element; #length])
+            }
+            _ => return Err(format!("zero value of {} unsupported", r#type)),
+        };
+
+        Ok(token)
+    }
+
+    // Values are converted by the Rust types of the struct fields, e.g. `Vec` and `HashMap`,
+    // the anonymous struct types of which are the hoisted `nested_json_structs`.
+    fn owned_expr(
+        &self,
+        value: &Value,
+        r#type: &Type,
+        nested_json_structs: &[JsonStruct],
+    ) -> Result<TokenStream, String> {
+        let r#type = match r#type {
+            Type::TypeName(TypeName::Identifier(name))
+                if !nested_json_structs.iter().any(|x| &x.name == name) =>
+            {
+                match self.underlying_type(r#type) {
+                    Type::StructType(_) => r#type.to_owned(),
+                    underlying_type => self.array_type(underlying_type.to_owned()),
+                }
+            }
+            Type::PointerType(PointerType(element))
+            | Type::ParenthesizedType(ParenthesizedType(element)) => {
+                return self.owned_expr(value, element, nested_json_structs)
+            }
+            _ => r#type.to_owned(),
+        };
+
+        let token = match value {
+            Value::Constant(constant) => self.constant_expr(constant, true)?,
+            Value::Zero(_) => quote!(::core::default::Default::default()),
+            Value::List(list_type, values) => {
+                // Like the array types, `[T; N]` if the length is known, else `Vec<T>`.
+                let (element, is_array) = match &r#type {
+                    Type::ArrayType(ArrayType {
+                        length: ArrayLength::IntLiteral(_),
+                        element,
+                    }) => (element.as_ref().to_owned(), true),
+                    Type::ArrayType(ArrayType { element, .. })
+                    | Type::SliceType(SliceType { element }) => {
+                        (element.as_ref().to_owned(), false)
+                    }
+                    _ => return Err(format!("list of {} unsupported", list_type)),
+                };
+                let values = values
+                    .iter()
+                    .map(|value| self.owned_expr(value, &element, nested_json_structs))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if is_array {
+                    quote!([#(#values),*])
+                } else {
+                    quote!(::std::vec![#(#values),*])
+                }
+            }
+            Value::Map(map_type, entries) => {
+                let (key_type, value_type) = match &r#type {
+                    Type::MapType(MapType { key, value }) => (key, value),
+                    _ => return Err(format!("map of {} unsupported", map_type)),
+                };
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| {
+                        let key = self.owned_expr(key, key_type, nested_json_structs)?;
+                        let value = self.owned_expr(value, value_type, nested_json_structs)?;
+                        Ok(quote!((#key, #value)))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                quote!(::core::iter::FromIterator::from_iter(
+                    ::std::vec![#(#entries),*]
+                ))
+            }
+            Value::Struct(struct_type, fields) => {
+                let name = match &r#type {
+                    Type::TypeName(TypeName::Identifier(name)) => name,
+                    _ => return Err(format!("struct type {} not found", struct_type)),
+                };
+                match nested_json_structs.iter().find(|x| &x.name == name) {
+                    Some(json_struct) => self.owned_struct_expr(json_struct, fields)?,
+                    None => {
+                        let json_struct = self
+                            .json_struct(name)
+                            .ok_or_else(|| format!("struct type {} not found", struct_type))?;
+                        self.owned_struct_expr(&json_struct, fields)?
+                    }
+                }
+            }
+            Value::Address(element) => self.owned_expr(element, &r#type, nested_json_structs)?,
+        };
+
+        Ok(token)
+    }
+
+    // The fields are the ones of `gen_json_struct!`, the values of which are wrapped by
+    // `golang_var_decl::FieldValue`, e.g. `Option<Box<T>>` of the recursive pointers.
+    fn owned_struct_expr(
+        &self,
+        json_struct: &JsonStruct,
+        fields: &[(Option<String>, Value)],
+    ) -> Result<TokenStream, String> {
+        let layout = json_struct.layout();
+
+        let mut struct_fields = vec![];
+        for field in &layout.fields {
+            let value = fields
+                .iter()
+                .find(|(field_name, _)| field_name.as_ref() == Some(&field.name))
+                .map(|(_, value)| value);
+
+            let expr = match (value, &field.kind) {
+                // e.g. nil to `None`.
+                (None, _) | (Some(Value::Zero(_)), _) => {
+                    let field_name = field.ident();
+                    struct_fields.push(quote!(#field_name: ::core::default::Default::default()));
+                    continue;
+                }
+                // `json:",string"` fields are `String`.
+                (Some(Value::Constant(Constant { value, .. })), _) if field.is_string() => {
+                    let s = match value {
+                        ConstValue::String(_) => utf8_str(value)?.to_owned(),
+                        _ => value.to_string(),
+                    };
+                    quote!(::std::string::String::from(#s))
+                }
+                // The copy of the embedded struct, e.g. `ServerBackend`.
+                (
+                    Some(value),
+                    JsonStructFieldKind::Flattened {
+                        copy_name: Some(copy_name),
+                        ..
+                    },
+                ) => {
+                    let copy = layout
+                        .nested_json_structs
+                        .iter()
+                        .find(|x| &x.name == copy_name)
+                        .ok_or_else(|| format!("struct type {} not found", copy_name))?;
+                    let mut value = value;
+                    while let Value::Address(element) = value {
+                        value = element;
+                    }
+                    match value {
+                        // Without the fields hidden by the dominant ones.
+                        Value::Struct(_, fields) => {
+                            let copy_field_names: Vec<_> =
+                                copy.layout().fields.into_iter().map(|x| x.name).collect();
+                            let fields: Vec<_> = fields
+                                .iter()
+                                .filter(|(field_name, _)| match field_name {
+                                    Some(field_name) => copy_field_names.contains(field_name),
+                                    None => true,
+                                })
+                                .cloned()
+                                .collect();
+                            self.owned_struct_expr(copy, &fields)?
+                        }
+                        _ => return Err(format!("value of {} unsupported", field.name)),
+                    }
+                }
+                (Some(value), _) => {
+                    self.owned_expr(value, &field.value_type(), &layout.nested_json_structs)?
+                }
+            };
+            let value_type = field.value_type_token();
+            let expr = quote!(<_ as ::golang_var_decl::FieldValue<#value_type>>::from_value(#expr));
+
+            let field_name = field.ident();
+            struct_fields.push(quote!(#field_name: #expr));
+        }
+
+        for (field_name, _) in fields {
+            match field_name {
+                Some(field_name) if layout.fields.iter().any(|x| &x.name == field_name) => {}
+                Some(field_name) => {
+                    return Err(format!(
+                        "field {} not found in {}",
+                        field_name, json_struct.name
+                    ))
+                }
+                None => return Err(format!("too many values in {}", json_struct.name)),
+            }
+        }
+
+        let struct_name = format_ident!("{}", layout.struct_name);
+        Ok(quote!(#struct_name { #(#struct_fields),* }))
+    }
+
+    // The struct types of the declared types, laid out like the ones of `gen_json_struct!`.
+    fn json_struct(&self, name: &str) -> Option<JsonStruct> {
+        let r#type = Type::TypeName(TypeName::Identifier(name.to_owned()));
+        match self.underlying_type(&r#type) {
+            Type::StructType(struct_type) => Some(JsonStruct {
+                name: name.to_owned(),
+                type_params: vec![],
+                struct_type: struct_type.to_owned(),
+                opt: JsonStructOption {
+                    enable_fixed_size_array: !self.opt.disable_fixed_size_array,
+                    type_mappings: self.opt.type_mappings.to_owned(),
+                    types: self.types.to_owned(),
+                    enable_unexported_fields: self.opt.enable_unexported_fields,
+                    ..Default::default()
+                },
+                field_opts: HashMap::new(),
+            }),
+            _ => None,
+        }
+    }
+
+    // e.g. `Vec<u8>` of `[16]byte` with `disable_fixed_size_array`.
+    fn array_type(&self, r#type: Type) -> Type {
+        if self.opt.disable_fixed_size_array {
+            r#type.array_to_slice()
+        } else {
+            r#type
+        }
+    }
+
+    fn constant_expr(&self, constant: &Constant, is_owned: bool) -> Result<TokenStream, String> {
+        let token = match &constant.value {
+            ConstValue::Bool(v) => quote!(#v),
+            ConstValue::Int(v) | ConstValue::Rune(v) => {
+                // The owned ones are typed by `TypeMappings`, i.e. `i64` nanoseconds.
+                if !is_owned && is_duration(self.underlying_type(&constant.type_or_default())) {
+                    let v = constant
+                        .value
+                        .to_u64()
+                        .ok_or_else(|| format!("negative duration {} unsupported", v))?;
+                    quote!(::core::time::Duration::from_nanos(#v))
+                } else {
+                    let v = v
+                        .to_i128()
+                        .ok_or_else(|| format!("integer {} overflows i128", v))?;
+                    let v = Literal::i128_unsuffixed(v);
+                    quote!(#v)
+                }
+            }
+            ConstValue::Float(v) => {
+                let v = constant
+                    .value
+                    .to_f64()
+                    .ok_or_else(|| format!("float {} overflows f64", v))?;
+                let v = Literal::f64_unsuffixed(v);
+                quote!(#v)
+            }
+            ConstValue::String(_) if is_owned => {
+                let v = utf8_str(&constant.value)?;
+                quote!(::std::string::String::from(#v))
+            }
+            ConstValue::String(_) => {
+                let v = utf8_str(&constant.value)?;
+                quote!(#v)
+            }
+        };
+
+        Ok(token)
+    }
+
+    fn underlying_type<'a>(&'a self, r#type: &'a Type) -> &'a Type {
+        underlying_type(&self.types, r#type)
+    }
+}
+
+// https://pkg.go.dev/time#Duration
+fn is_duration(r#type: &Type) -> bool {
+    match r#type {
+        Type::TypeName(TypeName::QualifiedIdent(package_name, name)) => {
+            package_name == "time" && name == "Duration"
+        }
+        _ => false,
+    }
+}
+
+// Rust strings are UTF-8, unlike Go strings, e.g. `"\xff"`.
+fn utf8_str(value: &ConstValue) -> Result<&str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("string {} is not UTF-8", value))
+}
+
+fn compare_const_values(a: &ConstValue, b: &ConstValue) -> Ordering {
+    match (a, b) {
+        (ConstValue::String(a), ConstValue::String(b)) => a.cmp(b),
+        (ConstValue::Int(a), ConstValue::Int(b)) | (ConstValue::Rune(a), ConstValue::Rune(b)) => {
+            a.cmp(b)
+        }
+        (ConstValue::Float(a), ConstValue::Float(b)) => a.cmp(b),
+        (ConstValue::Bool(a), ConstValue::Bool(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
@@ -0,0 +1,291 @@
+use std::{collections::HashMap, convert::TryFrom};
+
+use golang_type_core::{
+    golang_type_name_core::TypeName, ArrayLength, ArrayType, CompositeLit, ConstEvalError,
+    ConstEvaluator, ConstValue, Constant, Expression, MapType, ParenthesizedType, PointerType,
+    SliceType, StructField, Type, UnaryOperator,
+};
+
+// The value of a variable initialized with constants and composite literals.
+// https://golang.org/ref/spec#Variables
+#[derive(PartialEq, Debug, Clone)]
+pub enum Value {
+    Constant(Constant),
+    // The zero value of the type, e.g. `nil` or a var declared without an expression.
+    Zero(Type),
+    // Arrays and slices, e.g. `[]string{"a", "b"}`.
+    List(Type, Vec<Value>),
+    // In source order, e.g. `map[string]int{"a": 1}`.
+    Map(Type, Vec<(Value, Value)>),
+    // The field names are None if unkeyed and the struct type is unknown, e.g. `Point{1, 2}`.
+    Struct(Type, Vec<(Option<String>, Value)>),
+    // e.g. `&Config{}`
+    Address(Box<Value>),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ValueEvalError {
+    #[error("ConstEvalError {0}")]
+    ConstEvalError(#[from] ConstEvalError),
+    #[error("TypeMissing {0}")]
+    TypeMissing(String),
+    #[error("KeyInvalid {0}")]
+    KeyInvalid(String),
+    #[error("Unsupported {0}")]
+    Unsupported(String),
+}
+
+// Evaluates the expressions of var declarations.
+#[derive(Debug, Clone)]
+pub struct VarEvaluator {
+    pub constants: ConstEvaluator,
+    // The declared types, used to resolve the underlying types of composite literals.
+    // The ones missing are assumed to be struct types.
+    pub types: HashMap<String, Type>,
+    // The evaluated variables.
+    pub values: HashMap<String, Value>,
+}
+
+impl Default for VarEvaluator {
+    fn default() -> Self {
+        Self {
+            constants: ConstEvaluator::with_std_constants(),
+            types: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl VarEvaluator {
+    pub fn eval(&self, expression: &Expression) -> Result<Value, ValueEvalError> {
+        self.eval_as(expression, None)
+    }
+
+    // The type is the declared one, or the element type of the enclosing composite literal.
+    pub fn eval_as(
+        &self,
+        expression: &Expression,
+        r#type: Option<&Type>,
+    ) -> Result<Value, ValueEvalError> {
+        match expression {
+            Expression::CompositeLit(composite_lit) => {
+                let mut composite_type = match (&composite_lit.r#type, r#type) {
+                    (Some(composite_type), _) => composite_type.to_owned(),
+                    (None, Some(r#type)) => match self.underlying_type(r#type) {
+                        // `[]*Point{{1, 2}}` is short for `[]*Point{&Point{1, 2}}`.
+                        Type::PointerType(PointerType(element)) => {
+                            return Ok(Value::Address(
+                                self.eval_composite_lit(composite_lit, element)?.into(),
+                            ));
+                        }
+                        _ => r#type.to_owned(),
+                    },
+                    (None, None) => {
+                        return Err(ValueEvalError::TypeMissing(expression.to_string()))
+                    }
+                };
+                // e.g. `[Size]byte{}`
+                composite_type.resolve_array_lengths(&self.constants);
+                self.eval_composite_lit(composite_lit, &composite_type)
+            }
+            Expression::Unary(UnaryOperator::Address, operand) => {
+                let r#type = r#type.and_then(|r#type| match self.underlying_type(r#type) {
+                    Type::PointerType(PointerType(element)) => Some(element.as_ref()),
+                    _ => None,
+                });
+                Ok(Value::Address(self.eval_as(operand, r#type)?.into()))
+            }
+            Expression::Identifier(name) if name == "nil" => match r#type {
+                Some(r#type) => Ok(Value::Zero(r#type.to_owned())),
+                None => Err(ValueEvalError::TypeMissing(expression.to_string())),
+            },
+            Expression::Identifier(name) if self.values.contains_key(name) => {
+                Ok(self.values[name].to_owned())
+            }
+            Expression::Parenthesized(element) => self.eval_as(element, r#type),
+            _ => {
+                let constant = self.constants.eval(expression)?;
+                let constant = match r#type {
+                    // Keeps the default type as the dynamic type, e.g. `[]interface{}{1}`.
+                    Some(r#type)
+                        if r#type
+                            .is_empty_interface_with(&|name| self.types.contains_key(name)) =>
+                    {
+                        constant
+                    }
+                    Some(r#type) => constant.convert(r#type)?,
+                    None => constant,
+                };
+                Ok(Value::Constant(constant))
+            }
+        }
+    }
+
+    // https://golang.org/ref/spec#Composite_literals
+    fn eval_composite_lit(
+        &self,
+        composite_lit: &CompositeLit,
+        r#type: &Type,
+    ) -> Result<Value, ValueEvalError> {
+        let key_invalid = || ValueEvalError::KeyInvalid(composite_lit.to_string());
+
+        match self.underlying_type(r#type) {
+            Type::ArrayType(ArrayType { element, length }) => {
+                let mut values = self.eval_list_elements(composite_lit, element)?;
+                let length = match length {
+                    // The length of `[...]T` is the number of elements.
+                    ArrayLength::Implicit => values.len(),
+                    _ => length
+                        .eval(&self.constants)
+                        .ok_or_else(|| ValueEvalError::Unsupported(r#type.to_string()))?,
+                };
+                if values.len() > length {
+                    return Err(key_invalid());
+                }
+                values.resize(length, Value::Zero(element.as_ref().to_owned()));
+                let r#type = match self.underlying_type(r#type) {
+                    Type::ArrayType(ArrayType {
+                        length: ArrayLength::IntLiteral(_),
+                        ..
+                    }) => r#type.to_owned(),
+                    _ => Type::ArrayType(ArrayType {
+                        length: ArrayLength::IntLiteral(length),
+                        element: element.to_owned(),
+                    }),
+                };
+                Ok(Value::List(r#type, values))
+            }
+            Type::SliceType(SliceType { element }) => Ok(Value::List(
+                r#type.to_owned(),
+                self.eval_list_elements(composite_lit, element)?,
+            )),
+            Type::MapType(MapType { key, value }) => {
+                let mut entries = vec![];
+                for element in &composite_lit.elements {
+                    let element_key = element.key.as_ref().ok_or_else(key_invalid)?;
+                    entries.push((
+                        self.eval_as(element_key, Some(key))?,
+                        self.eval_as(&element.value, Some(value))?,
+                    ));
+                }
+                Ok(Value::Map(r#type.to_owned(), entries))
+            }
+            Type::StructType(_) | Type::TypeName(_) => {
+                let field_types = self.struct_field_types(r#type);
+
+                let mut fields = vec![];
+                for (i, element) in composite_lit.elements.iter().enumerate() {
+                    let name = match &element.key {
+                        Some(Expression::Identifier(name)) => Some(name.to_owned()),
+                        Some(_) => return Err(key_invalid()),
+                        None => field_types
+                            .as_ref()
+                            .and_then(|field_types| field_types.get(i))
+                            .map(|(name, _)| name.to_owned()),
+                    };
+                    let field_type = field_types.as_ref().and_then(|field_types| {
+                        field_types
+                            .iter()
+                            .find(|(field_name, _)| Some(field_name) == name.as_ref())
+                            .map(|(_, field_type)| field_type)
+                    });
+                    fields.push((name, self.eval_as(&element.value, field_type)?));
+                }
+                Ok(Value::Struct(r#type.to_owned(), fields))
+            }
+            _ => Err(ValueEvalError::Unsupported(composite_lit.to_string())),
+        }
+    }
+
+    // Elements may be indexed, e.g. `[...]string{2: "c", 0: "a"}`, the others are zero values.
+    fn eval_list_elements(
+        &self,
+        composite_lit: &CompositeLit,
+        element_type: &Type,
+    ) -> Result<Vec<Value>, ValueEvalError> {
+        let mut values: Vec<Value> = vec![];
+
+        let mut index = 0;
+        for element in &composite_lit.elements {
+            if let Some(key) = &element.key {
+                index = self
+                    .constants
+                    .eval(key)
+                    .ok()
+                    .and_then(|constant| constant.value.to_u64())
+                    .and_then(|index| usize::try_from(index).ok())
+                    .ok_or_else(|| ValueEvalError::KeyInvalid(key.to_string()))?;
+            }
+            if values.len() <= index {
+                values.resize(index + 1, Value::Zero(element_type.to_owned()));
+            }
+            values[index] = self.eval_as(&element.value, Some(element_type))?;
+            index += 1;
+        }
+
+        Ok(values)
+    }
+
+    // The field names and types in order, the embedded fields are named by their type.
+    fn struct_field_types(&self, r#type: &Type) -> Option<Vec<(String, Type)>> {
+        match self.underlying_type(r#type) {
+            Type::StructType(struct_type) => Some(
+                struct_type
+                    .field_decls
+                    .iter()
+                    .flat_map(|field_decl| match &field_decl.struct_field {
+                        StructField::IdentifierListType(names, r#type) => names
+                            .iter()
+                            .map(|name| (name.to_owned(), r#type.as_ref().to_owned()))
+                            .collect(),
+                        StructField::EmbeddedField(embedded_field) => {
+                            vec![(embedded_field.name(), embedded_field.r#type())]
+                        }
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn underlying_type<'a>(&'a self, r#type: &'a Type) -> &'a Type {
+        underlying_type(&self.types, r#type)
+    }
+}
+
+impl Value {
+    pub fn r#type(&self) -> Type {
+        match self {
+            Self::Constant(constant) => constant.type_or_default(),
+            Self::Zero(r#type)
+            | Self::List(r#type, _)
+            | Self::Map(r#type, _)
+            | Self::Struct(r#type, _) => r#type.to_owned(),
+            Self::Address(element) => Type::PointerType(PointerType(element.r#type().into())),
+        }
+    }
+
+    pub fn as_constant_value(&self) -> Option<&ConstValue> {
+        match self {
+            Self::Constant(constant) => Some(&constant.value),
+            _ => None,
+        }
+    }
+}
+
+// Follows the declared types, e.g. `type Names []string`.
+pub(crate) fn underlying_type<'a>(types: &'a HashMap<String, Type>, r#type: &'a Type) -> &'a Type {
+    let mut r#type = r#type;
+    // Bounded in case of invalid recursive declarations.
+    for _ in 0..=types.len() {
+        r#type = match r#type {
+            Type::TypeName(TypeName::Identifier(name)) => match types.get(name) {
+                Some(underlying_type) => underlying_type,
+                None => break,
+            },
+            Type::ParenthesizedType(ParenthesizedType(element)) => element,
+            _ => break,
+        };
+    }
+    r#type
+}
@@ -0,0 +1,255 @@
+use std::error;
+
+use golang_var_decl_core::{
+    golang_type_core::{
+        ArrayLength, ArrayType, BasicLit, CompositeLit, ConstValue, Constant, Expression,
+        KeyedElement, MapType, PointerType, SliceType, Type, TypeName,
+    },
+    NamedVar, Value, VarDecl, VarEvaluator, VarSpec,
+};
+
+#[test]
+fn test_parse() -> Result<(), Box<dyn error::Error>> {
+    let var_decl: VarDecl = r#"
+var (
+	a []string
+	b, c = 1, 2
+)
+"#
+    .parse()?;
+
+    assert_eq!(
+        var_decl,
+        VarDecl {
+            var_specs: vec![
+                VarSpec {
+                    names: vec!["a".to_owned()],
+                    r#type: Some(Type::SliceType(SliceType {
+                        element: Type::TypeName(TypeName::String).into()
+                    })),
+                    values: vec![],
+                    span: Default::default(),
+                },
+                VarSpec {
+                    names: vec!["b".to_owned(), "c".to_owned()],
+                    r#type: None,
+                    values: vec![
+                        Expression::BasicLit(BasicLit::Int("1".to_owned())),
+                        Expression::BasicLit(BasicLit::Int("2".to_owned()))
+                    ],
+                    span: Default::default(),
+                },
+            ]
+        }
+    );
+
+    assert!("const a = 1".parse::<VarDecl>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_composite_lit() -> Result<(), Box<dyn error::Error>> {
+    let var_decl: VarDecl = r#"var cfg = Config{Port: 8080, Hosts: []string{"a"}}"#.parse()?;
+
+    assert_eq!(
+        var_decl.var_specs[0].values,
+        vec![Expression::CompositeLit(CompositeLit {
+            r#type: Some(Type::TypeName(TypeName::Identifier("Config".to_owned()))),
+            elements: vec![
+                KeyedElement {
+                    key: Some(Expression::Identifier("Port".to_owned())),
+                    value: Expression::BasicLit(BasicLit::Int("8080".to_owned())),
+                },
+                KeyedElement {
+                    key: Some(Expression::Identifier("Hosts".to_owned())),
+                    value: Expression::CompositeLit(CompositeLit {
+                        r#type: Some(Type::SliceType(SliceType {
+                            element: Type::TypeName(TypeName::String).into()
+                        })),
+                        elements: vec![KeyedElement {
+                            key: None,
+                            value: Expression::BasicLit(BasicLit::String(r#""a""#.to_owned())),
+                        }],
+                    }),
+                },
+            ],
+        })]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_eval() -> Result<(), Box<dyn error::Error>> {
+    let var_decl: VarDecl = r#"
+var (
+	DefaultTimeout = 30 * time.Second
+	Names          = []string{"a", "b"}
+	cfg            = Config{Port: 8080}
+	Retries, Max   = 3, Retries * 2
+	empty          []int
+)
+"#
+    .parse()?;
+
+    let string = || Type::TypeName(TypeName::String);
+    let int = |v: i128| {
+        Value::Constant(Constant {
+            value: ConstValue::Int(v.into()),
+            r#type: Some(Type::TypeName(TypeName::Int)),
+        })
+    };
+    let string_slice = Type::SliceType(SliceType {
+        element: string().into(),
+    });
+
+    assert_eq!(
+        var_decl.eval()?,
+        vec![
+            NamedVar {
+                name: "DefaultTimeout".to_owned(),
+                value: Value::Constant(Constant {
+                    value: ConstValue::Int(30_000_000_000_i64.into()),
+                    r#type: Some(Type::TypeName(TypeName::QualifiedIdent(
+                        "time".to_owned(),
+                        "Duration".to_owned()
+                    ))),
+                }),
+                span: Default::default(),
+            },
+            NamedVar {
+                name: "Names".to_owned(),
+                value: Value::List(
+                    string_slice,
+                    vec![
+                        Value::Constant(Constant {
+                            value: ConstValue::String("a".into()),
+                            r#type: Some(string()),
+                        }),
+                        Value::Constant(Constant {
+                            value: ConstValue::String("b".into()),
+                            r#type: Some(string()),
+                        }),
+                    ]
+                ),
+                span: Default::default(),
+            },
+            NamedVar {
+                name: "cfg".to_owned(),
+                value: Value::Struct(
+                    Type::TypeName(TypeName::Identifier("Config".to_owned())),
+                    vec![(
+                        Some("Port".to_owned()),
+                        Value::Constant(Constant::untyped(ConstValue::Int(8080.into())))
+                    )]
+                ),
+                span: Default::default(),
+            },
+            NamedVar {
+                name: "Retries".to_owned(),
+                value: int(3),
+                span: Default::default(),
+            },
+            NamedVar {
+                name: "Max".to_owned(),
+                value: int(6),
+                span: Default::default(),
+            },
+            NamedVar {
+                name: "empty".to_owned(),
+                value: Value::Zero(Type::SliceType(SliceType {
+                    element: Type::TypeName(TypeName::Int).into()
+                })),
+                span: Default::default(),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_with_types() -> Result<(), Box<dyn error::Error>> {
+    let mut evaluator = VarEvaluator::default();
+    evaluator
+        .types
+        .insert("Point".to_owned(), "struct { X, Y int }".parse()?);
+    evaluator.constants.constants.insert(
+        "Two".to_owned(),
+        Constant::untyped(ConstValue::Int(2.into())),
+    );
+
+    let var_decl: VarDecl = r#"
+var (
+	points = []*Point{{1, Two}}
+	names  = [...]string{2: "c", 0: "a"}
+	ports  = map[string]int{"http": 80}
+)
+"#
+    .parse()?;
+    let named_vars = var_decl.eval_with(&mut evaluator)?;
+
+    let int = |v: i128| {
+        Value::Constant(Constant {
+            value: ConstValue::Int(v.into()),
+            r#type: Some(Type::TypeName(TypeName::Int)),
+        })
+    };
+    let string = |v: &str| {
+        Value::Constant(Constant {
+            value: ConstValue::String(v.into()),
+            r#type: Some(Type::TypeName(TypeName::String)),
+        })
+    };
+    let point = Type::TypeName(TypeName::Identifier("Point".to_owned()));
+
+    assert_eq!(
+        named_vars[0].value,
+        Value::List(
+            Type::SliceType(SliceType {
+                element: Type::PointerType(PointerType(point.to_owned().into())).into()
+            }),
+            vec![Value::Address(
+                Value::Struct(
+                    point,
+                    vec![
+                        (Some("X".to_owned()), int(1)),
+                        (Some("Y".to_owned()), int(2))
+                    ]
+                )
+                .into()
+            )]
+        )
+    );
+    assert_eq!(
+        named_vars[1].value,
+        Value::List(
+            Type::ArrayType(ArrayType {
+                length: ArrayLength::IntLiteral(3),
+                element: Type::TypeName(TypeName::String).into()
+            }),
+            vec![
+                string("a"),
+                Value::Zero(Type::TypeName(TypeName::String)),
+                string("c")
+            ]
+        )
+    );
+    assert_eq!(
+        named_vars[2].value,
+        Value::Map(
+            Type::MapType(MapType {
+                key: Type::TypeName(TypeName::String).into(),
+                value: Type::TypeName(TypeName::Int).into()
+            }),
+            vec![(string("http"), int(80))]
+        )
+    );
+    assert_eq!(evaluator.values.get("ports"), Some(&named_vars[2].value));
+
+    assert!(r#"var a = Point{X: nil}"#.parse::<VarDecl>()?.eval().is_err());
+    assert!("var a, b = f()".parse::<VarDecl>()?.eval().is_err());
+
+    Ok(())
+}
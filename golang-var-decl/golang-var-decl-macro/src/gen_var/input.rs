@@ -0,0 +1,83 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Error as SynError, Ident, LitBool, LitInt, LitStr, Token,
+};
+
+use golang_macro_utils::path_to_code;
+
+pub struct Input {
+    pub code: String,
+    pub nth: usize,
+    pub name: Option<String>,
+    //
+    pub alias_name: Option<String>,
+    pub enable_lazy_map: bool,
+    pub disable_fixed_size_array: bool,
+    pub enable_unexported_fields: bool,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self, SynError> {
+        let mut code = String::new();
+        let mut nth = 0;
+        let mut name = None;
+
+        let mut alias_name = None;
+        let mut enable_lazy_map = false;
+        let mut disable_fixed_size_array = false;
+        let mut enable_unexported_fields = false;
+
+        while !input.is_empty() {
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            if key == "code" {
+                let s = input.parse::<LitStr>()?.value();
+                input.parse::<Token![,]>()?;
+
+                code = s.trim_start().trim_end().to_owned();
+            } else if key == "path" {
+                let s = input.parse::<LitStr>()?.value();
+                input.parse::<Token![,]>()?;
+
+                match path_to_code(&s) {
+                    Ok(s) => code = s,
+                    Err(err) => {
+                        return Err(SynError::new_spanned(key, err));
+                    }
+                }
+            } else if key == "nth" {
+                nth = input.parse::<LitInt>()?.base10_parse::<usize>()?;
+                input.parse::<Token![,]>()?;
+            } else if key == "name" {
+                name = Some(input.parse::<LitStr>()?.value());
+                input.parse::<Token![,]>()?;
+            } else if key == "alias_name" {
+                alias_name = Some(input.parse::<LitStr>()?.value());
+                input.parse::<Token![,]>()?;
+            } else if key == "enable_lazy_map" {
+                enable_lazy_map = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else if key == "disable_fixed_size_array" {
+                disable_fixed_size_array = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else if key == "enable_unexported_fields" {
+                enable_unexported_fields = input.parse::<LitBool>()?.value();
+                input.parse::<Token![,]>()?;
+            } else {
+                let err = format!("unexpected input key: {}", key);
+                return Err(SynError::new_spanned(key, err));
+            }
+        }
+
+        Ok(Self {
+            code,
+            nth,
+            name,
+            alias_name,
+            enable_lazy_map,
+            disable_fixed_size_array,
+            enable_unexported_fields,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use golang_source_file::{golang_type_decl_core::TypeSpec, SourceFile};
+use golang_type_core::TypeMappings;
+use golang_var_decl_core::{
+    static_item::{StaticItem, StaticItemOption},
+    VarEvaluator,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+mod input;
+
+pub use self::input::Input;
+
+pub fn get_output(input: Input) -> TokenStream {
+    let source_file = match input.code.parse::<SourceFile>() {
+        Ok(source_file) => source_file,
+        Err(err) => {
+            let err = err.to_string();
+            return quote!(compile_error!(#err));
+        }
+    };
+
+    let type_mappings = match TypeMappings::from_manifest_dir() {
+        Ok(type_mappings) => TypeMappings {
+            import_paths: source_file.import_paths(),
+            declared_types: source_file
+                .type_specs()
+                .map(|type_spec| type_spec.name().to_owned())
+                .collect(),
+            ..type_mappings
+        },
+        Err(err) => return quote!(compile_error!(#err)),
+    };
+
+    let constants = source_file.const_evaluator();
+    let types = source_file
+        .type_specs()
+        .map(|type_spec| match type_spec {
+            TypeSpec::AliasDecl(alias_decl) => {
+                (alias_decl.name.to_owned(), alias_decl.r#type.to_owned())
+            }
+            TypeSpec::TypeDef(type_def) => (type_def.name.to_owned(), type_def.r#type.to_owned()),
+        })
+        .collect();
+
+    // Evaluated in order, so that variables may refer to the ones of previous declarations.
+    let mut evaluator = VarEvaluator {
+        constants,
+        types,
+        ..Default::default()
+    };
+    let var_decls: Vec<_> = source_file
+        .var_decls()
+        .map(|var_decl| (var_decl, var_decl.eval_with(&mut evaluator)))
+        .collect();
+
+    let named_vars = if let Some(name) = &input.name {
+        let mut named_vars = vec![];
+        for (var_decl, named_vars_ret) in var_decls {
+            let is_related = var_decl
+                .var_specs
+                .iter()
+                .any(|var_spec| var_spec.names.contains(name));
+            match named_vars_ret {
+                Ok(x) => named_vars.extend(x.into_iter().filter(|x| &x.name == name)),
+                Err(err) if is_related => {
+                    let err = err.to_string();
+                    return quote!(compile_error!(#err));
+                }
+                Err(_) => {}
+            }
+        }
+        if named_vars.is_empty() {
+            let err = format!("var {} not found", name);
+            return quote!(compile_error!(#err));
+        }
+        named_vars
+    } else {
+        match var_decls.into_iter().nth(input.nth) {
+            Some((_, Ok(named_vars))) => named_vars,
+            Some((_, Err(err))) => {
+                let err = err.to_string();
+                return quote!(compile_error!(#err));
+            }
+            None => {
+                let err = "Require [Variable declarations](https://golang.org/ref/spec#Variable_declarations)";
+                return quote!(compile_error!(#err));
+            }
+        }
+    };
+
+    let named_vars: Vec<_> = named_vars.into_iter().filter(|x| x.name != "_").collect();
+    if input.alias_name.is_some() && named_vars.len() > 1 {
+        let err = "alias_name requires a single var, e.g. name = \"Names\"";
+        return quote!(compile_error!(#err));
+    }
+
+    let static_items = named_vars.into_iter().map(|named_var| StaticItem {
+        name: named_var.name,
+        value: named_var.value,
+        types: evaluator.types.to_owned(),
+        opt: StaticItemOption {
+            alias_name: input.alias_name.to_owned(),
+            enable_lazy_map: input.enable_lazy_map,
+            disable_fixed_size_array: input.disable_fixed_size_array,
+            enable_unexported_fields: input.enable_unexported_fields,
+            type_mappings: type_mappings.to_owned(),
+        },
+    });
+
+    quote!(#(#static_items)*)
+}
@@ -1 +1,12 @@
+extern crate proc_macro;
 
+use syn::parse_macro_input;
+
+mod gen_var;
+
+#[proc_macro]
+pub fn gen_var(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as gen_var::Input);
+    let output = gen_var::get_output(input);
+    output.into()
+}
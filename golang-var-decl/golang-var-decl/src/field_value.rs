@@ -0,0 +1,29 @@
+// The struct field values of `gen_var!`, wrapped by the field opts of `gen_json_struct!`,
+// e.g. `Option<T>` of `*T`, `T` of `flatten_pointer`, `Option<Box<T>>` of the recursive ones.
+pub trait FieldValue<T> {
+    fn from_value(value: T) -> Self;
+}
+
+impl<T> FieldValue<T> for T {
+    fn from_value(value: T) -> Self {
+        value
+    }
+}
+
+impl<T> FieldValue<T> for Option<T> {
+    fn from_value(value: T) -> Self {
+        Some(value)
+    }
+}
+
+impl<T> FieldValue<T> for Box<T> {
+    fn from_value(value: T) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<T> FieldValue<T> for Option<Box<T>> {
+    fn from_value(value: T) -> Self {
+        Some(Box::new(value))
+    }
+}
@@ -0,0 +1,35 @@
+#[macro_export]
+macro_rules! gen_var {
+    //
+    ($code:literal) => {
+        golang_var_decl_macro::gen_var!(code = $code,);
+    };
+    //
+    (
+        $code:literal,
+        $( $opt_k:ident = $opt_v:literal ),+ $(,)?
+    ) => {
+        golang_var_decl_macro::gen_var!(
+            code = $code,
+            $( $opt_k = $opt_v ,)*
+        );
+    };
+}
+
+#[macro_export]
+macro_rules! gen_var_from_file {
+    //
+    ($path:literal) => {
+        golang_var_decl_macro::gen_var!(path = $path,);
+    };
+    //
+    (
+        $path:literal,
+        $( $opt_k:ident = $opt_v:literal ),+ $(,)?
+    ) => {
+        golang_var_decl_macro::gen_var!(
+            path = $path,
+            $( $opt_k = $opt_v ,)*
+        );
+    };
+}
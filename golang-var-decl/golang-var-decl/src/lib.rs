@@ -1 +1,7 @@
+pub use golang_var_decl_core::*;
+pub use golang_var_decl_macro;
 
+mod field_value;
+mod gen_var;
+
+pub use self::field_value::FieldValue;
@@ -0,0 +1,86 @@
+package config
+
+import "time"
+
+type Config struct {
+	Port      int
+	Hosts     []string
+	Debug     bool              `json:",omitempty"`
+	TimeoutMs int               `json:",string"`
+	Labels    map[string]string `json:"labels"`
+}
+
+type Backend struct {
+	Name   string
+	Weight uint
+}
+
+var DefaultConfig = Config{
+	Port:      8080,
+	Hosts:     []string{"localhost"},
+	TimeoutMs: 500,
+	Labels:    map[string]string{"env": "dev"},
+}
+
+var Backends = []*Backend{{Name: "a", Weight: 1}, {"b", 0}}
+
+type Server struct {
+	*Backend
+	Addr string
+}
+
+var DefaultServer = Server{Backend: &Backend{Name: "c"}, Addr: ":80"}
+
+type Limits struct {
+	Max   int
+	burst int
+}
+
+var DefaultLimits = Limits{Max: 10, burst: 2}
+
+type Route struct {
+	Path     string
+	Fallback *Backend
+	Mirror   *Backend
+}
+
+var DefaultRoute = Route{Path: "/", Fallback: &Backend{Name: "d"}}
+
+var MirroredRoute = Route{Path: "/m", Mirror: &Backend{Name: "e"}}
+
+type Node struct {
+	Name string
+	Next *Node
+}
+
+var DefaultNode = Node{Name: "a", Next: &Node{Name: "b"}}
+
+type Retry struct {
+	Backoff time.Duration
+	Jitter  time.Duration
+}
+
+var DefaultRetry = Retry{Backoff: 2 * time.Second, Jitter: -time.Millisecond}
+
+type Meta struct {
+	Page int
+	Name string
+}
+
+type Page struct {
+	Meta
+	Name   string
+	Params struct {
+		Size int
+	}
+	Hash [4]byte
+	tags []string
+}
+
+var DefaultPage = Page{
+	Meta:   Meta{Page: 1, Name: "m"},
+	Name:   "p",
+	Params: struct{ Size int }{Size: 10},
+	Hash:   [4]byte{1, 2, 3, 4},
+	tags:   []string{"a"},
+}
@@ -0,0 +1,65 @@
+use std::{collections::HashMap, time::Duration};
+
+use golang_var_decl::gen_var;
+
+#[test]
+fn simple() {
+    gen_var!(
+        r#"
+    var (
+        DefaultTimeout = 30 * time.Second
+        Names          = []string{"a", "b"}
+        Primes         = [...]int{2, 3, 5}
+        Ports          = map[string]int{"https": 443, "http": 80}
+        Ratio          = 1.5
+        _              = 0
+    )
+    "#
+    );
+
+    assert_eq!(DEFAULT_TIMEOUT, Duration::from_secs(30));
+    assert_eq!(NAMES, &["a", "b"]);
+    assert_eq!(PRIMES, [2, 3, 5]);
+    assert_eq!(PORTS, &[("http", 80), ("https", 443)]);
+    assert_eq!(
+        PORTS
+            .binary_search_by_key(&"https", |(k, _)| k)
+            .map(|i| PORTS[i].1),
+        Ok(443)
+    );
+    assert_eq!(RATIO, 1.5);
+}
+
+#[test]
+fn with_name_and_lazy_map() {
+    gen_var!(
+        r#"
+    const Base = 100
+
+    var Codes = map[int]string{Base + 4: "not found", Base: "ok"}
+    "#,
+        name = "Codes",
+        alias_name = "CODES_BY_ID",
+        enable_lazy_map = true
+    );
+
+    let codes: &HashMap<isize, String> = &CODES_BY_ID;
+    assert_eq!(codes.get(&104).map(|x| x.as_str()), Some("not found"));
+    assert_eq!(codes.len(), 2);
+}
+
+#[test]
+fn with_lazy_array() {
+    gen_var!(
+        r#"
+    const Size = 4
+
+    var Masks = map[string][Size]byte{"all": {0xFF, 0xFF, 0xFF, 0xFF}, "none": {}}
+    "#,
+        enable_lazy_map = true
+    );
+
+    let masks: &HashMap<String, [u8; 4]> = &MASKS;
+    assert_eq!(masks["all"], [0xFF; 4]);
+    assert_eq!(masks["none"], [0; 4]);
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use golang_type_decl::gen_json_struct_from_file;
+use golang_var_decl::gen_var_from_file;
+
+#[test]
+fn with_struct() {
+    gen_json_struct_from_file!("tests/files/config.go#L5-L11");
+    gen_json_struct_from_file!("tests/files/config.go#L13-L16");
+    gen_var_from_file!("tests/files/config.go", name = "DefaultConfig");
+    gen_var_from_file!("tests/files/config.go", name = "Backends");
+
+    assert_eq!(DEFAULT_CONFIG.port, 8080);
+    assert_eq!(DEFAULT_CONFIG.hosts, vec!["localhost".to_owned()]);
+    assert_eq!(DEFAULT_CONFIG.debug, None);
+    assert_eq!(DEFAULT_CONFIG.timeout_ms, "500");
+    assert_eq!(
+        DEFAULT_CONFIG.labels,
+        vec![("env".to_owned(), "dev".to_owned())]
+            .into_iter()
+            .collect::<HashMap<_, _>>()
+    );
+
+    assert_eq!(BACKENDS.len(), 2);
+    assert_eq!(BACKENDS[0].name, "a");
+    assert_eq!(BACKENDS[1].weight, 0);
+}
+
+#[test]
+fn with_embedded_struct() {
+    gen_json_struct_from_file!("tests/files/config.go", name = "Backend");
+    gen_json_struct_from_file!("tests/files/config.go", name = "Server");
+    gen_var_from_file!("tests/files/config.go", name = "DefaultServer");
+
+    assert_eq!(
+        DEFAULT_SERVER.backend.as_ref().map(|x| x.name.as_str()),
+        Some("c")
+    );
+    assert_eq!(DEFAULT_SERVER.addr, ":80");
+}
+
+#[test]
+fn with_unexported_fields() {
+    gen_json_struct_from_file!("tests/files/config.go", name = "Limits");
+    gen_var_from_file!("tests/files/config.go", name = "DefaultLimits");
+
+    assert_eq!(DEFAULT_LIMITS.max, 10);
+    assert_eq!(DEFAULT_LIMITS.burst, 2);
+}
+
+#[test]
+fn with_durations() {
+    gen_json_struct_from_file!("tests/files/config.go", name = "Retry");
+    gen_var_from_file!("tests/files/config.go", name = "DefaultRetry");
+
+    assert_eq!(DEFAULT_RETRY.backoff, 2_000_000_000);
+    assert_eq!(DEFAULT_RETRY.jitter, -1_000_000);
+}
+
+#[test]
+fn with_pointer_fields() {
+    gen_json_struct_from_file!("tests/files/config.go", name = "Backend");
+    gen_json_struct_from_file!("tests/files/config.go", name = "Route");
+    gen_var_from_file!("tests/files/config.go", name = "DefaultRoute");
+
+    assert_eq!(DEFAULT_ROUTE.path, "/");
+    assert_eq!(
+        DEFAULT_ROUTE.fallback.as_ref().map(|x| x.name.as_str()),
+        Some("d")
+    );
+    assert!(DEFAULT_ROUTE.mirror.is_none());
+}
+
+#[test]
+fn with_flatten_pointer_fields() {
+    gen_json_struct_from_file!("tests/files/config.go", name = "Backend");
+    gen_json_struct_from_file!("tests/files/config.go", name = "Route"; "Mirror" => { "flatten_pointer": true });
+    gen_var_from_file!("tests/files/config.go", name = "MirroredRoute");
+
+    assert_eq!(MIRRORED_ROUTE.path, "/m");
+    assert!(MIRRORED_ROUTE.fallback.is_none());
+    assert_eq!(MIRRORED_ROUTE.mirror.name, "e");
+}
+
+#[test]
+fn with_recursive_pointer_fields() {
+    gen_json_struct_from_file!("tests/files/config.go", name = "Node");
+    gen_var_from_file!("tests/files/config.go", name = "DefaultNode");
+
+    assert_eq!(DEFAULT_NODE.name, "a");
+    let next = DEFAULT_NODE.next.as_deref().expect("next");
+    assert_eq!(next.name, "b");
+    assert!(next.next.is_none());
+}
+
+#[test]
+fn with_promoted_and_hoisted_fields() {
+    gen_json_struct_from_file!(
+        "tests/files/config.go",
+        name = "Page",
+        disable_fixed_size_array = true,
+        enable_unexported_fields = true
+    );
+    gen_var_from_file!(
+        "tests/files/config.go",
+        name = "DefaultPage",
+        disable_fixed_size_array = true,
+        enable_unexported_fields = true
+    );
+
+    // The copy `PageMeta` without `Name`, hidden by the one of `Page`.
+    assert_eq!(DEFAULT_PAGE.meta.page, 1);
+    assert_eq!(DEFAULT_PAGE.name, "p");
+    assert_eq!(DEFAULT_PAGE.params.size, 10);
+    assert_eq!(DEFAULT_PAGE.hash, vec![1, 2, 3, 4]);
+    assert_eq!(DEFAULT_PAGE.tags, vec!["a".to_owned()]);
+}